@@ -9,10 +9,7 @@ sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), "..")))
 from utils import replace_line
 
 
-PACKAGE_NAMES = {
-    "mysql": "@w-kuipers/alphadb-mysql",
-    "postgres": "@w-kuipers/alphadb-postgres",
-}
+PACKAGE_NAME = "@w-kuipers/alphadb"
 
 NODE_PLATFORMS = {
     "darwin": {
@@ -33,7 +30,6 @@ NODE_PLATFORMS = {
 def parse_args():
     parser = argparse.ArgumentParser(description="Build Node binaries for AlphaDB.")
     parser.add_argument("version", help='Release version, for example "v1.0.0".')
-    parser.add_argument("engine", choices=PACKAGE_NAMES.keys())
     args = parser.parse_args()
 
     if not args.version.startswith("v"):
@@ -56,13 +52,13 @@ def set_github_env(name, value):
             f.write(f"{name}={value}\n")
 
 
-def update_package_files(paths, version, engine):
+def update_package_files(paths, version):
     package_version = version[1:]
     alphadb_path = paths["alphadb_crate"].as_posix()
 
     replace_line(
         '"name":',
-        f'\t"name": "{PACKAGE_NAMES[engine]}",\n',
+        f'\t"name": "{PACKAGE_NAME}",\n',
         str(paths["package"]),
     )
     replace_line(
@@ -70,11 +66,6 @@ def update_package_files(paths, version, engine):
         f'\t"version": "{package_version}",\n',
         str(paths["package"]),
     )
-    replace_line(
-        '"engine":',
-        f'\t\t"engine": "{engine}"\n',
-        str(paths["package"]),
-    )
     replace_line(
         "version =",
         f'version = "{package_version}"\n',
@@ -98,18 +89,18 @@ def install_linux_dependencies():
     set_github_env("OPENSSL_DIR", "/usr/lib/ssl")
 
 
-def build_platform_binaries(platform, node_dir, node_bin_dir, engine):
+def build_platform_binaries(platform, node_dir, node_bin_dir):
     platform_config = NODE_PLATFORMS[platform]
 
     for node_platform, rust_target in zip(
         platform_config["node"], platform_config["rust"]
     ):
         run(["rustup", "target", "add", rust_target], cwd=node_dir)
-        run(["yarn", f"build:{engine}", "--target", rust_target], cwd=node_dir)
+        run(["yarn", "build", "--target", rust_target], cwd=node_dir)
 
         shutil.move(
             "src/node/index.node",
-            node_bin_dir / f"{node_platform}-{engine}.node",
+            node_bin_dir / f"{node_platform}.node",
         )
 
 
@@ -130,7 +121,7 @@ def main():
     print(args.version)
     print(f'version = "{args.version[1:]}-node"')
 
-    update_package_files(paths, args.version, args.engine)
+    update_package_files(paths, args.version)
     run(["yarn", "install", "--ignore-scripts"], cwd=node_dir)
 
     platform = "linux" if sys.platform == "linux2" else sys.platform
@@ -139,7 +130,7 @@ def main():
         install_linux_dependencies()
 
     if platform in NODE_PLATFORMS:
-        build_platform_binaries(platform, node_dir, node_bin_dir, args.engine)
+        build_platform_binaries(platform, node_dir, node_bin_dir)
 
 
 if __name__ == "__main__":
@@ -9,16 +9,12 @@ sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), "..")))
 from utils import replace_line
 
 
-PACKAGE_NAMES = {
-    "mysql": "alphadb-mysql",
-    "postgres": "alphadb-postgres",
-}
+PACKAGE_NAME = "alphadb"
 
 
 def parse_args():
     parser = argparse.ArgumentParser(description="Build Python wheels for AlphaDB.")
     parser.add_argument("version", help='Release version, for example "v1.0.0".')
-    parser.add_argument("engine", choices=PACKAGE_NAMES.keys())
     args = parser.parse_args()
 
     if not args.version.startswith("v"):
@@ -45,7 +41,7 @@ def main():
     args = parse_args()
     root_dir = Path.cwd()
     py_dir = root_dir / "src/py"
-    package_dir = py_dir / "packages" / args.engine
+    package_dir = py_dir / "packages" / PACKAGE_NAME
     wheels_dir = py_dir / "target/wheels"
     paths = {
         "pyproject": package_dir / "pyproject.toml",
@@ -55,7 +51,7 @@ def main():
     shutil.copyfile(py_dir / "LICENSE", package_dir / "LICENSE")
 
     print(args.version)
-    print(f'package = "{PACKAGE_NAMES[args.engine]}"')
+    print(f'package = "{PACKAGE_NAME}"')
     print(f'version = "{args.version[1:]}"')
 
     update_package_files(paths, args.version)
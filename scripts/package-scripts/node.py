@@ -9,10 +9,7 @@ sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), "..")))
 from utils import replace_line
 
 
-PACKAGE_NAMES = {
-    "mysql": "@w-kuipers/alphadb-mysql",
-    "postgres": "@w-kuipers/alphadb-postgres",
-}
+PACKAGE_NAME = "@w-kuipers/alphadb"
 
 PACKAGE_FILES = [
     "package.json",
@@ -27,7 +24,6 @@ PACKAGE_DIRECTORIES = ["lib", "crates"]
 def parse_args():
     parser = argparse.ArgumentParser(description="Create the Node package for AlphaDB.")
     parser.add_argument("version", help='Release version, for example "v1.0.0".')
-    parser.add_argument("engine", choices=PACKAGE_NAMES.keys())
     args = parser.parse_args()
 
     if not args.version.startswith("v"):
@@ -43,13 +39,13 @@ def run(command, cwd=None):
     subprocess.run(command, cwd=cwd, check=True)
 
 
-def update_package_files(paths, version, engine):
+def update_package_files(paths, version):
     package_version = version[1:]
     release_url = f'https://github.com/w-kuipers/alphadb/releases/download/{version}'
 
     replace_line(
         '"name":',
-        f'\t"name": "{PACKAGE_NAMES[engine]}",\n',
+        f'\t"name": "{PACKAGE_NAME}",\n',
         str(paths["package"]),
     )
     replace_line(
@@ -57,11 +53,6 @@ def update_package_files(paths, version, engine):
         f'\t"version": "{package_version}",\n',
         str(paths["package"]),
     )
-    replace_line(
-        '"engine":',
-        f'\t\t"engine": "{engine}"\n',
-        str(paths["package"]),
-    )
     replace_line(
         "alphadb =",
         f'alphadb = {{ version = "{package_version}", default-features = false }}\n',
@@ -100,7 +91,7 @@ def main():
 
     dist_dir.mkdir()
 
-    update_package_files(paths, args.version, args.engine)
+    update_package_files(paths, args.version)
     build_typescript(node_dir)
     copy_package_contents(node_dir, dist_dir)
 
@@ -9,16 +9,12 @@ sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), "..")))
 from utils import replace_line
 
 
-PACKAGE_NAMES = {
-    "mysql": "alphadb-mysql",
-    "postgres": "alphadb-postgres",
-}
+PACKAGE_NAME = "alphadb"
 
 
 def parse_args():
     parser = argparse.ArgumentParser(description="Create the Python package for AlphaDB.")
     parser.add_argument("version", help='Release version, for example "v1.0.0".')
-    parser.add_argument("engine", choices=PACKAGE_NAMES.keys())
     args = parser.parse_args()
 
     if not args.version.startswith("v"):
@@ -45,8 +41,8 @@ def main():
     args = parse_args()
     root_dir = Path.cwd()
     py_dir = root_dir / "src/py"
-    package_dir = py_dir / "packages" / args.engine
-    dist_dir = root_dir / "py-dist" / args.engine
+    package_dir = py_dir / "packages" / PACKAGE_NAME
+    dist_dir = root_dir / "py-dist"
     paths = {
         "pyproject": package_dir / "pyproject.toml",
     }
@@ -0,0 +1,118 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How the connection should use TLS. Follows the libpq `sslmode` names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TlsMode {
+    /// Never use TLS
+    #[default]
+    Disable,
+
+    /// Use TLS when the server supports it, without verifying the certificate
+    Prefer,
+
+    /// Always use TLS, without verifying the certificate
+    Require,
+
+    /// Always use TLS and verify the certificate chain, but not the host name
+    VerifyCa,
+
+    /// Always use TLS and verify both the certificate chain and the host name
+    VerifyFull,
+}
+
+/// Certificate the client authenticates itself with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientCertificate {
+    /// PEM encoded certificate chain and PKCS#8 private key
+    Pem { cert: PathBuf, key: PathBuf },
+
+    /// PKCS#12 archive, as required by MySQL
+    Pkcs12 { path: PathBuf, password: Option<String> },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// `None` keeps the TLS setting of the URL (`sslmode`, `require_ssl`),
+    /// without a URL the connection does not use TLS
+    pub mode: Option<TlsMode>,
+
+    /// PEM encoded CA certificate(s) to trust, instead of the system roots
+    pub ca_cert: Option<PathBuf>,
+
+    pub client_cert: Option<ClientCertificate>,
+}
+
+/// Everything needed to open a database connection.
+///
+/// When `url` is set it is parsed first, any other field that is set
+/// overrides the matching part of the URL. PostgreSQL also accepts a
+/// key/value DSN (`host=localhost dbname=app`) as the URL. For SQLite,
+/// `database` is the path of the database file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectOptions {
+    pub url: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+
+    /// Unix socket to connect through, instead of `host` and `port`.
+    /// For PostgreSQL this is the directory containing the socket.
+    pub socket: Option<PathBuf>,
+
+    pub user: Option<String>,
+    pub password: Option<String>,
+    pub database: Option<String>,
+    pub tls: TlsOptions,
+    pub connect_timeout: Option<Duration>,
+
+    /// Maximum runtime of a single statement. Applied as `statement_timeout`
+    /// on PostgreSQL, `max_statement_time` on MariaDB and `max_execution_time`
+    /// on MySQL, which only limits `SELECT`.
+    pub statement_timeout: Option<Duration>,
+
+    /// Reported to the server, shown in `pg_stat_activity` and `performance_schema.session_connect_attrs`
+    pub application_name: Option<String>,
+}
+
+impl ConnectOptions {
+    pub fn new(host: &str, user: &str, password: &str, database: &str, port: u16) -> ConnectOptions {
+        ConnectOptions {
+            host: Some(host.to_string()),
+            port: Some(port),
+            user: Some(user.to_string()),
+            password: Some(password.to_string()),
+            database: Some(database.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_url(url: &str) -> ConnectOptions {
+        ConnectOptions {
+            url: Some(url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Options for a SQLite database file, or `:memory:`
+    pub fn from_path(path: &str) -> ConnectOptions {
+        ConnectOptions {
+            database: Some(path.to_string()),
+            ..Default::default()
+        }
+    }
+}
@@ -82,6 +82,15 @@ pub struct VerificationHooks {
     pub column_compatibility: &'static [ColumnCompatibilityHook],
 }
 
+/// Scope in which an engine requires index names to be unique
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexNameScope {
+    /// Index names only have to be unique within their table (MySQL)
+    Table,
+    /// Index names are shared by all tables in the schema (PostgreSQL, SQLite)
+    Schema,
+}
+
 /// Configuration for a SQL database engine's verification behavior.
 ///
 /// This struct contains all engine-specific data needed for version source verification.
@@ -107,6 +116,26 @@ pub struct EngineConfig {
     /// All column types supported by this engine
     pub supported_column_types: &'static [&'static str],
 
+    /// Version keys besides `createtable`, `altertable` and `default_data`
+    /// that the engine handles (e.g. `createextension`)
+    pub version_methods: &'static [&'static str],
+
+    /// Index methods accepted as an index `type`, in lowercase. Empty when the
+    /// engine does not support index types
+    pub index_types: &'static [&'static str],
+
+    /// Whether indexes can be given a `condition` (partial indexes)
+    pub partial_indexes: bool,
+
+    /// Scope in which index names must be unique
+    pub index_name_scope: IndexNameScope,
+
+    /// Words that can only be used as a name when quoted, in uppercase
+    pub reserved_words: &'static [&'static str],
+
+    /// Maximum length of table, column, index and constraint names, if the engine limits it
+    pub max_identifier_length: Option<usize>,
+
     /// Column type compatibility rules (e.g., TEXT cannot have auto_increment)
     pub type_compatibility_rules: &'static [ColumnCompatibilityRule],
 
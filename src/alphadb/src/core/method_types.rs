@@ -34,6 +34,16 @@ pub struct Status {
     pub template: Option<String>,
 }
 
+/// A single applied version, as recorded in the history table
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub version: String,
+    pub template: Option<String>,
+
+    /// Formatted as `YYYY-MM-DD HH:MM:SS` in the database server's timezone
+    pub applied_at: String,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum QueryValue {
     String(String),
@@ -41,6 +51,8 @@ pub enum QueryValue {
     Unsigned(u64),
     Float(f64),
     Bool(bool),
+    /// SQL `NULL`
+    Null,
 }
 
 impl QueryValue {
@@ -59,7 +71,7 @@ impl QueryValue {
                 }
             }
             Value::Bool(v) => Self::Bool(*v),
-            Value::Null => Self::String(String::new()),
+            Value::Null => Self::Null,
             Value::Array(_) | Value::Object(_) => Self::String(value.to_string()),
         }
     }
@@ -74,6 +86,7 @@ impl QueryValue {
                 None => Value::String(v.to_string()),
             },
             Self::Bool(v) => Value::Bool(*v),
+            Self::Null => Value::Null,
         }
     }
 
@@ -84,6 +97,7 @@ impl QueryValue {
             Self::Unsigned(v) => v.to_string(),
             Self::Float(v) => v.to_string(),
             Self::Bool(v) => v.to_string(),
+            Self::Null => String::new(),
         }
     }
 }
@@ -2,6 +2,7 @@ pub mod query;
 pub mod utils;
 pub mod verification;
 // pub mod version_source_verification;
+pub mod connect_options;
 pub mod engine;
 pub mod engine_config;
 pub mod method_types;
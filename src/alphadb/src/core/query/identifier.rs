@@ -0,0 +1,71 @@
+/// The way an engine quotes identifiers (table, column, index and constraint names)
+/// in generated SQL. Quoted names may be reserved words or contain any character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierQuote {
+    /// `` `name` `` (MySQL, MariaDB)
+    Backtick,
+    /// `"name"` (SQLite)
+    DoubleQuote,
+    /// `"name"` with the name in lowercase (PostgreSQL). PostgreSQL folds unquoted names
+    /// to lowercase, so names keep matching the ones in hand-written, unquoted queries.
+    LowercaseDoubleQuote,
+}
+
+impl IdentifierQuote {
+    fn char(&self) -> char {
+        match self {
+            IdentifierQuote::Backtick => '`',
+            IdentifierQuote::DoubleQuote | IdentifierQuote::LowercaseDoubleQuote => '"',
+        }
+    }
+
+    /// Quote a single identifier. Quote characters inside the name are doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alphadb::core::query::identifier::IdentifierQuote;
+    ///
+    /// assert_eq!(IdentifierQuote::DoubleQuote.quote("table"), "\"table\"");
+    /// assert_eq!(IdentifierQuote::Backtick.quote("it`s"), "`it``s`");
+    /// assert_eq!(IdentifierQuote::LowercaseDoubleQuote.quote("userId"), "\"userid\"");
+    /// ```
+    pub fn quote(&self, identifier: &str) -> String {
+        let identifier = match self {
+            IdentifierQuote::LowercaseDoubleQuote => identifier.to_lowercase(),
+            _ => identifier.to_string(),
+        };
+
+        let quote = self.char();
+        let escaped = identifier.replace(quote, &format!("{quote}{quote}"));
+        format!("{quote}{escaped}{quote}")
+    }
+
+    /// Quote every identifier and join them with `, `, e.g. for a column list
+    pub fn quote_list<I, S>(&self, identifiers: I) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        identifiers.into_iter().map(|i| self.quote(i.as_ref())).collect::<Vec<String>>().join(", ")
+    }
+}
+
+#[cfg(test)]
+mod identifier_tests {
+    use super::IdentifierQuote;
+
+    #[test]
+    fn quote() {
+        assert_eq!(IdentifierQuote::Backtick.quote("order"), "`order`");
+        assert_eq!(IdentifierQuote::DoubleQuote.quote("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(IdentifierQuote::Backtick.quote("a\"b"), "`a\"b`");
+        assert_eq!(IdentifierQuote::LowercaseDoubleQuote.quote("Order\"Item"), "\"order\"\"item\"");
+    }
+
+    #[test]
+    fn quote_list() {
+        assert_eq!(IdentifierQuote::DoubleQuote.quote_list(["id", "user"]), "\"id\", \"user\"");
+        assert_eq!(IdentifierQuote::Backtick.quote_list(Vec::<String>::new()), "");
+    }
+}
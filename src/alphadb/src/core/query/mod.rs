@@ -1,5 +1,6 @@
 pub mod build;
 pub mod column;
 pub mod default_data;
+pub mod identifier;
 pub mod primary_key;
 pub mod table;
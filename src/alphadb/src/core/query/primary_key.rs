@@ -1,17 +1,18 @@
 use serde_json::Value;
 
+use crate::core::query::identifier::IdentifierQuote;
 use crate::prelude::AlphaDBError;
 
-/// Formats a primary key value as a comma-separated column list.
+/// The columns of a primary key value.
 ///
 /// Accepts either a string or an array of strings.
 ///
 /// # Errors
 ///
 /// Returns an error if the value is not a string or array of strings.
-pub fn format_primary_key_columns(primary_key: &Value) -> Result<String, AlphaDBError> {
+pub fn primary_key_columns(primary_key: &Value) -> Result<Vec<String>, AlphaDBError> {
     if let Some(primary_key) = primary_key.as_str() {
-        return Ok(primary_key.to_string());
+        return Ok(vec![primary_key.to_string()]);
     }
 
     if let Some(primary_keys) = primary_key.as_array() {
@@ -30,7 +31,7 @@ pub fn format_primary_key_columns(primary_key: &Value) -> Result<String, AlphaDB
             }
         }
 
-        return Ok(columns.join(", "));
+        return Ok(columns);
     }
 
     Err(AlphaDBError {
@@ -39,3 +40,12 @@ pub fn format_primary_key_columns(primary_key: &Value) -> Result<String, AlphaDB
         ..Default::default()
     })
 }
+
+/// Formats a primary key value as a comma-separated list of quoted columns.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a string or array of strings.
+pub fn format_primary_key_columns(primary_key: &Value, quote: IdentifierQuote) -> Result<String, AlphaDBError> {
+    Ok(quote.quote_list(primary_key_columns(primary_key)?))
+}
@@ -20,6 +20,7 @@
 
 use crate::core::query::build::StructureQuery;
 use crate::core::query::column::DefineColumn;
+use crate::core::query::identifier::IdentifierQuote;
 use crate::core::query::primary_key::format_primary_key_columns;
 use crate::core::utils::consolidate::primary_key::get_primary_key;
 use crate::core::utils::errors::{AlphaDBError, Get};
@@ -46,7 +47,7 @@ pub type DropPrimaryKeyHook = fn(table_name: &str) -> Vec<DefineColumn>;
 
 /// Hook to build the statement(s) that add a primary key to the table.
 ///
-/// `columns` is the comma-separated column list as produced by
+/// `columns` is the comma-separated, quoted column list as produced by
 /// [`format_primary_key_columns`].
 pub type AddPrimaryKeyHook = fn(table_name: &str, columns: &str) -> Vec<DefineColumn>;
 
@@ -57,6 +58,16 @@ pub type DropForeignKeyHook = fn(foreign_key_name: &str) -> DefineColumn;
 /// allowing an engine to pre-process the `altertable` block.
 pub type PreprocessHook = fn(version_list: &Vec<Value>, table_data: &mut Value, table_name: &str, version: &str) -> Result<(), AlphaDBError>;
 
+/// Hook to build the statement that renames a column, for engines that can not
+/// use `RENAME COLUMN`.
+pub type RenameColumnHook = fn(version_list: &Vec<Value>, table_name: &str, old_name: &str, new_name: &str, version: &str) -> Result<DefineColumn, AlphaDBError>;
+
+/// Hook to build extra options for a single table from its `createtable` block.
+pub type CreateOptionsHook = fn(table_data: &Value) -> Result<Option<String>, AlphaDBError>;
+
+/// Hook to build the statement(s) for table level keys of an `altertable` block.
+pub type AlterOptionsHook = fn(table_data: &Value, table_name: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError>;
+
 /// Engine-specific behaviour required to build table queries.
 ///
 /// One instance is defined per engine (as a `const`), and passed to
@@ -66,6 +77,9 @@ pub struct TableQueryConfig {
     /// Engine name (e.g. "mysql", "postgres").
     pub name: &'static str,
 
+    /// How table, column and constraint names are quoted.
+    pub identifier_quote: IdentifierQuote,
+
     /// Builds a column definition for `CREATE TABLE` / `ADD COLUMN`.
     pub define_column: DefineColumnHook,
 
@@ -94,6 +108,20 @@ pub struct TableQueryConfig {
     /// Optional step run before any column statements are generated for
     /// `ALTER TABLE`. `None` for engines that need none.
     pub preprocess: Option<PreprocessHook>,
+
+    /// Builds the statement that renames a column. `None` emits `RENAME COLUMN old TO new`.
+    pub rename_column: Option<RenameColumnHook>,
+
+    /// Adds `IF EXISTS` / `IF NOT EXISTS` to the column statements of `ALTER TABLE`.
+    pub column_if_exists: bool,
+
+    /// Options for a single table, appended after `table_options`
+    /// (e.g. `WITH SYSTEM VERSIONING`). `None` for engines that need none.
+    pub create_options: Option<CreateOptionsHook>,
+
+    /// Builds the statement(s) for table level keys of an `altertable` block,
+    /// after all column and key changes. `None` for engines that need none.
+    pub alter_options: Option<AlterOptionsHook>,
 }
 
 /// Generate a `CREATE TABLE` query for the given [`TableQueryConfig`].
@@ -108,7 +136,7 @@ pub fn create_table(config: &TableQueryConfig, version: &Value, table_name: &str
     let mut version_trace = VersionTrace::from([version_number, "createtable", table_name]);
 
     let mut query = StructureQuery::createtable();
-    query.table(table_name);
+    query.table(config.identifier_quote.quote(table_name));
 
     for (column_name, column_value) in get_json_object(table_data)? {
         if let Some(column) = (config.define_column)(column_value, table_name, column_name, version_number)? {
@@ -119,7 +147,10 @@ pub fn create_table(config: &TableQueryConfig, version: &Value, table_name: &str
     let table_keys = get_object_keys(table_data)?;
 
     if table_keys.iter().any(|&i| i == "primary_key") {
-        query.constraint(format!("PRIMARY KEY ({})", format_primary_key_columns(&table_data["primary_key"])?));
+        query.constraint(format!(
+            "PRIMARY KEY ({})",
+            format_primary_key_columns(&table_data["primary_key"], config.identifier_quote)?
+        ));
     }
 
     if table_keys.iter().any(|&i| i == "foreign_key") {
@@ -127,6 +158,7 @@ pub fn create_table(config: &TableQueryConfig, version: &Value, table_name: &str
             message: "foreign_key must be an array of objects".to_string(),
             error: "invalid-structure".to_string(),
             version_trace: version_trace.clone(),
+            location: None,
         })?;
 
         version_trace.push("foreign_key".to_string());
@@ -146,6 +178,7 @@ pub fn create_table(config: &TableQueryConfig, version: &Value, table_name: &str
             message: "check must be an array of objects".to_string(),
             error: "invalid-structure".to_string(),
             version_trace: version_trace.clone(),
+            location: None,
         })?;
 
         version_trace.push("check".to_string());
@@ -164,6 +197,15 @@ pub fn create_table(config: &TableQueryConfig, version: &Value, table_name: &str
         query.options(options);
     }
 
+    if let Some(create_options) = config.create_options {
+        if let Some(options) = create_options(table_data).map_err(|mut e| {
+            e.set_version_trace(&version_trace);
+            e
+        })? {
+            query.options(options);
+        }
+    }
+
     Ok(query.build())
 }
 
@@ -182,7 +224,7 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
     let version_list = get_version_array(version_source)?;
 
     let mut query = StructureQuery::altertable();
-    query.table(table_name);
+    query.table(config.identifier_quote.quote(table_name));
 
     let mut version_index: Option<usize> = None;
     for (c, table) in array_iter(&version_source["version"])?.iter().enumerate() {
@@ -195,6 +237,7 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
                 message: "Version does not contain a version number".to_string(),
                 error: "no-version-number".to_string(),
                 version_trace: VersionTrace::from([format!("index {}", c)]),
+                location: None,
             });
         }
     }
@@ -220,7 +263,9 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
     if exists_in_object(&table_data["altertable"][table_name], "dropcolumn")? {
         for column in array_iter(&table_data["altertable"][table_name]["dropcolumn"])? {
             let mut definition = DefineColumn::new();
-            definition.method("DROP COLUMN").name(get_json_string(column)?);
+            definition
+                .method(if config.column_if_exists { "DROP COLUMN IF EXISTS" } else { "DROP COLUMN" })
+                .name(config.identifier_quote.quote(get_json_string(column)?));
             query.definition(definition);
         }
     }
@@ -230,7 +275,7 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
     if exists_in_object(&table_data["altertable"][table_name], "addcolumn")? {
         for column in object_iter(&table_data["altertable"][table_name]["addcolumn"])? {
             if let Some(mut definition) = (config.define_column)(&mutable_table_data["altertable"][table_name]["addcolumn"][column], table_name, column, version)? {
-                definition.method("ADD COLUMN");
+                definition.method(if config.column_if_exists { "ADD COLUMN IF NOT EXISTS" } else { "ADD COLUMN" });
                 query.definition(definition);
             }
         }
@@ -257,12 +302,17 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
     let table_data = mutable_table_data.clone();
     if exists_in_object(&table_data["altertable"][table_name], "renamecolumn")? {
         for column in object_iter(&table_data["altertable"][table_name]["renamecolumn"])? {
+            let new_name = get_json_string(&table_data["altertable"][table_name]["renamecolumn"][column])?;
+
+            if let Some(rename_column) = config.rename_column {
+                query.definition(rename_column(version_list, table_name, column, new_name, version)?);
+                continue;
+            }
+
             let mut definition = DefineColumn::new();
-            definition.method("RENAME COLUMN").name(format!(
-                "{} TO {}",
-                column,
-                get_json_string(&table_data["altertable"][table_name]["renamecolumn"][column])?
-            ));
+            definition
+                .method(if config.column_if_exists { "RENAME COLUMN IF EXISTS" } else { "RENAME COLUMN" })
+                .name(format!("{} TO {}", config.identifier_quote.quote(column), config.identifier_quote.quote(new_name)));
             query.definition(definition);
         }
     }
@@ -284,7 +334,7 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
                 }
             }
 
-            let columns = format_primary_key_columns(primary_key).map_err(|mut e| {
+            let columns = format_primary_key_columns(primary_key, config.identifier_quote).map_err(|mut e| {
                 e.set_version_trace(&version_trace);
                 e
             })?;
@@ -335,5 +385,11 @@ pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name
         }
     }
 
+    if let Some(alter_options) = config.alter_options {
+        for definition in alter_options(&mutable_table_data["altertable"][table_name], table_name, version)? {
+            query.definition(definition);
+        }
+    }
+
     Ok(query.build())
 }
@@ -14,14 +14,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::core::{
-    method_types::{Init, Query, Status},
+    connect_options::ConnectOptions,
+    method_types::{HistoryEntry, Init, Query, Status},
     utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
 };
 
 /// Hook type for the `connect` operation.
 ///
-/// Creates a new database connection from the provided credentials.
-pub type ConnectHook<C> = fn(host: &str, user: &str, password: &str, database: &str, port: u16) -> Result<C, AlphaDBError>;
+/// Creates a new database connection from the provided options. Returns the
+/// connection together with the name of the database it is connected to.
+pub type ConnectHook<C> = fn(options: &ConnectOptions) -> Result<(C, String), AlphaDBError>;
 
 /// Hook type for the `init` operation.
 ///
@@ -33,6 +35,11 @@ pub type InitHook<C> = fn(db_name: &str, connection: &mut C) -> Result<Init, Alp
 /// Gets the database status including initialization state, version, name and template.
 pub type StatusHook<C> = fn(db_name: &str, connection: &mut C) -> Result<Status, AlphaDBError>;
 
+/// Hook type for the `history` operation.
+///
+/// Gets every version applied to the database, oldest first.
+pub type HistoryHook<C> = fn(db_name: &str, connection: &mut C) -> Result<Vec<HistoryEntry>, AlphaDBError>;
+
 /// Hook type for the `update_queries` operation.
 ///
 /// Generates queries to update the database tables.
@@ -62,6 +69,7 @@ pub struct RuntimeHooks<C> {
     pub connect: ConnectHook<C>,
     pub init: InitHook<C>,
     pub status: StatusHook<C>,
+    pub history: HistoryHook<C>,
     pub update_queries: UpdateQueriesHook<C>,
     pub update: UpdateHook<C>,
     pub vacate: VacateHook<C>,
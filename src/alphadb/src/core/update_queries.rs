@@ -20,6 +20,7 @@
 
 use crate::core::method_types::{Query, Status};
 use crate::core::query::table::{alter_table, create_table, TableQueryConfig};
+use crate::core::utils::column_types::resolve_portable_column_types;
 use crate::core::utils::consolidate::default_data::consolidate_default_data;
 use crate::core::utils::errors::{AlphaDBError, Get};
 use crate::core::utils::json::{array_iter, exists_in_object, get_object_keys, object_iter};
@@ -38,13 +39,23 @@ pub type CreateIndexHook = fn(index: &Value, table_name: &str) -> Result<String,
 pub type DropIndexHook = fn(index_name: &Value, table_name: &str) -> Result<String, AlphaDBError>;
 pub type DefaultDataHook = fn(table_name: &str, item: &Value) -> Result<Query, AlphaDBError>;
 pub type ConfigUpdateQueryHook = fn(latest_version: &str, template_name: &str, db_name: &str) -> Query;
+pub type HistoryQueryHook = fn(version: &str, template_name: &str, db_name: &str) -> Query;
+pub type HistoryTableQueryHook = fn() -> Query;
 pub type VersionExtrasHook = fn(version: &Value) -> Result<Vec<Query>, AlphaDBError>;
 
+/// Replaces the shared [`create_table`] builder for engines that do not render SQL (mock).
+pub type CreateTableHook = fn(table_config: &TableQueryConfig, version: &Value, table_name: &str, version_number: &str) -> Result<Vec<Query>, AlphaDBError>;
+
+/// Replaces the shared [`alter_table`] builder for engines that can not express an
+/// `altertable` block as a single `ALTER TABLE` statement (SQLite).
+pub type AlterTableHook = fn(table_config: &TableQueryConfig, version_source: &Value, table_name: &str, version: &str) -> Result<Vec<Query>, AlphaDBError>;
+
 /// Engine-specific behaviour for [`update_queries`]. `C` is the engine connection
 /// type (e.g. `mysql::PooledConn`, `postgres::Client`).
 pub struct UpdateQueriesConfig<C> {
     /// Matched case-insensitively (via `Display`) against the version source `engine` field.
-    pub engine: AlphaDBEngine,
+    /// `None` accepts a version source for any engine.
+    pub engine: Option<AlphaDBEngine>,
     pub status: StatusHook<C>,
     pub table_config: &'static TableQueryConfig,
     pub create_index: CreateIndexHook,
@@ -52,8 +63,23 @@ pub struct UpdateQueriesConfig<C> {
     pub default_data: DefaultDataHook,
     pub config_update_query: ConfigUpdateQueryHook,
 
+    /// Records a single applied version in the history table.
+    pub history_query: HistoryQueryHook,
+
+    /// Creates the history table when it does not exist yet, for databases initialized
+    /// before it was introduced. `None` for engines that keep the history without a table (mock).
+    pub history_table_query: Option<HistoryTableQueryHook>,
+
     /// Runs once per version, before that version's tables. `None` if unused.
     pub version_extras: Option<VersionExtrasHook>,
+
+    /// Builds the queries for one table's `createtable` block, its indexes excluded.
+    /// `None` uses the shared [`create_table`] builder.
+    pub create_table: Option<CreateTableHook>,
+
+    /// Builds the queries for one table's `altertable` block. `None` uses the
+    /// shared [`alter_table`] builder.
+    pub alter_table: Option<AlterTableHook>,
 }
 
 /// Attach `trace` to errors from structural helpers that carry none of their own.
@@ -84,13 +110,17 @@ pub fn update_queries<C>(
         sort_versions(versions)?;
     }
 
+    if let Some(engine) = config.engine {
+        resolve_portable_column_types(&mut version_source, engine);
+    }
+
     let versions = get_version_array(&version_source)?;
 
-    if let Some(v) = version_source["engine"].as_str() {
-        if !v.eq_ignore_ascii_case(&config.engine.to_string()) {
+    if let (Some(v), Some(engine)) = (version_source["engine"].as_str(), config.engine) {
+        if !v.eq_ignore_ascii_case(&engine.to_string()) {
             return Err(AlphaDBError {
                 error: "incompatible-version-source".to_string(),
-                message: format!("Tried to update a {} database using a version source with engine '{v}'", config.engine.display_name()),
+                message: format!("Tried to update a {} database using a version source with engine '{v}'", engine.display_name()),
                 ..Default::default()
             });
         }
@@ -144,6 +174,7 @@ pub fn update_queries<C>(
                     message: format!("'{}' is not a valid version number", v),
                     error: "invalid-version-number".to_string(),
                     version_trace: VersionTrace::from([v.to_string()]),
+                    location: None,
                 })
             }
         },
@@ -161,11 +192,19 @@ pub fn update_queries<C>(
         });
     }
 
+    // Databases initialized before the history table was introduced do not have it yet
+    if let Some(history_table_query) = config.history_table_query {
+        queries.push(history_table_query());
+    }
+
+    let mut applied_versions: Vec<&str> = Vec::new();
+
     for (i, version) in versions.iter().enumerate() {
         let version_number = version["_id"].as_str().ok_or_else(|| AlphaDBError {
             message: "Missing a version number".to_string(),
             error: "missing-version-number".to_string(),
             version_trace: VersionTrace::from([format!(" index {i}")]),
+            location: None,
         })?;
 
         let mut version_trace = VersionTrace::from([version_number]);
@@ -180,6 +219,8 @@ pub fn update_queries<C>(
             continue;
         }
 
+        applied_versions.push(version_number);
+
         let version_keys = get_object_keys(version).map_err(with_trace(&version_trace))?;
 
         if let Some(version_extras) = config.version_extras {
@@ -192,8 +233,13 @@ pub fn update_queries<C>(
             for table in object_iter(&version["createtable"]).map_err(with_trace(&version_trace))? {
                 version_trace.push(table.clone());
 
-                let q = create_table(config.table_config, version, table, version_number)?;
-                queries.push(Query { query: q, data: None });
+                match config.create_table {
+                    Some(create_table_hook) => queries.extend(create_table_hook(config.table_config, version, table, version_number)?),
+                    None => queries.push(Query {
+                        query: create_table(config.table_config, version, table, version_number)?,
+                        data: None,
+                    }),
+                }
 
                 if exists_in_object(&version["createtable"][table], "index").map_err(with_trace(&version_trace))? {
                     for index in array_iter(&version["createtable"][table]["index"]).map_err(with_trace(&version_trace))? {
@@ -216,10 +262,13 @@ pub fn update_queries<C>(
             for table in object_iter(&version["altertable"]).map_err(with_trace(&version_trace))? {
                 version_trace.push(table.clone());
 
-                queries.push(Query {
-                    query: alter_table(config.table_config, &version_source, table, version_number)?,
-                    data: None,
-                });
+                match config.alter_table {
+                    Some(alter_table_hook) => queries.extend(alter_table_hook(config.table_config, &version_source, table, version_number)?),
+                    None => queries.push(Query {
+                        query: alter_table(config.table_config, &version_source, table, version_number)?,
+                        data: None,
+                    }),
+                }
 
                 // Indexes are standalone CREATE/DROP INDEX statements, emitted
                 // separately from the ALTER TABLE query.
@@ -271,6 +320,10 @@ pub fn update_queries<C>(
         }
     }
 
+    for version in applied_versions {
+        queries.push((config.history_query)(version, template_name, db_name));
+    }
+
     queries.push((config.config_update_query)(&latest_version, template_name, db_name));
 
     Ok(queries)
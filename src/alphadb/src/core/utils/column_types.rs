@@ -0,0 +1,175 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Portable column types, so one version source can define its columns for every engine.
+
+use crate::engine::AlphaDBEngine;
+use serde_json::Value;
+
+/// Column type names that can be used on every engine, with the type they become on
+/// MySQL, MariaDB, PostgreSQL and SQLite. Every portable name is replaced, by the same
+/// name on engines that support it.
+/// A `BOOLEAN` stored as `TINYINT` gets a `1`/`0` default instead of `true`/`false`.
+pub const PORTABLE_COLUMN_TYPES: [(&str, [&str; 4]); 8] = [
+    ("INTEGER", ["INT", "INT", "INTEGER", "INTEGER"]),
+    ("TINYINT", ["TINYINT", "TINYINT", "SMALLINT", "TINYINT"]),
+    ("BOOLEAN", ["TINYINT", "TINYINT", "BOOLEAN", "BOOLEAN"]),
+    ("FLOAT", ["FLOAT", "FLOAT", "REAL", "FLOAT"]),
+    ("DECIMAL", ["DECIMAL", "DECIMAL", "NUMERIC", "DECIMAL"]),
+    ("LONGTEXT", ["LONGTEXT", "LONGTEXT", "TEXT", "TEXT"]),
+    ("DATETIME", ["DATETIME", "DATETIME", "TIMESTAMP", "DATETIME"]),
+    ("JSON", ["JSON", "JSON", "JSONB", "JSON"]),
+];
+
+/// The type a portable column type becomes on `engine`, matched case-insensitively.
+/// `None` when `column_type` is not a portable type.
+///
+/// # Examples
+///
+/// ```rust
+/// use alphadb::core::utils::column_types::engine_column_type;
+/// use alphadb::engine::AlphaDBEngine;
+///
+/// assert_eq!(engine_column_type("json", AlphaDBEngine::PostgreSQL), Some("JSONB"));
+/// assert_eq!(engine_column_type("JSONB", AlphaDBEngine::MySQL), None);
+/// ```
+pub fn engine_column_type(column_type: &str, engine: AlphaDBEngine) -> Option<&'static str> {
+    let index = match engine {
+        AlphaDBEngine::MySQL => 0,
+        AlphaDBEngine::MariaDB => 1,
+        AlphaDBEngine::PostgreSQL => 2,
+        AlphaDBEngine::SQLite => 3,
+    };
+
+    PORTABLE_COLUMN_TYPES
+        .iter()
+        .find(|(portable, _)| portable.eq_ignore_ascii_case(column_type))
+        .map(|(_, types)| types[index])
+}
+
+/// The `1`/`0` default of a `BOOLEAN` column stored as `TINYINT`. `None` for a default
+/// that is not a boolean, which keeps the column `BOOLEAN` so verification reports it.
+fn tinyint_boolean_default(default: &Value) -> Option<Value> {
+    match default {
+        Value::Bool(b) => Some(Value::from(*b as u8)),
+        Value::String(s) if s == "true" || s == "TRUE" => Some(Value::from(1)),
+        Value::String(s) if s == "false" || s == "FALSE" => Some(Value::from(0)),
+        _ => None,
+    }
+}
+
+/// `get_mut` instead of indexing, which would add the missing keys to the version source
+fn resolve_columns(columns: Option<&mut Value>, engine: AlphaDBEngine) {
+    for column in columns.and_then(Value::as_object_mut).into_iter().flat_map(|c| c.values_mut()) {
+        let Some(column_type) = column["type"].as_str() else {
+            continue;
+        };
+        let Some(engine_type) = engine_column_type(column_type, engine) else {
+            continue;
+        };
+
+        if column_type.eq_ignore_ascii_case("BOOLEAN") && engine_type == "TINYINT" {
+            match column.get("default").filter(|d| !d.is_null()).map(tinyint_boolean_default) {
+                Some(Some(default)) => column["default"] = default,
+                Some(None) => continue,
+                None => {}
+            }
+        }
+
+        column["type"] = Value::from(engine_type);
+    }
+}
+
+/// Replace the portable column types of every `createtable`, `addcolumn` and `modifycolumn`
+/// block in the version source with the types of `engine`
+pub fn resolve_portable_column_types(version_source: &mut Value, engine: AlphaDBEngine) {
+    for version in version_source["version"].as_array_mut().into_iter().flatten() {
+        for table in version.get_mut("createtable").and_then(Value::as_object_mut).into_iter().flat_map(|t| t.values_mut()) {
+            resolve_columns(Some(table), engine);
+        }
+
+        for table in version.get_mut("altertable").and_then(Value::as_object_mut).into_iter().flat_map(|t| t.values_mut()) {
+            resolve_columns(table.get_mut("addcolumn"), engine);
+            resolve_columns(table.get_mut("modifycolumn"), engine);
+        }
+    }
+}
+
+#[cfg(test)]
+mod column_types_tests {
+    use super::resolve_portable_column_types;
+    use crate::engine::AlphaDBEngine;
+    use serde_json::json;
+
+    #[test]
+    fn resolve() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"product": {
+                "primary_key": "id",
+                "id": {"type": "integer"},
+                "data": {"type": "JSON"},
+                "name": {"type": "VARCHAR", "length": 50},
+            }}},
+            {"_id": "0.0.2", "altertable": {"product": {
+                "addcolumn": {"created_at": {"type": "DATETIME"}},
+                "modifycolumn": {"name": {"type": "LONGTEXT"}},
+            }}},
+        ]});
+
+        let mut postgres = version_source.clone();
+        resolve_portable_column_types(&mut postgres, AlphaDBEngine::PostgreSQL);
+        let product = &postgres["version"][0]["createtable"]["product"];
+        assert_eq!(product["id"]["type"], "INTEGER");
+        assert_eq!(product["data"]["type"], "JSONB");
+        assert_eq!(product["name"]["type"], "VARCHAR");
+        assert_eq!(product["primary_key"], "id");
+        assert_eq!(postgres["version"][1]["altertable"]["product"]["addcolumn"]["created_at"]["type"], "TIMESTAMP");
+        assert_eq!(postgres["version"][1]["altertable"]["product"]["modifycolumn"]["name"]["type"], "TEXT");
+
+        let mut mysql = version_source.clone();
+        resolve_portable_column_types(&mut mysql, AlphaDBEngine::MySQL);
+        assert_eq!(mysql["version"][0]["createtable"]["product"]["id"]["type"], "INT");
+        assert_eq!(mysql["version"][0]["createtable"]["product"]["data"]["type"], "JSON");
+        assert_eq!(mysql["version"][1]["altertable"]["product"]["addcolumn"]["created_at"]["type"], "DATETIME");
+
+        // Nothing else is added to the version source
+        assert_eq!(mysql["version"][0].get("altertable"), None);
+    }
+
+    #[test]
+    fn boolean_default() {
+        let mut version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"product": {
+                "active": {"type": "BOOLEAN", "default": true},
+                "archived": {"type": "BOOLEAN", "default": "false"},
+                "hidden": {"type": "BOOLEAN", "default": "no"},
+                "deleted": {"type": "BOOLEAN"},
+            }}},
+        ]});
+
+        let mut postgres = version_source.clone();
+        resolve_portable_column_types(&mut postgres, AlphaDBEngine::PostgreSQL);
+        assert_eq!(postgres["version"][0]["createtable"]["product"]["active"], json!({"type": "BOOLEAN", "default": true}));
+
+        resolve_portable_column_types(&mut version_source, AlphaDBEngine::MySQL);
+        let product = &version_source["version"][0]["createtable"]["product"];
+        assert_eq!(product["active"], json!({"type": "TINYINT", "default": 1}));
+        assert_eq!(product["archived"], json!({"type": "TINYINT", "default": 0}));
+        assert_eq!(product["deleted"], json!({"type": "TINYINT"}));
+
+        // An invalid default is left for verification to report
+        assert_eq!(product["hidden"], json!({"type": "BOOLEAN", "default": "no"}));
+    }
+}
@@ -1,7 +1,7 @@
+use crate::core::query::identifier::IdentifierQuote;
 use crate::core::utils::errors::AlphaDBError;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::fmt;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -10,14 +10,14 @@ pub enum Expr {
     Value { value: Value },
 }
 
-impl fmt::Display for Expr {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Expr {
+    pub fn to_sql(&self, quote: IdentifierQuote) -> String {
         match self {
-            Expr::Column { name } => write!(f, "{name}"),
+            Expr::Column { name } => quote.quote(name),
             Expr::Value { value } => match value {
-                Value::String(s) => write!(f, "'{}'", s.replace('\'', "''")),
-                Value::Null => write!(f, "NULL"),
-                _ => write!(f, "{value}"),
+                Value::String(s) => format!("'{}'", s.replace('\'', "''")),
+                Value::Null => "NULL".to_string(),
+                _ => value.to_string(),
             },
         }
     }
@@ -93,29 +93,33 @@ fn format_values(values: &[Value]) -> String {
 }
 
 impl Condition {
-    pub fn to_sql(&self) -> String {
+    /// Render the condition, quoting column names with `quote`
+    pub fn to_sql(&self, quote: IdentifierQuote) -> String {
         match self {
             Condition::Comparison { op, left, right } => {
-                format!("{left} {} {right}", op.as_sql())
+                format!("{} {} {}", left.to_sql(quote), op.as_sql(), right.to_sql(quote))
             }
-            Condition::And { conditions } => conditions.iter().map(|c| format!("({})", c.to_sql())).collect::<Vec<_>>().join(" AND "),
-            Condition::Or { conditions } => conditions.iter().map(|c| format!("({})", c.to_sql())).collect::<Vec<_>>().join(" OR "),
+            Condition::And { conditions } => conditions.iter().map(|c| format!("({})", c.to_sql(quote))).collect::<Vec<_>>().join(" AND "),
+            Condition::Or { conditions } => conditions.iter().map(|c| format!("({})", c.to_sql(quote))).collect::<Vec<_>>().join(" OR "),
             Condition::Not { condition } => {
-                format!("NOT ({})", condition.to_sql())
+                format!("NOT ({})", condition.to_sql(quote))
             }
-            Condition::IsNull { column } => format!("{column} IS NULL"),
-            Condition::IsNotNull { column } => format!("{column} IS NOT NULL"),
+            Condition::IsNull { column } => format!("{} IS NULL", quote.quote(column)),
+            Condition::IsNotNull { column } => format!("{} IS NOT NULL", quote.quote(column)),
             Condition::In { column, values } => {
-                format!("{column} IN ({})", format_values(values))
+                format!("{} IN ({})", quote.quote(column), format_values(values))
             }
             Condition::NotIn { column, values } => {
-                format!("{column} NOT IN ({})", format_values(values))
+                format!("{} NOT IN ({})", quote.quote(column), format_values(values))
             }
         }
     }
 }
 
-pub fn condition_to_sql(json: &Value) -> Result<String, AlphaDBError> {
-    let condition: Condition = serde_json::from_value(json.clone())?;
-    Ok(condition.to_sql())
+pub fn parse_condition(json: &Value) -> Result<Condition, AlphaDBError> {
+    Ok(serde_json::from_value(json.clone())?)
+}
+
+pub fn condition_to_sql(json: &Value, quote: IdentifierQuote) -> Result<String, AlphaDBError> {
+    Ok(parse_condition(json)?.to_sql(quote))
 }
@@ -16,7 +16,9 @@
 pub mod column;
 pub mod default_data;
 pub mod primary_key;
+pub mod schema;
 pub mod table;
 pub mod version_source;
 
+pub use schema::consolidate_schema;
 pub use version_source::consolidate_version_source;
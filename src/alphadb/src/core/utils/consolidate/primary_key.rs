@@ -13,6 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::core::query::primary_key::primary_key_columns;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::json::{array_iter, exists_in_object, get_json_string};
 use crate::core::utils::version_number::parse_version_number;
@@ -80,11 +81,47 @@ pub fn get_primary_key<'a>(version_list: &'a Vec<Value>, table_name: &str, targe
     Ok(primary_key)
 }
 
+/// Get the primary key columns of a table after applying `versions`, in order
+///
+/// Unlike [`get_primary_key`] this follows composite (array) primary keys. Dropping a
+/// column removes it from the key, invalid definitions are skipped.
+pub fn get_primary_key_columns<'a>(versions: impl IntoIterator<Item = &'a Value>, table_name: &str) -> Vec<String> {
+    let mut primary_key: Vec<String> = Vec::new();
+
+    for version in versions {
+        let created = &version["createtable"][table_name];
+        if created.is_object() {
+            primary_key = primary_key_columns(&created["primary_key"]).unwrap_or_default();
+        }
+
+        let altered = &version["altertable"][table_name];
+        if !altered.is_object() {
+            continue;
+        }
+
+        if let Some(new_primary_key) = altered.get("primary_key") {
+            primary_key = primary_key_columns(new_primary_key).unwrap_or_default();
+        }
+
+        for dropped in altered["dropcolumn"].as_array().into_iter().flatten() {
+            primary_key.retain(|c| dropped != c.as_str());
+        }
+
+        for column in primary_key.iter_mut() {
+            if let Some(new_name) = altered["renamecolumn"][column.as_str()].as_str() {
+                *column = new_name.to_string();
+            }
+        }
+    }
+
+    primary_key
+}
+
 #[cfg(test)]
 mod get_primary_key_tests {
     use crate::core::utils::version_source::get_version_array;
 
-    use super::get_primary_key;
+    use super::{get_primary_key, get_primary_key_columns};
     use serde_json::json;
 
     #[test]
@@ -237,4 +274,17 @@ mod get_primary_key_tests {
             Some("email_address")
         );
     }
+
+    #[test]
+    fn composite_columns() {
+        let versions = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {"primary_key": ["a", "b", "c"]}}},
+            {"_id": "0.0.2", "altertable": {"table": {"dropcolumn": ["b"], "renamecolumn": {"c": "d"}}}},
+        ]});
+        let versions = get_version_array(&versions).unwrap();
+
+        assert_eq!(get_primary_key_columns(&versions[..1], "table"), ["a", "b", "c"]);
+        assert_eq!(get_primary_key_columns(versions, "table"), ["a", "d"]);
+        assert!(get_primary_key_columns(versions, "other").is_empty());
+    }
 }
@@ -0,0 +1,103 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use serde_json::{json, Value};
+
+use crate::core::utils::{
+    errors::AlphaDBError,
+    json::{get_json_object, get_json_string, get_object_keys},
+    version_number::parse_version_number,
+};
+
+use super::table::consolidate_table;
+
+/// Consolidate all tables into a single `createtable` object
+///
+/// The result is the schema as it is after applying every version up to and including
+/// `target_version` (all versions when `None`). Tables that are not created yet at the
+/// target version are left out. `version_list` must be sorted.
+pub fn consolidate_schema(version_list: &Vec<Value>, target_version: Option<&str>) -> Result<Value, AlphaDBError> {
+    let mut versions: Vec<Value> = Vec::new();
+
+    for version in version_list {
+        if let Some(target_version) = target_version {
+            if parse_version_number(get_json_string(&version["_id"])?)? > parse_version_number(target_version)? {
+                break;
+            }
+        }
+
+        versions.push(version.clone());
+    }
+
+    // Get all table names
+    let mut tables: Vec<String> = Vec::new();
+    for version in versions.iter() {
+        if !get_object_keys(version)?.contains(&&"createtable".to_string()) {
+            continue;
+        }
+
+        for table in get_object_keys(&version["createtable"])? {
+            if !tables.contains(table) {
+                tables.push(table.to_string());
+            }
+        }
+    }
+
+    let mut schema = json!({});
+    for table in tables {
+        let consolidated_table = consolidate_table(&versions, table.as_str(), None)?;
+
+        if !get_json_object(&consolidated_table)?.is_empty() {
+            schema[table] = consolidated_table;
+        }
+    }
+
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod consolidate_schema_tests {
+    use super::consolidate_schema;
+    use crate::core::utils::version_source::get_version_array;
+    use serde_json::json;
+
+    #[test]
+    fn schema_at_target_version() {
+        let versions = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table1": {"primary_key": "id", "id": {"type": "INT"}, "col1": {"type": "VARCHAR", "length": 200}}}},
+            {"_id": "0.0.2", "altertable": {"table1": {"modifycolumn": {"col1": {"recreate": false, "length": 300}}}}},
+            {"_id": "0.0.3", "createtable": {"table2": {"col1": {"type": "INT"}}}},
+        ]});
+
+        let result = json!({
+            "table1": {"primary_key": "id", "id": {"type": "INT"}, "col1": {"type": "VARCHAR", "length": 300}}
+        });
+        assert_eq!(consolidate_schema(get_version_array(&versions).unwrap(), Some("0.0.2")).unwrap(), result);
+    }
+
+    #[test]
+    fn schema_at_latest_version() {
+        let versions = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table1": {"col1": {"type": "VARCHAR", "length": 200}}}},
+            {"_id": "0.0.2", "createtable": {"table2": {"col1": {"type": "INT"}}}},
+        ]});
+
+        let result = json!({
+            "table1": {"col1": {"type": "VARCHAR", "length": 200}},
+            "table2": {"col1": {"type": "INT"}}
+        });
+        assert_eq!(consolidate_schema(get_version_array(&versions).unwrap(), None).unwrap(), result);
+    }
+}
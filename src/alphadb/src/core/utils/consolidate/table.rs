@@ -40,8 +40,9 @@ pub fn consolidate_table(version_list: &Vec<Value>, table_name: &str, target_ver
             if exists_in_object(&version["createtable"], table_name)? {
                 let cols = get_object_keys(&version["createtable"][table_name])?;
 
+                // Only objects are columns, foreign_key, check and index hold arrays
                 for col in cols {
-                    if *col != "primary_key".to_string() {
+                    if version["createtable"][table_name][col].is_object() {
                         columns.push(col.to_string());
                     }
                 }
@@ -163,6 +164,24 @@ mod consolidate_table_tests {
         assert_eq!(consolidate_table(get_version_array(&versions).unwrap(), "table", None).unwrap(), result);
     }
 
+    #[test]
+    fn table_constraints() {
+        let versions = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {
+                "primary_key": "id",
+                "id": {"type": "INTEGER"},
+                "foreign_key": [{"name": "table_id_fk", "from": "id", "references": "other", "to": "id"}],
+                "index": [{"name": "idx", "columns": ["id"]}]
+            }}},
+        ]});
+
+        let result = json!({
+            "primary_key": "id",
+            "id": {"type": "INTEGER"}
+        });
+        assert_eq!(consolidate_table(get_version_array(&versions).unwrap(), "table", None).unwrap(), result);
+    }
+
     #[test]
     fn consolidate_with_target_version() {
         let versions = json!({"name": "test", "version": [
@@ -0,0 +1,248 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Semantic diff between two consolidated schemas, as produced by
+//! [`consolidate_schema`](crate::core::utils::consolidate::consolidate_schema).
+//! Columns are matched by name, so a renamed column shows up as removed and added.
+
+use crate::core::utils::{errors::AlphaDBError, json::get_json_object};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// A single changed attribute of a column or table. `None` means the attribute is not set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttributeDiff {
+    pub attribute: String,
+    pub from: Option<Value>,
+    pub to: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "lowercase")]
+pub enum ColumnDiff {
+    Added { column: String, definition: Value },
+    Removed { column: String, definition: Value },
+    Modified { column: String, attributes: Vec<AttributeDiff> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "lowercase")]
+pub enum TableDiff {
+    Added {
+        table: String,
+        definition: Value,
+    },
+    Removed {
+        table: String,
+        definition: Value,
+    },
+    Modified {
+        table: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        primary_key: Option<AttributeDiff>,
+        columns: Vec<ColumnDiff>,
+    },
+}
+
+/// Keys of `from` followed by the keys only present in `to`, keeping the order of definition
+fn merged_keys<'a>(from: &'a Map<String, Value>, to: &'a Map<String, Value>) -> Vec<&'a String> {
+    let mut keys: Vec<&String> = from.keys().collect();
+    keys.extend(to.keys().filter(|k| !from.contains_key(*k)));
+    keys
+}
+
+/// Get the attributes that differ between two column definitions
+pub fn diff_column(from: &Value, to: &Value) -> Result<Vec<AttributeDiff>, AlphaDBError> {
+    let from = get_json_object(from)?;
+    let to = get_json_object(to)?;
+    let mut attributes = Vec::new();
+
+    for key in merged_keys(from, to) {
+        if from.get(key) != to.get(key) {
+            attributes.push(AttributeDiff {
+                attribute: key.clone(),
+                from: from.get(key).cloned(),
+                to: to.get(key).cloned(),
+            });
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Get the differences between two consolidated tables.
+/// Returns `None` if the tables are the same.
+pub fn diff_table(table: &str, from: &Value, to: &Value) -> Result<Option<TableDiff>, AlphaDBError> {
+    let from_columns = get_json_object(from)?;
+    let to_columns = get_json_object(to)?;
+    let mut columns = Vec::new();
+
+    for column in merged_keys(from_columns, to_columns) {
+        if column == "primary_key" {
+            continue;
+        }
+
+        match (from_columns.get(column), to_columns.get(column)) {
+            (Some(from_definition), None) => columns.push(ColumnDiff::Removed {
+                column: column.clone(),
+                definition: from_definition.clone(),
+            }),
+            (None, Some(to_definition)) => columns.push(ColumnDiff::Added {
+                column: column.clone(),
+                definition: to_definition.clone(),
+            }),
+            (Some(from_definition), Some(to_definition)) => {
+                let attributes = diff_column(from_definition, to_definition)?;
+                if !attributes.is_empty() {
+                    columns.push(ColumnDiff::Modified {
+                        column: column.clone(),
+                        attributes,
+                    });
+                }
+            }
+            (None, None) => (),
+        }
+    }
+
+    let mut primary_key = None;
+    if from_columns.get("primary_key") != to_columns.get("primary_key") {
+        primary_key = Some(AttributeDiff {
+            attribute: "primary_key".to_string(),
+            from: from_columns.get("primary_key").cloned(),
+            to: to_columns.get("primary_key").cloned(),
+        });
+    }
+
+    if columns.is_empty() && primary_key.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(TableDiff::Modified {
+        table: table.to_string(),
+        primary_key,
+        columns,
+    }))
+}
+
+/// Get the differences between two consolidated schemas
+pub fn diff_schema(from: &Value, to: &Value) -> Result<Vec<TableDiff>, AlphaDBError> {
+    let from_tables = get_json_object(from)?;
+    let to_tables = get_json_object(to)?;
+    let mut diff = Vec::new();
+
+    for table in merged_keys(from_tables, to_tables) {
+        match (from_tables.get(table), to_tables.get(table)) {
+            (Some(from_definition), None) => diff.push(TableDiff::Removed {
+                table: table.clone(),
+                definition: from_definition.clone(),
+            }),
+            (None, Some(to_definition)) => diff.push(TableDiff::Added {
+                table: table.clone(),
+                definition: to_definition.clone(),
+            }),
+            (Some(from_definition), Some(to_definition)) => {
+                if let Some(table_diff) = diff_table(table, from_definition, to_definition)? {
+                    diff.push(table_diff);
+                }
+            }
+            (None, None) => (),
+        }
+    }
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn identical_schemas() {
+        let schema = json!({"table1": {"col1": {"type": "INT"}}});
+        assert_eq!(diff_schema(&schema, &schema).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn added_and_removed_tables() {
+        let from = json!({"table1": {"col1": {"type": "INT"}}});
+        let to = json!({"table2": {"col1": {"type": "TEXT"}}});
+
+        assert_eq!(
+            diff_schema(&from, &to).unwrap(),
+            Vec::from([
+                TableDiff::Removed {
+                    table: "table1".to_string(),
+                    definition: json!({"col1": {"type": "INT"}})
+                },
+                TableDiff::Added {
+                    table: "table2".to_string(),
+                    definition: json!({"col1": {"type": "TEXT"}})
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn modified_columns() {
+        let from = json!({"table1": {"primary_key": "id", "id": {"type": "INT"}, "col1": {"type": "VARCHAR", "length": 100}, "col2": {"type": "TEXT"}}});
+        let to = json!({"table1": {"primary_key": "id", "id": {"type": "INT"}, "col1": {"type": "VARCHAR", "length": 200, "null": true}, "col3": {"type": "TEXT"}}});
+
+        assert_eq!(
+            diff_schema(&from, &to).unwrap(),
+            Vec::from([TableDiff::Modified {
+                table: "table1".to_string(),
+                primary_key: None,
+                columns: Vec::from([
+                    ColumnDiff::Modified {
+                        column: "col1".to_string(),
+                        attributes: Vec::from([
+                            AttributeDiff {
+                                attribute: "length".to_string(),
+                                from: Some(json!(100)),
+                                to: Some(json!(200))
+                            },
+                            AttributeDiff {
+                                attribute: "null".to_string(),
+                                from: None,
+                                to: Some(json!(true))
+                            },
+                        ])
+                    },
+                    ColumnDiff::Removed {
+                        column: "col2".to_string(),
+                        definition: json!({"type": "TEXT"})
+                    },
+                    ColumnDiff::Added {
+                        column: "col3".to_string(),
+                        definition: json!({"type": "TEXT"})
+                    },
+                ])
+            }])
+        );
+    }
+
+    #[test]
+    fn changed_primary_key() {
+        let from = json!({"table1": {"primary_key": "id", "id": {"type": "INT"}}});
+        let to = json!({"table1": {"primary_key": "uuid", "id": {"type": "INT"}}});
+
+        let diff = serde_json::to_value(diff_schema(&from, &to).unwrap()).unwrap();
+        assert_eq!(
+            diff,
+            json!([{"change": "modified", "table": "table1", "primary_key": {"attribute": "primary_key", "from": "id", "to": "uuid"}, "columns": []}])
+        );
+    }
+}
@@ -13,7 +13,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::core::utils::source_map::SourceLocation;
 use crate::core::verification::issue::{VerificationIssue, VerificationIssueLevel, VersionTrace};
+use crate::core::verification::rules::INVALID_STRUCTURE;
 use serde_json::Error as SerdeJsonError;
 use thiserror::Error;
 
@@ -22,6 +24,8 @@ pub struct AlphaDBError {
     pub message: String,
     pub error: String,
     pub version_trace: VersionTrace,
+    /// Position in the version source the error refers to, when known
+    pub location: Option<SourceLocation>,
 }
 
 pub trait Get {
@@ -88,6 +92,8 @@ impl ToVerificationIssue for AlphaDBError {
             message: self.message.clone(),
             level: VerificationIssueLevel::Critical,
             version_trace: self.version_trace.clone(),
+            rule: Some(INVALID_STRUCTURE.id),
+            location: self.location.clone(),
         };
 
         if !verification_issues.contains(&issue) {
@@ -101,6 +107,7 @@ impl Default for AlphaDBError {
             message: String::new(),
             error: String::new(),
             version_trace: VersionTrace::new(),
+            location: None,
         }
     }
 }
@@ -108,6 +115,12 @@ impl Default for AlphaDBError {
 impl From<SerdeJsonError> for AlphaDBError {
     fn from(error: SerdeJsonError) -> Self {
         AlphaDBError {
+            // Syntax errors are reported with their position in the parsed text
+            location: (error.line() > 0).then(|| SourceLocation {
+                file: None,
+                line: error.line(),
+                column: error.column(),
+            }),
             message: error.to_string(),
             error: "serde-json-error".to_string(),
             version_trace: VersionTrace::new(),
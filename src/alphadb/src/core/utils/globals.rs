@@ -14,3 +14,4 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 pub const CONFIG_TABLE_NAME: &str = "adb_conf";
+pub const HISTORY_TABLE_NAME: &str = "adb_history";
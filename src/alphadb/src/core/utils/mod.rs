@@ -13,14 +13,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+pub mod column_types;
 mod condition;
 pub mod consolidate;
+pub mod diff;
 pub mod error_messages;
 pub mod errors;
 pub mod globals;
 pub mod json;
+pub mod source_map;
 pub mod types;
 pub mod version_number;
 pub mod version_source;
 
-pub use condition::condition_to_sql;
+pub use condition::{condition_to_sql, parse_condition};
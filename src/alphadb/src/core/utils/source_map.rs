@@ -0,0 +1,322 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::core::verification::issue::VersionTrace;
+
+/// A position in a version source. Lines and columns start at 1, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// File the position is in, `None` when the version source was not read from a file
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file.display(), self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+/// Locations of the object keys and array items in a version source, by their path from
+/// the root. Array items are addressed by their index, e.g. `["version", "0", "createtable"]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceMap {
+    locations: HashMap<Vec<String>, SourceLocation>,
+}
+
+impl SourceMap {
+    /// Map the keys and array items of a JSON document. Scanning stops at the first syntax
+    /// error, which is left for the JSON parser to report.
+    pub fn from_json(source: &str, file: Option<&Path>) -> Self {
+        let mut scanner = Scanner {
+            chars: source.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+            file: file.map(Path::to_path_buf),
+            locations: HashMap::new(),
+        };
+
+        scanner.skip_whitespace();
+        let root = scanner.location();
+        scanner.locations.insert(Vec::new(), root);
+        scanner.value(&mut Vec::new());
+
+        SourceMap { locations: scanner.locations }
+    }
+
+    /// Add every location of `other` below `prefix`. Used for version sources that are
+    /// combined from several files.
+    pub fn insert(&mut self, prefix: &[String], other: SourceMap) {
+        for (path, location) in other.locations {
+            self.locations.insert(prefix.iter().cloned().chain(path).collect(), location);
+        }
+    }
+
+    /// Set the location of a single path
+    pub fn set(&mut self, path: Vec<String>, location: SourceLocation) {
+        self.locations.insert(path, location);
+    }
+
+    pub fn get(&self, path: &[String]) -> Option<&SourceLocation> {
+        self.locations.get(path)
+    }
+
+    /// Find the location a version trace points at. Follows the trace as deep as the source
+    /// allows, so a trace into a value that is not in the source still points at its parent.
+    pub fn locate(&self, version_source: &Value, version_trace: &VersionTrace) -> Option<SourceLocation> {
+        let mut trace = version_trace.iter();
+        let version = trace.next()?;
+
+        let mut path = vec!["version".to_string()];
+        if let Some(index) = version.strip_prefix("index ") {
+            path.push(index.to_string());
+        } else if version != "versions" {
+            let index = version_source["version"].as_array()?.iter().position(|v| v["_id"] == version.as_str())?;
+            path.push(index.to_string());
+        }
+
+        for item in trace {
+            let key = ["table:", "column:", "item:"].iter().find_map(|prefix| item.strip_prefix(prefix)).unwrap_or(item);
+            match self.child(&path, key) {
+                Some(child) => path = child,
+                None => break,
+            }
+        }
+
+        self.get(&path).cloned()
+    }
+
+    /// Path of the child `key` of `path`. Traces skip some levels, like `modifycolumn`
+    /// between a table and its column, so grandchildren are searched as well.
+    fn child(&self, path: &[String], key: &str) -> Option<Vec<String>> {
+        let mut child = path.to_vec();
+        child.push(key.to_string());
+        if self.locations.contains_key(&child) {
+            return Some(child);
+        }
+
+        self.locations
+            .iter()
+            .filter(|(p, _)| p.len() == path.len() + 2 && p.starts_with(path) && p[path.len() + 1] == key)
+            .min_by_key(|(_, location)| (location.line, location.column))
+            .map(|(p, _)| p.clone())
+    }
+}
+
+struct Scanner {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+    file: Option<PathBuf>,
+    locations: HashMap<Vec<String>, SourceLocation>,
+}
+
+impl Scanner {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn location(&self) -> SourceLocation {
+        SourceLocation {
+            file: self.file.clone(),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Option<()> {
+        self.skip_whitespace();
+        (self.bump()? == expected).then_some(())
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+        loop {
+            match self.bump()? {
+                '"' => return Some(string),
+                '\\' => match self.bump()? {
+                    'n' => string.push('\n'),
+                    't' => string.push('\t'),
+                    'r' => string.push('\r'),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'u' => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        string.push(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    c => string.push(c),
+                },
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn value(&mut self, path: &mut Vec<String>) -> Option<()> {
+        self.skip_whitespace();
+
+        match self.peek()? {
+            '{' => {
+                self.bump();
+                self.skip_whitespace();
+                if self.peek()? == '}' {
+                    self.bump();
+                    return Some(());
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let location = self.location();
+                    path.push(self.string()?);
+                    self.locations.insert(path.clone(), location);
+
+                    self.expect(':')?;
+                    self.value(path)?;
+                    path.pop();
+
+                    self.skip_whitespace();
+                    match self.bump()? {
+                        ',' => continue,
+                        '}' => return Some(()),
+                        _ => return None,
+                    }
+                }
+            }
+            '[' => {
+                self.bump();
+                self.skip_whitespace();
+                if self.peek()? == ']' {
+                    self.bump();
+                    return Some(());
+                }
+
+                for i in 0.. {
+                    self.skip_whitespace();
+                    path.push(i.to_string());
+                    self.locations.insert(path.clone(), self.location());
+                    self.value(path)?;
+                    path.pop();
+
+                    self.skip_whitespace();
+                    match self.bump()? {
+                        ',' => continue,
+                        ']' => return Some(()),
+                        _ => return None,
+                    }
+                }
+                None
+            }
+            '"' => self.string().map(|_| ()),
+            _ => {
+                while self.peek().is_some_and(|c| !c.is_whitespace() && !matches!(c, ',' | ']' | '}')) {
+                    self.bump();
+                }
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod source_map_tests {
+    use super::{SourceLocation, SourceMap};
+    use crate::core::verification::issue::VersionTrace;
+    use serde_json::Value;
+    use std::path::Path;
+
+    const SOURCE: &str = r#"{
+    "name": "test",
+    "version": [
+        {
+            "_id": "0.0.1",
+            "createtable": {
+                "user": {"id": {"type": "INT"}, "na\"me": {"type": "TEXT"}}
+            }
+        },
+        {
+            "_id": "0.0.2",
+            "altertable": {"user": {"modifycolumn": {"id": {"type": "BIGINT"}}}},
+            "default_data": {"user": [{"id": 1}, {"id": 2}]}
+        }
+    ]
+}"#;
+
+    fn locate(source_map: &SourceMap, trace: &[&str]) -> Option<(usize, usize)> {
+        let version_source: Value = serde_json::from_str(SOURCE).unwrap();
+        source_map.locate(&version_source, &VersionTrace::from(trace.to_vec())).map(|l| (l.line, l.column))
+    }
+
+    #[test]
+    fn key_locations() {
+        let source_map = SourceMap::from_json(SOURCE, None);
+        let path = |p: &[&str]| p.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(source_map.get(&path(&["name"])).map(|l| (l.line, l.column)), Some((2, 5)));
+        assert_eq!(source_map.get(&path(&["version", "1"])).map(|l| (l.line, l.column)), Some((10, 9)));
+        assert_eq!(
+            source_map.get(&path(&["version", "0", "createtable", "user", "na\"me"])).map(|l| (l.line, l.column)),
+            Some((7, 49))
+        );
+    }
+
+    #[test]
+    fn locate_traces() {
+        let source_map = SourceMap::from_json(SOURCE, None);
+
+        assert_eq!(locate(&source_map, &["0.0.1", "createtable", "user", "id"]), Some((7, 26)));
+        // Columns in alterations are nested below the alter method
+        assert_eq!(locate(&source_map, &["0.0.2", "altertable", "table:user", "column:id"]), Some((12, 54)));
+        assert_eq!(locate(&source_map, &["0.0.2", "default_data", "table:user", "item:1"]), Some((13, 50)));
+        // Traces into values that are not in the source point at the deepest known key
+        assert_eq!(locate(&source_map, &["index 1", "altertable", "table:account"]), Some((12, 13)));
+        assert_eq!(locate(&source_map, &["0.0.9", "altertable"]), None);
+    }
+
+    #[test]
+    fn combined_files() {
+        let mut source_map = SourceMap::from_json(r#"{"name": "test", "version": []}"#, None);
+        source_map.insert(
+            &["version".to_string(), "0".to_string()],
+            SourceMap::from_json("{\n  \"createtable\": {}\n}", Some(Path::new("versions/0.0.1-init.json"))),
+        );
+
+        assert_eq!(
+            source_map.get(&["version".to_string(), "0".to_string(), "createtable".to_string()]),
+            Some(&SourceLocation {
+                file: Some("versions/0.0.1-init.json".into()),
+                line: 2,
+                column: 3
+            })
+        );
+    }
+}
@@ -53,6 +53,7 @@ pub fn sort_versions(versions: &mut [serde_json::Value]) -> Result<(), AlphaDBEr
             message: "No version number specified".to_string(),
             error: "missing-version-number".to_string(),
             version_trace: VersionTrace::from([format!("index {}", i)]),
+            location: None,
         })?;
 
         let key = parse_version_number(id)?;
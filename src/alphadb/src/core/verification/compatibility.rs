@@ -17,7 +17,10 @@ use serde_json::Value;
 
 use crate::core::{
     utils::consolidate::column::get_column_type,
-    verification::issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+    verification::{
+        issue::{VerificationIssue, VersionTrace},
+        rules::INCOMPATIBLE_TYPE_ATTRIBUTE,
+    },
 };
 
 /// Compatibility rule for type-attribute incompatibilities
@@ -37,11 +40,10 @@ pub fn verify_column_type_compatibility(
 ) {
     for rule in rules {
         if !check_column_type_compatibility(checking_type, rule, column_keys) {
-            issues.push(VerificationIssue {
-                level: VerificationIssueLevel::Critical,
-                message: format!("Column type {} is incompatible with attribute {}", checking_type, rule.attribute.to_uppercase()),
-                version_trace: version_trace.clone(),
-            });
+            issues.push(INCOMPATIBLE_TYPE_ATTRIBUTE.issue(
+                format!("Column type {} is incompatible with attribute {}", checking_type, rule.attribute.to_uppercase()),
+                version_trace.clone(),
+            ));
         }
     }
 }
@@ -0,0 +1,166 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::{
+    utils::errors::AlphaDBError,
+    verification::{
+        issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+        rules::{find_rule_in, Rule, INVALID_SUPPRESSION, UNKNOWN_RULE},
+    },
+};
+
+/// Project wide verification settings, e.g.
+///
+/// ```json
+/// {
+///     "disable": ["ADB0101", "empty-block"],
+///     "severity": {"ADB0035": "critical"}
+/// }
+/// ```
+///
+/// Rules are referenced by id or name.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VerificationConfig {
+    /// Rules whose issues are left out
+    pub disable: Vec<String>,
+
+    /// Level overrides per rule
+    pub severity: HashMap<String, VerificationIssueLevel>,
+}
+
+impl VerificationConfig {
+    pub fn from_json(config: &str) -> Result<Self, AlphaDBError> {
+        serde_json::from_str(config).map_err(|e| AlphaDBError {
+            message: format!("The verification config is invalid: {e}"),
+            error: "invalid-verification-config".to_string(),
+            version_trace: VersionTrace::new(),
+            location: None,
+        })
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, AlphaDBError> {
+        let path = path.as_ref();
+        let config = fs::read_to_string(path).map_err(|e| AlphaDBError {
+            message: format!("Unable to read verification config '{}': {e}", path.display()),
+            error: "invalid-verification-config".to_string(),
+            version_trace: VersionTrace::new(),
+            location: None,
+        })?;
+
+        Self::from_json(&config)
+    }
+
+    /// Report rules referenced by the config that are neither built-in nor in `custom_rules`
+    pub fn verify(&self, custom_rules: &[Rule], issues: &mut Vec<VerificationIssue>) {
+        for key in self.disable.iter().chain(self.severity.keys()) {
+            if find_rule_in(key, custom_rules).is_none() {
+                issues.push(UNKNOWN_RULE.issue(format!("Rule `{key}` in the verification config does not exist."), VersionTrace::new()));
+            }
+        }
+    }
+
+    /// Drop the issues of disabled rules and apply level overrides. Rules are looked up
+    /// among the built-in rules and `custom_rules`.
+    pub fn apply(&self, custom_rules: &[Rule], issues: Vec<VerificationIssue>) -> Vec<VerificationIssue> {
+        issues
+            .into_iter()
+            .filter(|issue| !self.disable.iter().any(|key| issue_matches(issue, key, custom_rules)))
+            .map(|mut issue| {
+                if let Some((_, level)) = self.severity.iter().find(|(key, _)| issue_matches(&issue, key, custom_rules)) {
+                    issue.level = *level;
+                }
+                issue
+            })
+            .collect()
+    }
+}
+
+fn issue_matches(issue: &VerificationIssue, key: &str, custom_rules: &[Rule]) -> bool {
+    match (issue.rule, find_rule_in(key, custom_rules)) {
+        (Some(id), Some(rule)) => rule.id == id,
+        _ => false,
+    }
+}
+
+/// Rules ignored by a version's `_ignore` key, e.g. `"_ignore": ["ADB0012"]`. Custom rules can be ignored as well.
+pub fn ignored_rules(version: &Value, version_trace: &VersionTrace, custom_rules: &[Rule], issues: &mut Vec<VerificationIssue>) -> Vec<&'static str> {
+    let Some(ignore) = version.get("_ignore") else {
+        return Vec::new();
+    };
+
+    let Some(ignore) = ignore.as_array() else {
+        issues.push(INVALID_SUPPRESSION.issue("`_ignore` must be a list of rule ids.".to_string(), version_trace.clone()));
+        return Vec::new();
+    };
+
+    let mut rules = Vec::new();
+    for key in ignore {
+        let Some(key) = key.as_str() else {
+            issues.push(INVALID_SUPPRESSION.issue("`_ignore` must be a list of rule ids.".to_string(), version_trace.clone()));
+            continue;
+        };
+
+        match find_rule_in(key, custom_rules) {
+            Some(rule) => rules.push(rule.id),
+            None => issues.push(UNKNOWN_RULE.issue(format!("Rule `{key}` does not exist."), version_trace.clone())),
+        }
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod config_tests {
+    use super::{ignored_rules, VerificationConfig};
+    use crate::core::verification::{
+        issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+        rules::{DROPPED_COLUMN, EMPTY_BLOCK, FOREIGN_KEY_TYPE_MISMATCH},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn disable_and_override() {
+        let config = VerificationConfig::from_json(r#"{"disable": ["empty-block"], "severity": {"adb0035": "low"}}"#).unwrap();
+        let issues = vec![
+            EMPTY_BLOCK.issue("Does not contain any data".to_string(), VersionTrace::new()),
+            FOREIGN_KEY_TYPE_MISMATCH.issue("Type mismatch".to_string(), VersionTrace::new()),
+            DROPPED_COLUMN.issue("Dropped".to_string(), VersionTrace::new()),
+        ];
+
+        let issues = config.apply(&[], issues);
+        let levels: Vec<(Option<&str>, VerificationIssueLevel)> = issues.iter().map(|i| (i.rule, i.level)).collect();
+        assert_eq!(
+            levels,
+            vec![(Some("ADB0035"), VerificationIssueLevel::Low), (Some("ADB0101"), VerificationIssueLevel::High)]
+        );
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(VerificationConfig::from_json(r#"{"severity": {"ADB0101": "fatal"}}"#).is_err());
+        assert!(VerificationConfig::from_json(r#"{"disabled": []}"#).is_err());
+
+        let config = VerificationConfig::from_json(r#"{"disable": ["ADB9999"]}"#).unwrap();
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        config.verify(&[], &mut issues);
+        assert_eq!(issues[0].rule, Some("ADB0008"));
+    }
+
+    #[test]
+    fn ignore_key() {
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        let trace = VersionTrace::from(["0.0.1"]);
+
+        assert_eq!(
+            ignored_rules(&json!({"_ignore": ["ADB0012", "dropped-column", "nope"]}), &trace, &[], &mut issues),
+            vec!["ADB0012", "ADB0101"]
+        );
+        assert_eq!(ignored_rules(&json!({"_ignore": "ADB0012"}), &trace, &[], &mut issues), Vec::<&str>::new());
+
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(messages, vec!["Rule `nope` does not exist.", "`_ignore` must be a list of rule ids."]);
+    }
+}
@@ -0,0 +1,418 @@
+//! Custom verification rules
+//!
+//! Applications can check version sources against their own policies, like "every table
+//! has a `created_at` column", next to the built-in rules. A rule implements
+//! [`VerificationRule`], or is built from closures with [`CustomRule`], and is added with
+//! `AlphaDBVerification::with_rule`. Rules that belong together can be shared as a [`Ruleset`].
+//!
+//! Custom rules are described by a [`Rule`] like the built-in ones, so their id can be
+//! disabled or overridden in a `VerificationConfig` and ignored with a version's `_ignore`.
+//! Use an id of your own, built-in ids start with `ADB`.
+
+use serde_json::Value;
+
+use crate::core::{
+    utils::consolidate::schema::consolidate_schema,
+    verification::{
+        history::{version_number_of, versions_until},
+        issue::{VerificationIssue, VersionTrace},
+        rules::Rule,
+    },
+};
+
+/// A version as seen by a custom rule
+#[derive(Debug, Clone, Copy)]
+pub struct VersionContext<'a> {
+    /// Version number, e.g. `0.0.2`
+    pub version_number: &'a str,
+
+    /// The version object from the version source
+    pub version: &'a Value,
+
+    /// Engine of the version source, e.g. `postgres`
+    pub engine: &'a str,
+
+    /// All tables as they are after this version is applied, keyed by table name
+    pub schema: &'a Value,
+}
+
+/// A table that is created or altered in a version
+#[derive(Debug, Clone, Copy)]
+pub struct TableContext<'a> {
+    pub version: &'a VersionContext<'a>,
+
+    pub table_name: &'a str,
+
+    /// `createtable` or `altertable`
+    pub method: &'a str,
+
+    /// The table's block in the version
+    pub table_data: &'a Value,
+
+    /// The table as it is after this version is applied, `null` when it does not exist
+    pub table: &'a Value,
+}
+
+/// A column that is created, added or modified in a version
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnContext<'a> {
+    pub table: &'a TableContext<'a>,
+
+    pub column_name: &'a str,
+
+    /// The column's definition or modification in the version
+    pub column_data: &'a Value,
+
+    /// The column as it is after this version is applied, `null` when it does not exist
+    pub column: &'a Value,
+}
+
+/// A verification rule defined outside of AlphaDB
+///
+/// Every check returns `Err` with a message to report an issue. The issue gets the id and
+/// level of `rule()`, and points at the version, table or column that was checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use alphadb::verification::{Rule, TableContext, VerificationIssueLevel, VerificationRule};
+///
+/// struct CreatedAt;
+///
+/// impl VerificationRule for CreatedAt {
+///     fn rule(&self) -> Rule {
+///         Rule {
+///             id: "ACME001",
+///             name: "missing-created-at",
+///             level: VerificationIssueLevel::High,
+///             summary: "Every table must have a `created_at` column.",
+///             explanation: "Add a `created_at` DATETIME column to the table.",
+///         }
+///     }
+///
+///     fn check_table(&self, table: &TableContext) -> Result<(), String> {
+///         match table.table.get("created_at") {
+///             Some(_) => Ok(()),
+///             None => Err(format!("Table `{}` has no `created_at` column.", table.table_name)),
+///         }
+///     }
+/// }
+/// ```
+pub trait VerificationRule: Send + Sync {
+    /// Id, name, level and description of the rule
+    fn rule(&self) -> Rule;
+
+    /// Check a version, runs once for every version
+    fn check_version(&self, _version: &VersionContext) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Check a table, runs for every table in a `createtable` or `altertable` block
+    fn check_table(&self, _table: &TableContext) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Check a column, runs for every column in `createtable`, `addcolumn` and `modifycolumn`
+    fn check_column(&self, _column: &ColumnContext) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+type VersionCheck = Box<dyn Fn(&VersionContext) -> Result<(), String> + Send + Sync>;
+type TableCheck = Box<dyn Fn(&TableContext) -> Result<(), String> + Send + Sync>;
+type ColumnCheck = Box<dyn Fn(&ColumnContext) -> Result<(), String> + Send + Sync>;
+
+/// A custom rule built from closures
+///
+/// # Examples
+///
+/// ```rust
+/// use alphadb::verification::{CustomRule, Rule, VerificationIssueLevel};
+///
+/// const TEXT_PRIMARY_KEY: Rule = Rule {
+///     id: "ACME002",
+///     name: "text-primary-key",
+///     level: VerificationIssueLevel::Critical,
+///     summary: "Primary keys can not be TEXT columns.",
+///     explanation: "Use an integer or UUID primary key.",
+/// };
+///
+/// let rule = CustomRule::new(TEXT_PRIMARY_KEY).column(|column| {
+///     let is_primary_key = column.table.table["primary_key"] == column.column_name;
+///     match is_primary_key && column.column["type"] == "TEXT" {
+///         true => Err(format!("Primary key `{}` is a TEXT column.", column.column_name)),
+///         false => Ok(()),
+///     }
+/// });
+/// ```
+pub struct CustomRule {
+    rule: Rule,
+    version: Option<VersionCheck>,
+    table: Option<TableCheck>,
+    column: Option<ColumnCheck>,
+}
+
+impl CustomRule {
+    pub fn new(rule: Rule) -> Self {
+        CustomRule {
+            rule,
+            version: None,
+            table: None,
+            column: None,
+        }
+    }
+
+    /// Check every version with `check`
+    pub fn version(mut self, check: impl Fn(&VersionContext) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.version = Some(Box::new(check));
+        self
+    }
+
+    /// Check every created or altered table with `check`
+    pub fn table(mut self, check: impl Fn(&TableContext) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.table = Some(Box::new(check));
+        self
+    }
+
+    /// Check every created, added or modified column with `check`
+    pub fn column(mut self, check: impl Fn(&ColumnContext) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.column = Some(Box::new(check));
+        self
+    }
+}
+
+impl VerificationRule for CustomRule {
+    fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    fn check_version(&self, version: &VersionContext) -> Result<(), String> {
+        self.version.as_ref().map_or(Ok(()), |check| check(version))
+    }
+
+    fn check_table(&self, table: &TableContext) -> Result<(), String> {
+        self.table.as_ref().map_or(Ok(()), |check| check(table))
+    }
+
+    fn check_column(&self, column: &ColumnContext) -> Result<(), String> {
+        self.column.as_ref().map_or(Ok(()), |check| check(column))
+    }
+}
+
+/// A reusable set of custom rules, e.g. the policies shared by all projects of a company
+#[derive(Default)]
+pub struct Ruleset {
+    rules: Vec<Box<dyn VerificationRule>>,
+}
+
+impl Ruleset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule to the set
+    pub fn with_rule(mut self, rule: impl VerificationRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Add all rules of another set
+    pub fn with_ruleset(mut self, ruleset: Ruleset) -> Self {
+        self.rules.extend(ruleset.rules);
+        self
+    }
+
+    /// Descriptions of the rules in the set
+    pub fn rules(&self) -> Vec<Rule> {
+        self.rules.iter().map(|rule| rule.rule()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn report(&self, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace, check: impl Fn(&dyn VerificationRule) -> Result<(), String>) {
+        for rule in self.rules.iter() {
+            if let Err(message) = check(rule.as_ref()) {
+                issues.push(rule.rule().issue(message, version_trace.clone()));
+            }
+        }
+    }
+
+    /// Run every rule against the version, its tables and its columns
+    pub(crate) fn verify_version(&self, version_list: &[Value], version: &Value, version_output: &str, engine: &str, issues: &mut Vec<VerificationIssue>) {
+        let Some(version_number) = version_number_of(version) else {
+            return;
+        };
+        if self.is_empty() {
+            return;
+        }
+
+        // Malformed history is reported by the structural checks
+        let history: Vec<Value> = versions_until(version_list, version_number, true).into_iter().cloned().collect();
+        let Ok(schema) = consolidate_schema(&history, None) else {
+            return;
+        };
+
+        let version_context = VersionContext {
+            version_number: version_output,
+            version,
+            engine,
+            schema: &schema,
+        };
+        self.report(issues, &VersionTrace::from([version_output]), |rule| rule.check_version(&version_context));
+
+        for method in ["createtable", "altertable"] {
+            for (table_name, table_data) in version[method].as_object().into_iter().flatten() {
+                let table_context = TableContext {
+                    version: &version_context,
+                    table_name,
+                    method,
+                    table_data,
+                    table: &schema[table_name],
+                };
+                let mut version_trace = VersionTrace::from([version_output.to_string(), method.to_string(), format!("table:{table_name}")]);
+                self.report(issues, &version_trace, |rule| rule.check_table(&table_context));
+
+                let columns: Vec<(&String, &Value)> = match method {
+                    "createtable" => table_data.as_object().into_iter().flatten().filter(|(_, data)| data.is_object()).collect(),
+                    _ => ["addcolumn", "modifycolumn"]
+                        .iter()
+                        .flat_map(|key| table_data[key].as_object().into_iter().flatten())
+                        .collect(),
+                };
+
+                for (column_name, column_data) in columns {
+                    let column_context = ColumnContext {
+                        table: &table_context,
+                        column_name,
+                        column_data,
+                        column: &schema[table_name][column_name],
+                    };
+                    version_trace.push(format!("column:{column_name}"));
+                    self.report(issues, &version_trace, |rule| rule.check_column(&column_context));
+                    version_trace.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod custom_tests {
+    use super::{CustomRule, Ruleset, TableContext, VerificationRule};
+    use crate::{
+        core::verification::{
+            config::VerificationConfig,
+            issue::{VerificationIssueLevel, VersionTrace},
+            rules::Rule,
+        },
+        verification::AlphaDBVerification,
+    };
+    use serde_json::{json, Value};
+
+    const MISSING_CREATED_AT: Rule = Rule {
+        id: "ACME001",
+        name: "missing-created-at",
+        level: VerificationIssueLevel::High,
+        summary: "Every table must have a `created_at` column.",
+        explanation: "Add a `created_at` DATETIME column to the table.",
+    };
+
+    const TEXT_PRIMARY_KEY: Rule = Rule {
+        id: "ACME002",
+        name: "text-primary-key",
+        level: VerificationIssueLevel::Critical,
+        summary: "Primary keys can not be TEXT columns.",
+        explanation: "Use an integer primary key.",
+    };
+
+    struct CreatedAt;
+
+    impl VerificationRule for CreatedAt {
+        fn rule(&self) -> Rule {
+            MISSING_CREATED_AT
+        }
+
+        fn check_table(&self, table: &TableContext) -> Result<(), String> {
+            match table.table.get("created_at") {
+                Some(_) => Ok(()),
+                None => Err(format!("Table `{}` has no `created_at` column.", table.table_name)),
+            }
+        }
+    }
+
+    fn policies() -> Ruleset {
+        Ruleset::new().with_rule(CreatedAt).with_rule(CustomRule::new(TEXT_PRIMARY_KEY).column(|column| {
+            match column.table.table["primary_key"] == column.column_name && column.column["type"] == "TEXT" {
+                true => Err(format!("Primary key `{}` is a TEXT column.", column.column_name)),
+                false => Ok(()),
+            }
+        }))
+    }
+
+    fn version_source() -> Value {
+        json!({"name": "test", "engine": "mysql", "version": [
+            {"_id": "0.0.1", "createtable": {
+                "account": {"primary_key": "id", "id": {"type": "INT"}, "created_at": {"type": "DATETIME"}},
+                "tag": {"primary_key": "name", "name": {"type": "TEXT"}},
+            }},
+            {"_id": "0.0.2", "_ignore": ["text-primary-key"], "altertable": {"account": {"dropcolumn": ["created_at"]}}},
+        ]})
+    }
+
+    fn issues(verification: AlphaDBVerification) -> Vec<(Option<&'static str>, VerificationIssueLevel, String, VersionTrace)> {
+        let mut verification = verification.with_ruleset(policies());
+        let issues = verification.verify().unwrap_err();
+        issues
+            .into_iter()
+            .filter(|i| i.rule.is_some_and(|r| r.starts_with("ACME")))
+            .map(|i| (i.rule, i.level, i.message, i.version_trace))
+            .collect()
+    }
+
+    #[test]
+    fn custom_rules() {
+        let verification = AlphaDBVerification::new(version_source().to_string()).unwrap();
+
+        assert_eq!(
+            issues(verification),
+            vec![
+                (
+                    Some("ACME001"),
+                    VerificationIssueLevel::High,
+                    "Table `tag` has no `created_at` column.".to_string(),
+                    VersionTrace::from(["0.0.1", "createtable", "table:tag"])
+                ),
+                (
+                    Some("ACME002"),
+                    VerificationIssueLevel::Critical,
+                    "Primary key `name` is a TEXT column.".to_string(),
+                    VersionTrace::from(["0.0.1", "createtable", "table:tag", "column:name"])
+                ),
+                (
+                    Some("ACME001"),
+                    VerificationIssueLevel::High,
+                    "Table `account` has no `created_at` column.".to_string(),
+                    VersionTrace::from(["0.0.2", "altertable", "table:account"])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn configured_custom_rules() {
+        let config = VerificationConfig::from_json(r#"{"disable": ["ACME002"], "severity": {"missing-created-at": "low"}}"#).unwrap();
+        let verification = AlphaDBVerification::new(version_source().to_string()).unwrap().with_config(config);
+
+        let rules: Vec<(Option<&str>, VerificationIssueLevel)> = issues(verification).into_iter().map(|(rule, level, _, _)| (rule, level)).collect();
+        assert_eq!(rules, vec![(Some("ACME001"), VerificationIssueLevel::Low), (Some("ACME001"), VerificationIssueLevel::Low)]);
+    }
+
+    #[test]
+    fn ruleset() {
+        let ruleset = Ruleset::new().with_rule(CreatedAt).with_ruleset(policies());
+        let ids: Vec<&str> = ruleset.rules().iter().map(|rule| rule.id).collect();
+        assert_eq!(ids, vec!["ACME001", "ACME001", "ACME002"]);
+    }
+}
@@ -0,0 +1,390 @@
+use serde_json::Value;
+
+use crate::core::{
+    engine_config::EngineConfig,
+    utils::{
+        consolidate::{column::will_column_be_dropped, table::consolidate_table},
+        errors::AlphaDBError,
+        json::{get_json_float, get_json_int},
+        version_number::parse_version_number,
+    },
+    verification::{
+        foreign_key::get_live_foreign_keys,
+        issue::{VerificationIssue, VersionTrace},
+        rules::{
+            DEFAULT_DATA_DUPLICATE_KEY, DEFAULT_DATA_MISSING_VALUE, DEFAULT_DATA_TOO_LONG, DEFAULT_DATA_TYPE_MISMATCH, DEFAULT_DATA_UNKNOWN_COLUMN, DEFAULT_DATA_UNKNOWN_REFERENCE,
+            DEFAULT_DATA_UNKNOWN_TABLE, INVALID_DEFAULT_DATA,
+        },
+    },
+};
+
+/// Column types the database fills in by itself when no value is inserted
+const GENERATED_COLUMN_TYPES: [&str; 3] = ["SERIAL", "BIGSERIAL", "SMALLSERIAL"];
+
+/// Compare values the way the database would, so `1` and `"1"` are the same key
+fn value_key(value: &Value) -> String {
+    match value.as_str() {
+        Some(v) => v.to_string(),
+        None => value.to_string(),
+    }
+}
+
+/// Whether a row has to provide a value for `column`
+fn is_required(column: &Value) -> bool {
+    let allows_null = column["null"] == true;
+    let has_default = column.get("default").is_some_and(|d| !d.is_null() && d != "");
+    let generated = column["auto_increment"] == true
+        || column.get("generated").is_some()
+        || column["type"].as_str().is_some_and(|t| GENERATED_COLUMN_TYPES.contains(&t.to_uppercase().as_str()));
+
+    !allows_null && !has_default && !generated
+}
+
+/// Check that a value fits the column type, according to the engine's value type lists
+fn verify_value_type(column: &str, definition: &Value, value: &Value, config: &EngineConfig, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) {
+    // `null` values are left out of the insert, like a missing value
+    if value.is_null() {
+        if is_required(definition) {
+            issues.push(DEFAULT_DATA_MISSING_VALUE.issue(
+                format!("Column {column} is not allowed to be NULL, so default data is required to be specified."),
+                version_trace.clone(),
+            ));
+        }
+        return;
+    }
+
+    let Some(column_type) = definition["type"].as_str() else {
+        return;
+    };
+    let column_type = column_type.to_uppercase();
+    let column_type = column_type.as_str();
+
+    let mut expected: Vec<&str> = Vec::new();
+    let mut matches = false;
+
+    if config.string_columns.contains(&column_type) {
+        expected.push("string");
+        matches |= value.is_string();
+    }
+    if config.int_columns.contains(&column_type) {
+        expected.push("int");
+        matches |= get_json_int(value).is_ok();
+    }
+    if config.float_columns.contains(&column_type) {
+        expected.push("float");
+        matches |= get_json_float(value).is_ok();
+    }
+
+    if !expected.is_empty() && !matches {
+        issues.push(DEFAULT_DATA_TYPE_MISMATCH.issue(
+            format!("Default data for column type `{column_type}` is required to be of type {}", expected.join(" or ")),
+            version_trace.clone(),
+        ));
+        return;
+    }
+
+    if ["VARCHAR", "CHAR"].contains(&column_type) {
+        if let (Some(value), Some(length)) = (value.as_str(), definition["length"].as_u64()) {
+            let value_length = value.chars().count() as u64;
+            if value_length > length {
+                issues.push(DEFAULT_DATA_TOO_LONG.issue(
+                    format!("Default data for column `{column}` is {value_length} characters long, but the column only allows {length}"),
+                    version_trace.clone(),
+                ));
+            }
+        }
+    }
+}
+
+/// Indexes of the consolidated rows of `table_name` that `version` adds or changes
+pub fn version_items(version: &Value, table_name: &str, default_data: &Value) -> Vec<usize> {
+    let seeded = default_data[table_name].as_array().map_or(0, Vec::len);
+
+    match &version["default_data"][table_name] {
+        // Appended after the rows of earlier versions
+        Value::Array(rows) => (seeded.saturating_sub(rows.len())..seeded).collect(),
+        Value::Object(rows) => rows.keys().filter_map(|k| k.parse().ok()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Verify the consolidated default data of a table against the table schema at `version_number`.
+///
+/// `default_data` holds the consolidated default data of all tables, so foreign key values can be
+/// checked against the rows seeded into the referenced table. Only the rows at `items` are reported
+/// on, the rows of earlier versions are used for the duplicate key and foreign key lookups.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_table_default_data(
+    version_list: &Vec<Value>,
+    table_name: &str,
+    version_number: &str,
+    default_data: &Value,
+    items: &[usize],
+    config: &EngineConfig,
+    issues: &mut Vec<VerificationIssue>,
+    version_trace: &VersionTrace,
+) -> Result<(), AlphaDBError> {
+    let v = parse_version_number(version_number)?;
+    let schema = consolidate_table(version_list, table_name, Some(version_number))?;
+    let columns: Vec<(&String, &Value)> = schema.as_object().into_iter().flatten().filter(|(_, d)| d.is_object()).collect();
+
+    if columns.is_empty() {
+        issues.push(DEFAULT_DATA_UNKNOWN_TABLE.issue(
+            format!("Default data is specified for table `{table_name}`, but the table does not exist at this version."),
+            version_trace.clone(),
+        ));
+        return Ok(());
+    }
+
+    let Some(rows) = default_data[table_name].as_array() else {
+        return Ok(());
+    };
+
+    let mut version_trace = version_trace.clone();
+
+    for (i, row) in rows.iter().enumerate().filter(|(i, _)| items.contains(i)) {
+        version_trace.push(format!("item:{i}"));
+
+        let Some(row) = row.as_object() else {
+            issues.push(INVALID_DEFAULT_DATA.issue("Default data items must be objects.".to_string(), version_trace.clone()));
+            version_trace.pop();
+            continue;
+        };
+
+        for (column, value) in row {
+            match columns.iter().find(|(name, _)| *name == column) {
+                Some((_, definition)) => verify_value_type(column, definition, value, config, issues, &version_trace),
+                None => issues.push(DEFAULT_DATA_UNKNOWN_COLUMN.issue(
+                    format!("Default data for column {column} is specified, but the column does not exist in the table."),
+                    version_trace.clone(),
+                )),
+            }
+        }
+
+        for (column, definition) in &columns {
+            // Values for columns that are dropped later on are left out by consolidation
+            if !is_required(definition) || row.contains_key(column.as_str()) || will_column_be_dropped(version_list, column, table_name, v).unwrap_or(false) {
+                continue;
+            }
+
+            issues.push(DEFAULT_DATA_MISSING_VALUE.issue(
+                format!("Column {column} is not allowed to be NULL, so default data is required to be specified."),
+                version_trace.clone(),
+            ));
+        }
+
+        version_trace.pop();
+    }
+
+    // Primary key and unique values can only be seeded once
+    let primary_key = schema["primary_key"].as_str();
+    for (column, definition) in &columns {
+        let is_primary_key = primary_key == Some(column.as_str());
+        if !is_primary_key && definition["unique"] != true {
+            continue;
+        }
+
+        let mut column_values: Vec<String> = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            let value = &row[column.as_str()];
+            if value.is_null() {
+                continue;
+            }
+
+            let value = value_key(value);
+            if column_values.contains(&value) && items.contains(&i) {
+                let message = match is_primary_key {
+                    true => format!("Column `{column}` is the table's primary key so it's value should be unique, but the value `{value}` is previously specified as default data"),
+                    false => format!("Column `{column}` has the UNIQUE key, but the value `{value}` is previously specified as default data"),
+                };
+
+                version_trace.push(format!("item:{i}"));
+                issues.push(DEFAULT_DATA_DUPLICATE_KEY.issue(message, version_trace.clone()));
+                version_trace.pop();
+            }
+
+            column_values.push(value);
+        }
+    }
+
+    // Foreign key values can only be checked when the referenced table is seeded as well
+    for foreign_key in get_live_foreign_keys(version_list, v + 1).iter().filter(|fk| fk.table == table_name) {
+        let (Some(from), Some(references), Some(to)) = (
+            foreign_key.definition["from"].as_str(),
+            foreign_key.definition["references"].as_str(),
+            foreign_key.definition["to"].as_str(),
+        ) else {
+            continue;
+        };
+
+        let Some(referenced_rows) = default_data[references].as_array().filter(|r| !r.is_empty()) else {
+            continue;
+        };
+        let referenced_values: Vec<String> = referenced_rows.iter().map(|r| &r[to]).filter(|v| !v.is_null()).map(value_key).collect();
+
+        for (i, row) in rows.iter().enumerate().filter(|(i, _)| items.contains(i)) {
+            let value = &row[from];
+            if value.is_null() || referenced_values.contains(&value_key(value)) {
+                continue;
+            }
+
+            version_trace.push(format!("item:{i}"));
+            issues.push(DEFAULT_DATA_UNKNOWN_REFERENCE.issue(
+                format!(
+                    "Default data for column `{from}` references `{references}.{to}` with value `{}`, but that value is not part of the default data of `{references}`",
+                    value_key(value)
+                ),
+                version_trace.clone(),
+            ));
+            version_trace.pop();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod default_data_tests {
+    use super::{verify_table_default_data, version_items};
+    use crate::core::{
+        utils::consolidate::default_data::consolidate_default_data,
+        verification::issue::{VerificationIssue, VersionTrace},
+    };
+    use crate::engine::mysql_impl::verification::MYSQL_CONFIG;
+    use serde_json::{json, Value};
+
+    fn messages(versions: Value, table: &str, version: &str) -> Vec<String> {
+        let version_list = versions.as_array().unwrap().clone();
+        let default_data = consolidate_default_data(&version_list, Some(version)).unwrap();
+        let items = version_items(version_list.iter().find(|v| v["_id"] == version).unwrap(), table, &default_data);
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+
+        verify_table_default_data(&version_list, table, version, &default_data, &items, &MYSQL_CONFIG, &mut issues, &VersionTrace::new()).unwrap();
+        issues.into_iter().map(|i| i.message).collect()
+    }
+
+    fn account_table() -> Value {
+        json!({
+            "primary_key": "id",
+            "id": {"type": "INT", "auto_increment": true},
+            "email": {"type": "VARCHAR", "length": 12, "unique": true},
+            "name": {"type": "VARCHAR", "length": 50},
+            "score": {"type": "FLOAT", "null": true},
+            "role": {"type": "VARCHAR", "length": 10, "default": "user"},
+        })
+    }
+
+    #[test]
+    fn valid_default_data() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}, "default_data": {"account": [
+                {"email": "a@test.nl", "name": "A", "score": "1.5"},
+                {"id": 5, "email": "b@test.nl", "name": "B", "role": "admin"},
+            ]}},
+        ]);
+
+        assert!(messages(versions, "account", "0.0.1").is_empty());
+    }
+
+    #[test]
+    fn invalid_values() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}, "default_data": {"account": [
+                {"email": "a@test.nl", "name": 1, "score": "high", "age": 31},
+                {"email": "a.very.long@test.nl"},
+            ]}},
+        ]);
+
+        assert_eq!(
+            messages(versions, "account", "0.0.1"),
+            vec![
+                "Default data for column type `VARCHAR` is required to be of type string",
+                "Default data for column type `FLOAT` is required to be of type float",
+                "Default data for column age is specified, but the column does not exist in the table.",
+                "Default data for column `email` is 19 characters long, but the column only allows 12",
+                "Column name is not allowed to be NULL, so default data is required to be specified.",
+            ]
+        );
+    }
+
+    #[test]
+    fn null_values() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}, "default_data": {"account": [
+                {"email": "a@test.nl", "name": null, "score": null, "role": null},
+            ]}},
+        ]);
+
+        assert_eq!(
+            messages(versions, "account", "0.0.1"),
+            vec!["Column name is not allowed to be NULL, so default data is required to be specified."]
+        );
+    }
+
+    #[test]
+    fn earlier_rows() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}, "default_data": {"account": [
+                {"id": 1, "email": "a@test.nl", "name": 1},
+            ]}},
+            {"_id": "0.0.2", "default_data": {"account": [
+                {"id": 1, "email": "b@test.nl", "name": "B"},
+            ]}},
+        ]);
+
+        // The type mismatch of 0.0.1 is not reported again, its key is still taken
+        assert_eq!(
+            messages(versions, "account", "0.0.2"),
+            vec!["Column `id` is the table's primary key so it's value should be unique, but the value `1` is previously specified as default data"]
+        );
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}, "default_data": {"account": [
+                {"id": 1, "email": "a@test.nl", "name": "A"},
+                {"id": "1", "email": "a@test.nl", "name": "B"},
+            ]}},
+        ]);
+
+        assert_eq!(
+            messages(versions, "account", "0.0.1"),
+            vec![
+                "Column `id` is the table's primary key so it's value should be unique, but the value `1` is previously specified as default data",
+                "Column `email` has the UNIQUE key, but the value `a@test.nl` is previously specified as default data",
+            ]
+        );
+    }
+
+    #[test]
+    fn foreign_key_values() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": account_table(),
+                "post": {"id": {"type": "INT", "auto_increment": true}, "account_id": {"type": "INT"}, "foreign_key": [{"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id"}]},
+            }},
+            {"_id": "0.0.2", "default_data": {
+                "account": [{"id": 1, "email": "a@test.nl", "name": "A"}],
+                "post": [{"account_id": 1}, {"account_id": 2}],
+            }},
+        ]);
+
+        assert_eq!(
+            messages(versions, "post", "0.0.2"),
+            vec!["Default data for column `account_id` references `account.id` with value `2`, but that value is not part of the default data of `account`"]
+        );
+    }
+
+    #[test]
+    fn missing_table() {
+        let versions = json!([
+            {"_id": "0.0.1", "default_data": {"account": [{"id": 1}]}},
+        ]);
+
+        assert_eq!(
+            messages(versions, "account", "0.0.1"),
+            vec!["Default data is specified for table `account`, but the table does not exist at this version."]
+        );
+    }
+}
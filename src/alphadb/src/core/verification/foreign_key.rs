@@ -1,18 +1,463 @@
 use serde_json::Value;
 
 use crate::core::{
-    utils::errors::AlphaDBError,
-    verification::issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+    utils::{consolidate::primary_key::get_primary_key_columns, errors::AlphaDBError},
+    verification::{
+        history::{consolidate_table_at, version_number_of, versions_until},
+        issue::{VerificationIssue, VersionTrace},
+        rules::{
+            Rule, FOREIGN_KEY_TYPE_MISMATCH, INCOMPATIBLE_FOREIGN_KEY_TYPES, INVALID_DEFINITION_TYPE, INVALID_FOREIGN_KEY, NON_UNIQUE_REFERENCE, REFERENCED_COLUMN_REMOVED,
+            SET_NULL_ON_NOT_NULL, UNKNOWN_FOREIGN_KEY_COLUMN, UNKNOWN_REFERENCE,
+        },
+    },
 };
 
 pub fn verify_foreign_key(foreign_key: &Value, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) -> Result<(), AlphaDBError> {
     if !foreign_key.is_array() {
-        issues.push(VerificationIssue {
-            level: VerificationIssueLevel::High,
-            message: "Foreign key definitions must be specified as an array.".to_string(),
-            version_trace: version_trace.clone(),
-        });
+        issues.push(INVALID_DEFINITION_TYPE.issue("Foreign key definitions must be specified as an array.".to_string(), version_trace.clone()));
     }
 
     Ok(())
 }
+
+/// A foreign key as it exists at some point in the version history
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExistingForeignKey {
+    /// The table the foreign key is defined on
+    pub table: String,
+    pub definition: Value,
+}
+
+impl ExistingForeignKey {
+    fn name(&self) -> &str {
+        self.definition["name"].as_str().unwrap_or_default()
+    }
+}
+
+fn foreign_key_definitions(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten().filter(|fk| fk.is_object())
+}
+
+/// Apply the foreign keys created, dropped and modified in `version`
+fn apply_foreign_key_changes(version: &Value, foreign_keys: &mut Vec<ExistingForeignKey>) {
+    if let Some(createtable) = version["createtable"].as_object() {
+        for (table, table_data) in createtable {
+            for definition in foreign_key_definitions(&table_data["foreign_key"]) {
+                foreign_keys.push(ExistingForeignKey {
+                    table: table.to_string(),
+                    definition: definition.clone(),
+                });
+            }
+        }
+    }
+
+    if let Some(altertable) = version["altertable"].as_object() {
+        for (table, table_data) in altertable {
+            for name in table_data["drop_foreign_key"].as_array().into_iter().flatten() {
+                foreign_keys.retain(|fk| !(&fk.table == table && &fk.definition["name"] == name));
+            }
+
+            for key in ["modify_foreign_key", "add_foreign_key"] {
+                for definition in foreign_key_definitions(&table_data[key]) {
+                    foreign_keys.retain(|fk| !(&fk.table == table && fk.definition["name"] == definition["name"]));
+                    foreign_keys.push(ExistingForeignKey {
+                        table: table.to_string(),
+                        definition: definition.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Apply the column drops and renames in `version` to the columns foreign keys point at
+fn apply_column_changes(version: &Value, foreign_keys: &mut Vec<ExistingForeignKey>) {
+    let Some(altertable) = version["altertable"].as_object() else {
+        return;
+    };
+
+    for (table, table_data) in altertable {
+        for column in table_data["dropcolumn"].as_array().into_iter().flatten() {
+            // A foreign key does not outlive the column it is defined on
+            foreign_keys.retain(|fk| !(&fk.table == table && &fk.definition["from"] == column));
+        }
+
+        for (old, new) in table_data["renamecolumn"].as_object().into_iter().flatten() {
+            for fk in foreign_keys.iter_mut() {
+                if &fk.table == table && fk.definition["from"] == old.as_str() {
+                    fk.definition["from"] = new.clone();
+                }
+                if fk.definition["references"] == table.as_str() && fk.definition["to"] == old.as_str() {
+                    fk.definition["to"] = new.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Get all foreign keys that exist right before `version_number` is applied.
+pub fn get_live_foreign_keys(version_list: &[Value], version_number: u32) -> Vec<ExistingForeignKey> {
+    let mut foreign_keys = Vec::new();
+    for version in versions_until(version_list, version_number, false) {
+        apply_foreign_key_changes(version, &mut foreign_keys);
+        apply_column_changes(version, &mut foreign_keys);
+    }
+
+    foreign_keys
+}
+
+/// Normalize a column type to its canonical name and the family it belongs to.
+/// Types within a family can usually be compared, types across families can not.
+fn type_family(column_type: &str) -> (String, &'static str) {
+    let column_type = column_type.to_uppercase();
+    let column_type = match column_type.as_str() {
+        "INT" | "SERIAL" => "INTEGER".to_string(),
+        "BIGSERIAL" => "BIGINT".to_string(),
+        "SMALLSERIAL" => "SMALLINT".to_string(),
+        "DOUBLE" => "DOUBLE PRECISION".to_string(),
+        _ => column_type,
+    };
+
+    let family = match column_type.as_str() {
+        "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INTEGER" | "BIGINT" => "integer",
+        "VARCHAR" | "CHAR" | "TEXT" | "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" => "string",
+        "DECIMAL" | "NUMERIC" => "decimal",
+        "FLOAT" | "REAL" | "DOUBLE PRECISION" => "float",
+        "DATETIME" | "TIMESTAMP" | "TIMESTAMPTZ" => "timestamp",
+        _ => "",
+    };
+
+    (column_type, family)
+}
+
+/// Verify a single foreign key definition against the consolidated schema at `version_number`
+pub fn verify_foreign_key_definition(
+    foreign_key: &Value,
+    table_name: &str,
+    version_list: &[Value],
+    version_number: u32,
+    issues: &mut Vec<VerificationIssue>,
+    version_trace: &VersionTrace,
+) {
+    let mut issue = |rule: &Rule, message: String| issues.push(rule.issue(message, version_trace.clone()));
+
+    if !foreign_key.is_object() {
+        issue(&INVALID_DEFINITION_TYPE, "Foreign key definitions must be specified as an object.".to_string());
+        return;
+    }
+
+    let mut incomplete = false;
+    for key in ["name", "from", "references", "to"] {
+        if foreign_key[key].as_str().is_none_or(|v| v.is_empty()) {
+            issue(&INVALID_FOREIGN_KEY, format!("Foreign key is missing '{key}'."));
+            incomplete = true;
+        }
+    }
+    if incomplete {
+        return;
+    }
+
+    let name = foreign_key["name"].as_str().unwrap_or_default();
+    let from = foreign_key["from"].as_str().unwrap_or_default();
+    let references = foreign_key["references"].as_str().unwrap_or_default();
+    let to = foreign_key["to"].as_str().unwrap_or_default();
+
+    let table = consolidate_table_at(version_list, table_name, version_number).unwrap_or_default();
+    let from_column = &table[from];
+    if !from_column.is_object() {
+        issue(
+            &UNKNOWN_FOREIGN_KEY_COLUMN,
+            format!("Foreign key '{name}' column '{from}' does not exist on table '{table_name}'."),
+        );
+    }
+
+    let Some(referenced_table) = consolidate_table_at(version_list, references, version_number) else {
+        issue(
+            &UNKNOWN_REFERENCE,
+            format!("Foreign key '{name}' references table '{references}', which does not exist at this version."),
+        );
+        return;
+    };
+
+    let to_column = &referenced_table[to];
+    if !to_column.is_object() {
+        issue(
+            &UNKNOWN_REFERENCE,
+            format!("Foreign key '{name}' references column '{references}.{to}', which does not exist at this version."),
+        );
+        return;
+    }
+
+    // A single column is only unique through a primary key of just that column
+    let primary_key = get_primary_key_columns(versions_until(version_list, version_number, true), references);
+    if primary_key != [to] && to_column["unique"] != true {
+        let message = if primary_key.iter().any(|c| c == to) {
+            format!(
+                "Foreign key '{name}' references column '{references}.{to}', which is only part of the composite primary key ({}).",
+                primary_key.join(", ")
+            )
+        } else {
+            format!("Foreign key '{name}' references column '{references}.{to}', which is not a primary key or unique column.")
+        };
+        issue(&NON_UNIQUE_REFERENCE, message);
+    }
+
+    if !from_column.is_object() {
+        return;
+    }
+
+    if let (Some(from_type), Some(to_type)) = (from_column["type"].as_str(), to_column["type"].as_str()) {
+        let (from_type, from_family) = type_family(from_type);
+        let (to_type, to_family) = type_family(to_type);
+
+        if from_type != to_type {
+            let rule = if !from_family.is_empty() && from_family == to_family {
+                &FOREIGN_KEY_TYPE_MISMATCH
+            } else {
+                &INCOMPATIBLE_FOREIGN_KEY_TYPES
+            };
+
+            issue(
+                rule,
+                format!("Foreign key '{name}' column '{from}' ({from_type}) is not compatible with referenced column '{references}.{to}' ({to_type})."),
+            );
+        }
+    }
+
+    for action in ["on_delete", "on_update"] {
+        let sets_null = foreign_key[action].as_str().is_some_and(|a| a.eq_ignore_ascii_case("set null"));
+        if sets_null && from_column["null"] != true {
+            issue(
+                &SET_NULL_ON_NOT_NULL,
+                format!("Foreign key '{name}' uses '{action}: set null', but column '{from}' does not allow null values."),
+            );
+        }
+    }
+}
+
+/// Verify every foreign key defined in `version`, and every column drop or rename in it
+/// that would break a foreign key still referencing that column.
+pub fn verify_version_foreign_keys(version_list: &[Value], version: &Value, version_output: &str, issues: &mut Vec<VerificationIssue>) {
+    let Some(version_number) = version_number_of(version) else {
+        return;
+    };
+
+    if let Some(createtable) = version["createtable"].as_object() {
+        for (table, table_data) in createtable {
+            for (i, foreign_key) in table_data["foreign_key"].as_array().into_iter().flatten().enumerate() {
+                let version_trace = VersionTrace::from([
+                    version_output.to_string(),
+                    "createtable".to_string(),
+                    table.to_string(),
+                    "foreign_key".to_string(),
+                    format!("item:{i}"),
+                ]);
+                verify_foreign_key_definition(foreign_key, table, version_list, version_number, issues, &version_trace);
+            }
+        }
+    }
+
+    let Some(altertable) = version["altertable"].as_object() else {
+        return;
+    };
+
+    for (table, table_data) in altertable {
+        let mut version_trace = VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}")]);
+
+        for key in ["modify_foreign_key", "add_foreign_key"] {
+            if let Some(foreign_keys) = table_data.get(key) {
+                version_trace.push(key.to_string());
+                verify_foreign_key(foreign_keys, issues, &version_trace).ok();
+
+                for (i, foreign_key) in foreign_keys.as_array().into_iter().flatten().enumerate() {
+                    version_trace.push(format!("item:{i}"));
+                    verify_foreign_key_definition(foreign_key, table, version_list, version_number, issues, &version_trace);
+                    version_trace.pop();
+                }
+                version_trace.pop();
+            }
+        }
+    }
+
+    // Foreign keys dropped or redirected in this version no longer count
+    let mut foreign_keys = get_live_foreign_keys(version_list, version_number);
+    apply_foreign_key_changes(version, &mut foreign_keys);
+
+    for (table, table_data) in altertable {
+        let dropped = table_data["dropcolumn"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(|c| ("dropcolumn", c));
+        let renamed = table_data["renamecolumn"].as_object().into_iter().flatten().map(|(c, _)| ("renamecolumn", c.as_str()));
+
+        for (method, column) in dropped.chain(renamed) {
+            for fk in foreign_keys
+                .iter()
+                .filter(|fk| fk.definition["references"] == table.as_str() && fk.definition["to"] == column)
+            {
+                issues.push(REFERENCED_COLUMN_REMOVED.issue(
+                    format!("Column '{column}' is still referenced by foreign key '{}' on table '{}'.", fk.name(), fk.table),
+                    VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}"), method.to_string()]),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod foreign_key_tests {
+    use super::{get_live_foreign_keys, verify_version_foreign_keys};
+    use crate::core::verification::issue::VerificationIssue;
+    use serde_json::{json, Value};
+
+    fn messages(versions: &Value) -> Vec<String> {
+        let version_list = versions.as_array().unwrap();
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        for version in version_list {
+            verify_version_foreign_keys(version_list, version, version["_id"].as_str().unwrap(), &mut issues);
+        }
+        issues.into_iter().map(|i| i.message).collect()
+    }
+
+    fn account_table() -> Value {
+        json!({"primary_key": "id", "id": {"type": "INT", "auto_increment": true}, "email": {"type": "VARCHAR", "length": 100, "unique": true}, "nickname": {"type": "VARCHAR", "length": 100}})
+    }
+
+    #[test]
+    fn valid_foreign_keys() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": account_table(),
+                "post": {"id": {"type": "INT"}, "account_id": {"type": "INTEGER", "null": true}, "foreign_key": [{"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id", "on_delete": "set null"}]},
+            }},
+            {"_id": "0.0.2", "altertable": {"post": {
+                "addcolumn": {"email": {"type": "VARCHAR", "length": 100}},
+                "add_foreign_key": [{"name": "post_email_fk", "from": "email", "references": "account", "to": "email"}],
+            }}},
+            {"_id": "0.0.3", "altertable": {
+                "post": {"drop_foreign_key": ["post_email_fk"]},
+                "account": {"dropcolumn": ["email"]},
+            }},
+        ]);
+
+        assert!(messages(&versions).is_empty());
+    }
+
+    #[test]
+    fn missing_references() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": account_table(),
+                "post": {"id": {"type": "INT"}, "foreign_key": [
+                    {"name": "post_user_fk", "from": "id", "references": "user", "to": "id"},
+                    {"name": "post_account_fk", "from": "account_id", "references": "account", "to": "uuid"},
+                    {"name": "post_incomplete_fk", "from": "id"},
+                ]},
+            }},
+        ]);
+
+        assert_eq!(
+            messages(&versions),
+            vec![
+                "Foreign key 'post_user_fk' references table 'user', which does not exist at this version.",
+                "Foreign key 'post_account_fk' column 'account_id' does not exist on table 'post'.",
+                "Foreign key 'post_account_fk' references column 'account.uuid', which does not exist at this version.",
+                "Foreign key is missing 'references'.",
+                "Foreign key is missing 'to'.",
+            ]
+        );
+    }
+
+    #[test]
+    fn referenced_column_constraints() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"account": account_table()}},
+            {"_id": "0.0.2", "createtable": {"post": {
+                "id": {"type": "INT"},
+                "account_id": {"type": "BIGINT"},
+                "author": {"type": "TEXT"},
+                "foreign_key": [
+                    {"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id", "on_delete": "SET NULL"},
+                    {"name": "post_author_fk", "from": "author", "references": "account", "to": "nickname"},
+                    {"name": "post_id_fk", "from": "id", "references": "account", "to": "email"},
+                ],
+            }}},
+        ]);
+
+        assert_eq!(
+            messages(&versions),
+            vec![
+                "Foreign key 'post_account_fk' column 'account_id' (BIGINT) is not compatible with referenced column 'account.id' (INTEGER).",
+                "Foreign key 'post_account_fk' uses 'on_delete: set null', but column 'account_id' does not allow null values.",
+                "Foreign key 'post_author_fk' references column 'account.nickname', which is not a primary key or unique column.",
+                "Foreign key 'post_author_fk' column 'author' (TEXT) is not compatible with referenced column 'account.nickname' (VARCHAR).",
+                "Foreign key 'post_id_fk' column 'id' (INTEGER) is not compatible with referenced column 'account.email' (VARCHAR).",
+            ]
+        );
+    }
+
+    #[test]
+    fn composite_primary_key_references() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": {"primary_key": ["id"], "id": {"type": "INT"}},
+                "membership": {"primary_key": ["account_id", "group_id"], "account_id": {"type": "INT"}, "group_id": {"type": "INT"}},
+            }},
+            {"_id": "0.0.2", "createtable": {"post": {
+                "id": {"type": "INT"},
+                "account_id": {"type": "INT"},
+                "group_id": {"type": "INT"},
+                "foreign_key": [
+                    {"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id"},
+                    {"name": "post_group_fk", "from": "group_id", "references": "membership", "to": "group_id"},
+                ],
+            }}},
+        ]);
+
+        assert_eq!(
+            messages(&versions),
+            vec!["Foreign key 'post_group_fk' references column 'membership.group_id', which is only part of the composite primary key (account_id, group_id)."]
+        );
+    }
+
+    #[test]
+    fn breaking_referenced_columns() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": account_table(),
+                "post": {"id": {"type": "INT"}, "account_id": {"type": "INT"}, "account_email": {"type": "VARCHAR", "length": 100}, "foreign_key": [
+                    {"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id"},
+                    {"name": "post_email_fk", "from": "account_email", "references": "account", "to": "email"},
+                ]},
+            }},
+            {"_id": "0.0.2", "altertable": {"account": {"renamecolumn": {"id": "account_id"}, "dropcolumn": ["email"]}}},
+        ]);
+
+        assert_eq!(
+            messages(&versions),
+            vec![
+                "Column 'email' is still referenced by foreign key 'post_email_fk' on table 'post'.",
+                "Column 'id' is still referenced by foreign key 'post_account_fk' on table 'post'.",
+            ]
+        );
+    }
+
+    #[test]
+    fn live_foreign_keys() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "account": account_table(),
+                "post": {"id": {"type": "INT"}, "account_id": {"type": "INT"}, "foreign_key": [{"name": "post_account_fk", "from": "account_id", "references": "account", "to": "id"}]},
+            }},
+            {"_id": "0.0.2", "altertable": {"post": {"renamecolumn": {"account_id": "owner_id"}}}},
+            {"_id": "0.0.3", "altertable": {"post": {"dropcolumn": ["owner_id"]}}},
+        ]);
+
+        let foreign_keys = get_live_foreign_keys(versions.as_array().unwrap(), 3);
+        assert_eq!(foreign_keys.len(), 1);
+        assert_eq!(foreign_keys[0].definition["from"], "owner_id");
+        assert!(get_live_foreign_keys(versions.as_array().unwrap(), 4).is_empty());
+    }
+}
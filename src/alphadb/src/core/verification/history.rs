@@ -0,0 +1,54 @@
+use serde_json::Value;
+
+use crate::core::utils::{consolidate::table::consolidate_table, version_number::parse_version_number};
+
+/// Get the version number of a version object, or `None` when it is missing or invalid.
+/// Those versions are reported elsewhere and skipped here.
+pub fn version_number_of(version: &Value) -> Option<u32> {
+    version["_id"].as_str().and_then(|v| parse_version_number(v).ok())
+}
+
+/// Whether the parts of a version that consolidation reads have the expected structure.
+/// Malformed versions are reported by the structural checks and skipped here.
+fn is_consolidatable(version: &Value) -> bool {
+    let tables_are_objects = ["createtable", "altertable"].iter().all(|method| match version.get(method) {
+        Some(tables) => tables.as_object().is_some_and(|t| t.values().all(Value::is_object)),
+        None => true,
+    });
+    let alterations_are_objects = version["altertable"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .all(|(_, table)| ["modifycolumn", "renamecolumn"].iter().all(|key| table.get(key).is_none_or(Value::is_object)));
+
+    version.is_object() && tables_are_objects && alterations_are_objects
+}
+
+/// Get all well formed versions before `version_number` (or up to and including it when
+/// `inclusive`), sorted by version number
+pub fn versions_until(version_list: &[Value], version_number: u32, inclusive: bool) -> Vec<&Value> {
+    let mut versions: Vec<(u32, &Value)> = version_list
+        .iter()
+        .filter(|v| is_consolidatable(v))
+        .filter_map(|v| version_number_of(v).map(|n| (n, v)))
+        .filter(|(n, _)| *n < version_number || (inclusive && *n == version_number))
+        .collect();
+    versions.sort_by_key(|(n, _)| *n);
+
+    versions.into_iter().map(|(_, v)| v).collect()
+}
+
+/// Consolidate a table as it is right after `version_number` is applied. `None` when the
+/// table does not exist at that version.
+pub fn consolidate_table_at(version_list: &[Value], table_name: &str, version_number: u32) -> Option<Value> {
+    // Later versions are left out entirely, so columns keep the name they had at this
+    // version instead of the one from their latest rename
+    let history: Vec<Value> = versions_until(version_list, version_number, true).into_iter().cloned().collect();
+
+    let table = consolidate_table(&history, table_name, None).ok()?;
+    if !table.as_object()?.values().any(Value::is_object) {
+        return None;
+    }
+
+    Some(table)
+}
@@ -0,0 +1,214 @@
+use serde_json::Value;
+
+use crate::core::{
+    engine_config::EngineConfig,
+    verification::{
+        issue::{VerificationIssue, VersionTrace},
+        rules::{IDENTIFIER_TOO_LONG, INVALID_IDENTIFIER, RESERVED_IDENTIFIER},
+    },
+};
+
+/// Table keys holding lists of named indexes and constraints, with the kind of name they hold
+const NAMED_DEFINITIONS: [(&str, &str); 7] = [
+    ("index", "Index"),
+    ("add_index", "Index"),
+    ("modify_index", "Index"),
+    ("foreign_key", "Foreign key"),
+    ("add_foreign_key", "Foreign key"),
+    ("modify_foreign_key", "Foreign key"),
+    ("check", "Check constraint"),
+];
+
+/// Whether the name can be written without quotes on every engine
+fn is_plain_identifier(identifier: &str) -> bool {
+    let mut chars = identifier.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Check a single table, column, index or constraint name against the engine's reserved
+/// words and identifier length limit
+///
+/// # Arguments
+/// * `identifier` - The name to check
+/// * `kind` - What the name belongs to, e.g. `Column`, used in the issue messages
+/// * `config` - Engine configuration
+/// * `issues` - Issues are added to this list
+/// * `version_trace` - Trace of the name in the version source
+pub fn verify_identifier(identifier: &str, kind: &str, config: &EngineConfig, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) {
+    if config.reserved_words.contains(&identifier.to_uppercase().as_str()) {
+        issues.push(RESERVED_IDENTIFIER.issue(
+            format!("{kind} name `{identifier}` is a reserved word on {}, queries written by hand must quote it.", config.name),
+            version_trace.clone(),
+        ));
+    }
+
+    if !is_plain_identifier(identifier) {
+        issues.push(INVALID_IDENTIFIER.issue(
+            format!("{kind} name `{identifier}` can only be used quoted. Use letters, digits and underscores, and do not start with a digit."),
+            version_trace.clone(),
+        ));
+    }
+
+    let length = identifier.chars().count();
+    if let Some(max_length) = config.max_identifier_length.filter(|max| length > *max) {
+        issues.push(IDENTIFIER_TOO_LONG.issue(
+            format!("{kind} name `{identifier}` is {length} characters long, {} allows at most {max_length}.", config.name),
+            version_trace.clone(),
+        ));
+    }
+}
+
+/// Check the names of the indexes and constraints defined in a table block
+fn verify_named_definitions(table_data: &Value, config: &EngineConfig, issues: &mut Vec<VerificationIssue>, version_trace: &mut VersionTrace) {
+    for (key, kind) in NAMED_DEFINITIONS {
+        let Some(definitions) = table_data[key].as_array() else {
+            continue;
+        };
+
+        version_trace.push(key.to_string());
+        for (i, definition) in definitions.iter().enumerate() {
+            if let Some(name) = definition["name"].as_str() {
+                version_trace.push(format!("item:{i}"));
+                verify_identifier(name, kind, config, issues, version_trace);
+                version_trace.pop();
+            }
+        }
+        version_trace.pop();
+    }
+}
+
+/// Check every name `version` introduces: created tables and their columns, added and
+/// renamed columns, and the names of indexes and constraints.
+pub fn verify_version_identifiers(version: &Value, version_output: &str, config: &EngineConfig, issues: &mut Vec<VerificationIssue>) {
+    for (table, table_data) in version["createtable"].as_object().into_iter().flatten() {
+        let mut version_trace = VersionTrace::from([version_output.to_string(), "createtable".to_string(), format!("table:{table}")]);
+        verify_identifier(table, "Table", config, issues, &version_trace);
+
+        // Columns are the object values, other keys hold the primary key, indexes and constraints
+        for (column, _) in table_data.as_object().into_iter().flatten().filter(|(_, data)| data.is_object()) {
+            version_trace.push(format!("column:{column}"));
+            verify_identifier(column, "Column", config, issues, &version_trace);
+            version_trace.pop();
+        }
+
+        verify_named_definitions(table_data, config, issues, &mut version_trace);
+    }
+
+    for (table, table_data) in version["altertable"].as_object().into_iter().flatten() {
+        let mut version_trace = VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}")]);
+
+        if let Some(added) = table_data["addcolumn"].as_object() {
+            version_trace.push("addcolumn".to_string());
+            for column in added.keys() {
+                version_trace.push(format!("column:{column}"));
+                verify_identifier(column, "Column", config, issues, &version_trace);
+                version_trace.pop();
+            }
+            version_trace.pop();
+        }
+
+        if let Some(renamed) = table_data["renamecolumn"].as_object() {
+            version_trace.push("renamecolumn".to_string());
+            for (old, new) in renamed {
+                if let Some(new) = new.as_str() {
+                    version_trace.push(format!("column:{old}"));
+                    verify_identifier(new, "Column", config, issues, &version_trace);
+                    version_trace.pop();
+                }
+            }
+            version_trace.pop();
+        }
+
+        verify_named_definitions(table_data, config, issues, &mut version_trace);
+    }
+}
+
+#[cfg(all(test, feature = "mysql", feature = "postgres", feature = "sqlite"))]
+mod identifier_tests {
+    use super::verify_version_identifiers;
+    use crate::core::{engine_config::EngineConfig, verification::issue::VerificationIssue};
+    use crate::engine::{mysql_impl::verification::MYSQL_CONFIG, postgres_impl::verification::POSTGRES_CONFIG, sqlite_impl::verification::SQLITE_CONFIG};
+    use serde_json::{json, Value};
+
+    fn identifier_issues(version: &Value, config: &EngineConfig) -> Vec<(&'static str, String)> {
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        verify_version_identifiers(version, "0.0.1", config, &mut issues);
+        issues.into_iter().map(|i| (i.rule.unwrap_or_default(), i.message)).collect()
+    }
+
+    #[test]
+    fn reserved_and_invalid_names() {
+        let version = json!({"_id": "0.0.1", "createtable": {
+            "order": {
+                "id": {"type": "INT"},
+                "first name": {"type": "VARCHAR", "length": 50},
+                "primary_key": "id",
+                "index": [{"name": "2fa_idx", "columns": ["id"]}],
+            }
+        }});
+
+        assert_eq!(
+            identifier_issues(&version, &MYSQL_CONFIG),
+            vec![
+                (
+                    "ADB0050",
+                    "Table name `order` is a reserved word on mysql, queries written by hand must quote it.".to_string()
+                ),
+                (
+                    "ADB0051",
+                    "Column name `first name` can only be used quoted. Use letters, digits and underscores, and do not start with a digit.".to_string()
+                ),
+                (
+                    "ADB0051",
+                    "Index name `2fa_idx` can only be used quoted. Use letters, digits and underscores, and do not start with a digit.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reserved_words_differ_per_engine() {
+        let version = json!({"_id": "0.0.1", "createtable": {"account": {"user": {"type": "INTEGER"}, "key": {"type": "INTEGER"}}}});
+
+        assert_eq!(
+            identifier_issues(&version, &POSTGRES_CONFIG),
+            vec![(
+                "ADB0050",
+                "Column name `user` is a reserved word on postgres, queries written by hand must quote it.".to_string()
+            )]
+        );
+        assert_eq!(
+            identifier_issues(&version, &MYSQL_CONFIG),
+            vec![(
+                "ADB0050",
+                "Column name `key` is a reserved word on mysql, queries written by hand must quote it.".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn long_names() {
+        let name = "a".repeat(64);
+        let version = json!({"_id": "0.0.2", "altertable": {"account": {
+            "addcolumn": {&name: {"type": "INTEGER"}},
+            "renamecolumn": {"old": "select"},
+            "add_foreign_key": [{"name": "account_user_fk", "from": "user_id", "references": "user", "to": "id"}],
+        }}});
+
+        assert_eq!(
+            identifier_issues(&version, &POSTGRES_CONFIG),
+            vec![
+                ("ADB0052", format!("Column name `{name}` is 64 characters long, postgres allows at most 63.")),
+                (
+                    "ADB0050",
+                    "Column name `select` is a reserved word on postgres, queries written by hand must quote it.".to_string()
+                ),
+            ]
+        );
+        assert_eq!(identifier_issues(&version, &MYSQL_CONFIG).len(), 1);
+        assert_eq!(identifier_issues(&version, &SQLITE_CONFIG).len(), 1);
+    }
+}
@@ -1,18 +1,398 @@
 use serde_json::Value;
 
 use crate::core::{
-    utils::errors::AlphaDBError,
-    verification::issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+    engine_config::{EngineConfig, IndexNameScope},
+    utils::{errors::AlphaDBError, parse_condition},
+    verification::{
+        history::{consolidate_table_at, version_number_of, versions_until},
+        issue::{VerificationIssue, VersionTrace},
+        rules::{Rule, DUPLICATE_INDEX_NAME, INVALID_DEFINITION_TYPE, INVALID_INDEX, INVALID_INDEX_CONDITION, UNKNOWN_INDEX, UNKNOWN_INDEX_COLUMN, UNSUPPORTED_INDEX_TYPE},
+    },
 };
 
 pub fn verify_index(foreign_key: &Value, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) -> Result<(), AlphaDBError> {
     if !foreign_key.is_array() {
-        issues.push(VerificationIssue {
-            level: VerificationIssueLevel::High,
-            message: "Column index definitions must be specified as an array.".to_string(),
-            version_trace: version_trace.clone(),
-        });
+        issues.push(INVALID_DEFINITION_TYPE.issue("Column index definitions must be specified as an array.".to_string(), version_trace.clone()));
     }
 
     Ok(())
 }
+
+/// An index as it exists at some point in the version history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistingIndex {
+    pub table: String,
+    pub name: String,
+}
+
+fn index_names(value: &Value) -> impl Iterator<Item = &str> {
+    value.as_array().into_iter().flatten().filter_map(|index| index["name"].as_str())
+}
+
+/// Replay the index operations of a single version on top of `indexes`,
+/// in the order the update driver executes them
+fn apply_version_indexes(version: &Value, indexes: &mut Vec<ExistingIndex>) {
+    if let Some(createtable) = version["createtable"].as_object() {
+        for (table, table_data) in createtable {
+            for name in index_names(&table_data["index"]) {
+                indexes.push(ExistingIndex {
+                    table: table.to_string(),
+                    name: name.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(altertable) = version["altertable"].as_object() {
+        for (table, table_data) in altertable {
+            for name in table_data["drop_index"].as_array().into_iter().flatten().filter_map(Value::as_str) {
+                indexes.retain(|i| !(&i.table == table && i.name == name));
+            }
+
+            for name in index_names(&table_data["add_index"]) {
+                indexes.push(ExistingIndex {
+                    table: table.to_string(),
+                    name: name.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Get all indexes that exist right before `version_number` is applied.
+pub fn get_existing_indexes(version_list: &[Value], version_number: u32) -> Vec<ExistingIndex> {
+    let mut indexes = Vec::new();
+    for version in versions_until(version_list, version_number, false) {
+        apply_version_indexes(version, &mut indexes);
+    }
+
+    indexes
+}
+
+/// Get the column names of a table as they are after `version_number` is applied
+fn table_columns(version_list: &[Value], table_name: &str, version_number: u32) -> Vec<String> {
+    consolidate_table_at(version_list, table_name, version_number)
+        .and_then(|table| table.as_object().map(|t| t.iter().filter(|(_, v)| v.is_object()).map(|(k, _)| k.to_string()).collect()))
+        .unwrap_or_default()
+}
+
+/// Verify the contents of a single index definition
+pub fn verify_index_definition(index: &Value, table_name: &str, columns: &[String], config: &EngineConfig, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) {
+    let mut issue = |rule: &Rule, message: String| issues.push(rule.issue(message, version_trace.clone()));
+
+    if !index.is_object() {
+        issue(&INVALID_DEFINITION_TYPE, "Index definitions must be specified as an object.".to_string());
+        return;
+    }
+
+    if index["name"].as_str().is_none_or(|n| n.is_empty()) {
+        issue(&INVALID_INDEX, "Index does not have a name.".to_string());
+    }
+
+    match index["columns"].as_array() {
+        Some(index_columns) if !index_columns.is_empty() => {
+            for column in index_columns {
+                match column.as_str() {
+                    Some(column) => {
+                        if !columns.iter().any(|c| c == column) {
+                            issue(&UNKNOWN_INDEX_COLUMN, format!("Index column '{column}' does not exist on table '{table_name}'."));
+                        }
+                    }
+                    None => issue(&INVALID_INDEX, "Index columns must be specified as strings.".to_string()),
+                }
+            }
+        }
+        _ => issue(&INVALID_INDEX, "Index 'columns' must contain at least one column.".to_string()),
+    }
+
+    if let Some(index_type) = index.get("type") {
+        match index_type.as_str() {
+            Some(_) if config.index_types.is_empty() => {
+                issue(&UNSUPPORTED_INDEX_TYPE, format!("Engine '{}' does not support index types.", config.name));
+            }
+            Some(t) if !config.index_types.contains(&t.to_lowercase().as_str()) => {
+                issue(
+                    &UNSUPPORTED_INDEX_TYPE,
+                    format!(
+                        "Index type '{t}' is not supported by engine '{}'. Supported types: {}.",
+                        config.name,
+                        config.index_types.join(", ")
+                    ),
+                );
+            }
+            Some(_) => (),
+            None => issue(&INVALID_INDEX, "Index type must be specified as a string.".to_string()),
+        }
+    }
+
+    if let Some(condition) = index.get("condition") {
+        if !config.partial_indexes {
+            issue(
+                &INVALID_INDEX_CONDITION,
+                format!("Partial indexes ('condition') are not supported by engine '{}'.", config.name),
+            );
+        } else if let Err(e) = parse_condition(condition) {
+            issue(&INVALID_INDEX_CONDITION, format!("Index condition is invalid: {}", e.message));
+        }
+    }
+}
+
+/// Check whether the name of a new index is still available. Pushes the index
+/// to `indexes` so later definitions in the same version are checked against it.
+fn claim_index_name(index: &Value, table_name: &str, config: &EngineConfig, indexes: &mut Vec<ExistingIndex>, issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) {
+    let Some(name) = index["name"].as_str() else {
+        return;
+    };
+
+    let conflict = indexes
+        .iter()
+        .find(|i| i.name == name && (i.table == table_name || config.index_name_scope == IndexNameScope::Schema));
+    if let Some(conflict) = conflict {
+        let message = if conflict.table == table_name {
+            format!("Index '{name}' already exists on table '{table_name}'.")
+        } else {
+            format!("Index name '{name}' is already used on table '{}'. Index names must be unique per schema.", conflict.table)
+        };
+
+        issues.push(DUPLICATE_INDEX_NAME.issue(message, version_trace.clone()));
+        return;
+    }
+
+    indexes.push(ExistingIndex {
+        table: table_name.to_string(),
+        name: name.to_string(),
+    });
+}
+
+fn require_existing_index(name: &str, table_name: &str, indexes: &[ExistingIndex], issues: &mut Vec<VerificationIssue>, version_trace: &VersionTrace) -> bool {
+    if indexes.iter().any(|i| i.table == table_name && i.name == name) {
+        return true;
+    }
+
+    issues.push(UNKNOWN_INDEX.issue(
+        format!("Index '{name}' does not exist on table '{table_name}' at this point in the version history."),
+        version_trace.clone(),
+    ));
+    false
+}
+
+/// Verify every index created, modified or dropped in `version` against the
+/// consolidated table schema and the index history up to that version.
+pub fn verify_version_indexes(version_list: &[Value], version: &Value, version_output: &str, config: &EngineConfig, issues: &mut Vec<VerificationIssue>) {
+    let Some(version_number) = version_number_of(version) else {
+        return;
+    };
+
+    let mut indexes = get_existing_indexes(version_list, version_number);
+
+    if let Some(createtable) = version["createtable"].as_object() {
+        for (table, table_data) in createtable {
+            let Some(table_indexes) = table_data["index"].as_array() else {
+                continue;
+            };
+
+            let columns = table_columns(version_list, table, version_number);
+            for (i, index) in table_indexes.iter().enumerate() {
+                let version_trace = VersionTrace::from([
+                    version_output.to_string(),
+                    "createtable".to_string(),
+                    table.to_string(),
+                    "index".to_string(),
+                    format!("item:{i}"),
+                ]);
+
+                verify_index_definition(index, table, &columns, config, issues, &version_trace);
+                claim_index_name(index, table, config, &mut indexes, issues, &version_trace);
+            }
+        }
+    }
+
+    if let Some(altertable) = version["altertable"].as_object() {
+        for (table, table_data) in altertable {
+            let columns = table_columns(version_list, table, version_number);
+            let mut version_trace = VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}")]);
+
+            if let Some(drop_index) = table_data.get("drop_index") {
+                version_trace.push("drop_index".to_string());
+                verify_index(drop_index, issues, &version_trace).ok();
+
+                for (i, name) in drop_index.as_array().into_iter().flatten().enumerate() {
+                    version_trace.push(format!("item:{i}"));
+                    match name.as_str() {
+                        Some(name) => {
+                            if require_existing_index(name, table, &indexes, issues, &version_trace) {
+                                indexes.retain(|idx| !(&idx.table == table && idx.name == name));
+                            }
+                        }
+                        None => issues.push(INVALID_INDEX.issue("Indexes to drop must be specified by name.".to_string(), version_trace.clone())),
+                    }
+                    version_trace.pop();
+                }
+                version_trace.pop();
+            }
+
+            if let Some(modify_index) = table_data.get("modify_index") {
+                version_trace.push("modify_index".to_string());
+                verify_index(modify_index, issues, &version_trace).ok();
+
+                for (i, index) in modify_index.as_array().into_iter().flatten().enumerate() {
+                    version_trace.push(format!("item:{i}"));
+                    verify_index_definition(index, table, &columns, config, issues, &version_trace);
+                    if let Some(name) = index["name"].as_str() {
+                        require_existing_index(name, table, &indexes, issues, &version_trace);
+                    }
+                    version_trace.pop();
+                }
+                version_trace.pop();
+            }
+
+            if let Some(add_index) = table_data.get("add_index") {
+                version_trace.push("add_index".to_string());
+                verify_index(add_index, issues, &version_trace).ok();
+
+                for (i, index) in add_index.as_array().into_iter().flatten().enumerate() {
+                    version_trace.push(format!("item:{i}"));
+                    verify_index_definition(index, table, &columns, config, issues, &version_trace);
+                    claim_index_name(index, table, config, &mut indexes, issues, &version_trace);
+                    version_trace.pop();
+                }
+                version_trace.pop();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mysql", feature = "postgres", feature = "sqlite"))]
+mod index_tests {
+    use super::{get_existing_indexes, verify_version_indexes, ExistingIndex};
+    use crate::core::{engine_config::EngineConfig, verification::issue::VerificationIssue};
+    use crate::engine::{mysql_impl::verification::MYSQL_CONFIG, postgres_impl::verification::POSTGRES_CONFIG, sqlite_impl::verification::SQLITE_CONFIG};
+    use serde_json::{json, Value};
+
+    fn messages(versions: &Value, config: &EngineConfig) -> Vec<String> {
+        let version_list = versions.as_array().unwrap();
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        for version in version_list {
+            verify_version_indexes(version_list, version, version["_id"].as_str().unwrap(), config, &mut issues);
+        }
+        issues.into_iter().map(|i| i.message).collect()
+    }
+
+    #[test]
+    fn valid_indexes() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "email": {"type": "VARCHAR", "length": 100}, "index": [{"name": "user_email", "columns": ["email"], "type": "btree"}]}}},
+            {"_id": "0.0.2", "altertable": {"user": {"renamecolumn": {"email": "mail"}, "modify_index": [{"name": "user_email", "columns": ["mail", "id"]}]}}},
+            {"_id": "0.0.3", "altertable": {"user": {"drop_index": ["user_email"], "add_index": [{"name": "user_email", "columns": ["id"]}]}}},
+        ]);
+
+        assert!(messages(&versions, &MYSQL_CONFIG).is_empty());
+    }
+
+    #[test]
+    fn incomplete_definition() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "index": [{"columns": []}, {"name": "user_missing", "columns": ["email"]}]}}},
+        ]);
+
+        assert_eq!(
+            messages(&versions, &MYSQL_CONFIG),
+            vec![
+                "Index does not have a name.",
+                "Index 'columns' must contain at least one column.",
+                "Index column 'email' does not exist on table 'user'.",
+            ]
+        );
+    }
+
+    #[test]
+    fn column_dropped_before_index() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "email": {"type": "TEXT"}}}},
+            {"_id": "0.0.2", "altertable": {"user": {"dropcolumn": ["email"], "add_index": [{"name": "user_email", "columns": ["email"]}]}}},
+        ]);
+
+        assert_eq!(messages(&versions, &POSTGRES_CONFIG), vec!["Index column 'email' does not exist on table 'user'."]);
+    }
+
+    #[test]
+    fn duplicate_names() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {
+                "user": {"id": {"type": "INT"}, "index": [{"name": "idx_id", "columns": ["id"]}]},
+                "post": {"id": {"type": "INT"}, "index": [{"name": "idx_id", "columns": ["id"]}]},
+            }},
+            {"_id": "0.0.2", "altertable": {"user": {"add_index": [{"name": "idx_id", "columns": ["id"]}]}}},
+        ]);
+
+        // MySQL only requires unique names per table
+        assert_eq!(messages(&versions, &MYSQL_CONFIG), vec!["Index 'idx_id' already exists on table 'user'."]);
+
+        // PostgreSQL shares index names across the schema
+        assert_eq!(
+            messages(&versions, &POSTGRES_CONFIG),
+            vec![
+                "Index name 'idx_id' is already used on table 'user'. Index names must be unique per schema.",
+                "Index 'idx_id' already exists on table 'user'.",
+            ]
+        );
+    }
+
+    #[test]
+    fn engine_specific_options() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "index": [{"name": "user_id", "columns": ["id"], "type": "gin", "condition": {"type": "is_not_null", "column": "id"}}]}}},
+        ]);
+
+        assert_eq!(
+            messages(&versions, &MYSQL_CONFIG),
+            vec![
+                "Index type 'gin' is not supported by engine 'mysql'. Supported types: btree, hash.",
+                "Partial indexes ('condition') are not supported by engine 'mysql'.",
+            ]
+        );
+        assert!(messages(&versions, &POSTGRES_CONFIG).is_empty());
+        assert_eq!(messages(&versions, &SQLITE_CONFIG), vec!["Engine 'sqlite' does not support index types."]);
+    }
+
+    #[test]
+    fn invalid_condition() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "index": [{"name": "user_id", "columns": ["id"], "condition": {"type": "between", "column": "id"}}]}}},
+        ]);
+
+        let messages = messages(&versions, &POSTGRES_CONFIG);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with("Index condition is invalid:"));
+    }
+
+    #[test]
+    fn unknown_index_references() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "index": [{"name": "user_id", "columns": ["id"]}]}}},
+            {"_id": "0.0.2", "altertable": {"user": {"drop_index": ["user_id"]}}},
+            {"_id": "0.0.3", "altertable": {"user": {"drop_index": ["user_id"], "modify_index": [{"name": "user_id", "columns": ["id"]}]}}},
+        ]);
+
+        assert_eq!(
+            messages(&versions, &MYSQL_CONFIG),
+            vec![
+                "Index 'user_id' does not exist on table 'user' at this point in the version history.",
+                "Index 'user_id' does not exist on table 'user' at this point in the version history.",
+            ]
+        );
+    }
+
+    #[test]
+    fn existing_indexes() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": {"id": {"type": "INT"}, "index": [{"name": "a", "columns": ["id"]}, {"name": "b", "columns": ["id"]}]}}},
+            {"_id": "0.0.2", "altertable": {"user": {"drop_index": ["a"], "add_index": [{"name": "c", "columns": ["id"]}]}}},
+        ]);
+
+        let indexes = get_existing_indexes(versions.as_array().unwrap(), 3);
+        let names: Vec<&str> = indexes.iter().map(|i: &ExistingIndex| i.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "c"]);
+        assert_eq!(get_existing_indexes(versions.as_array().unwrap(), 2).len(), 2);
+    }
+}
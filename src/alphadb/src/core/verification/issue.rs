@@ -14,6 +14,9 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::source_map::SourceLocation;
+use crate::core::verification::rules::INVALID_STRUCTURE;
+use serde::Deserialize;
 
 /// **Verification issue level**
 ///
@@ -22,7 +25,8 @@ use crate::core::utils::errors::AlphaDBError;
 /// Low: Will work, but will not have any effect on the database.
 /// High: Will still work, but might produce a different result than desired.
 /// Critical: Will not execute.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum VerificationIssueLevel {
     Low,
     High,
@@ -119,6 +123,11 @@ pub struct VerificationIssue {
     pub level: VerificationIssueLevel,
     pub message: String,
     pub version_trace: VersionTrace,
+    /// Stable id of the rule that produced the issue (see `core::verification::rules`)
+    pub rule: Option<&'static str>,
+
+    /// Position in the version source the issue refers to, when known
+    pub location: Option<SourceLocation>,
 }
 
 pub trait VerificationIssueAccess {
@@ -162,6 +171,8 @@ impl From<AlphaDBError> for VerificationIssue {
             level: VerificationIssueLevel::Critical,
             message: error.message,
             version_trace: error.version_trace,
+            rule: Some(INVALID_STRUCTURE.id),
+            location: error.location,
         }
     }
 }
@@ -170,6 +181,9 @@ impl From<AlphaDBError> for VerificationIssue {
 pub struct VerificationIssueDraft {
     pub level: VerificationIssueLevel,
     pub message: String,
+
+    /// Id of the rule that produced the issue
+    pub rule: &'static str,
 }
 
 pub trait IssueCollection {
@@ -0,0 +1,341 @@
+use serde_json::{Map, Value};
+
+use crate::core::{
+    utils::consolidate::column::consolidate_column,
+    verification::{
+        history::{version_number_of, versions_until},
+        issue::{VerificationIssue, VersionTrace},
+        rules::{
+            find_rule, Rule, DROPPED_COLUMN, INVALID_SUPPRESSION, LOSSY_TYPE_CHANGE, NARROWED_LENGTH, NARROWED_TYPE, NOT_NULL_WITHOUT_DEFAULT, UNKNOWN_RULE, UNUSED_ACKNOWLEDGEMENT,
+        },
+    },
+};
+
+/// A change the version source author acknowledged in the version's `_acknowledged` object,
+/// e.g. `"_acknowledged": {"dropped-column": ["user.email"]}`
+struct Acknowledgement {
+    rule: &'static Rule,
+    target: String,
+    used: bool,
+}
+
+/// Type kind and its rank within the kind. Changing to a lower rank narrows the type.
+fn type_rank(column_type: &str) -> (&str, u8) {
+    match column_type {
+        "TINYINT" => ("integer", 1),
+        "SMALLINT" | "SMALLSERIAL" => ("integer", 2),
+        "MEDIUMINT" => ("integer", 3),
+        "INT" | "INTEGER" | "SERIAL" => ("integer", 4),
+        "BIGINT" | "BIGSERIAL" => ("integer", 5),
+        "CHAR" | "VARCHAR" => ("string", 1),
+        "TINYTEXT" => ("string", 2),
+        "TEXT" => ("string", 3),
+        "MEDIUMTEXT" => ("string", 4),
+        "LONGTEXT" => ("string", 5),
+        "REAL" | "FLOAT" => ("float", 1),
+        "DOUBLE" | "DOUBLE PRECISION" => ("float", 2),
+        "DECIMAL" | "NUMERIC" => ("decimal", 1),
+        t => (t, 0),
+    }
+}
+
+/// Whether every value of the old kind can be represented by the new kind
+fn is_widening_kind_change(from: &str, to: (&str, u8)) -> bool {
+    match from {
+        "integer" => ["decimal", "float"].contains(&to.0) || (to.0 == "string" && to.1 > 1),
+        "decimal" | "float" => to.0 == "string" && to.1 > 1,
+        _ => false,
+    }
+}
+
+fn parse_acknowledgements(version: &Value, version_trace: &VersionTrace, issues: &mut Vec<VerificationIssue>) -> Vec<Acknowledgement> {
+    let mut acknowledgements = Vec::new();
+    let Some(acknowledged) = version.get("_acknowledged") else {
+        return acknowledgements;
+    };
+
+    let invalid = |issues: &mut Vec<VerificationIssue>| {
+        issues.push(INVALID_SUPPRESSION.issue("`_acknowledged` must map rule ids to a list of `table.column` changes.".to_string(), version_trace.clone()));
+    };
+
+    let Some(acknowledged) = acknowledged.as_object() else {
+        invalid(issues);
+        return acknowledgements;
+    };
+
+    for (key, targets) in acknowledged {
+        let Some(rule) = find_rule(key) else {
+            issues.push(UNKNOWN_RULE.issue(format!("Rule `{key}` does not exist."), version_trace.clone()));
+            continue;
+        };
+
+        let Some(targets) = targets.as_array() else {
+            invalid(issues);
+            continue;
+        };
+
+        for target in targets {
+            match target.as_str() {
+                Some(target) => acknowledgements.push(Acknowledgement {
+                    rule,
+                    target: target.to_string(),
+                    used: false,
+                }),
+                None => invalid(issues),
+            }
+        }
+    }
+
+    acknowledgements
+}
+
+struct Linter<'a> {
+    acknowledgements: Vec<Acknowledgement>,
+    issues: &'a mut Vec<VerificationIssue>,
+}
+
+impl Linter<'_> {
+    /// Report a lint, unless the change is acknowledged
+    fn report(&mut self, rule: &'static Rule, target: &str, message: String, version_trace: &VersionTrace) {
+        if let Some(acknowledgement) = self.acknowledgements.iter_mut().find(|a| a.rule == rule && a.target == target) {
+            acknowledgement.used = true;
+            return;
+        }
+
+        self.issues.push(rule.issue(message, version_trace.clone()));
+    }
+
+    fn lint_modification(&mut self, target: &str, previous: &Value, modification: &Map<String, Value>, version_trace: &VersionTrace) {
+        // Without `recreate: false` the modification replaces the whole definition
+        let mut column = match modification.get("recreate") == Some(&Value::Bool(false)) {
+            true => previous.as_object().cloned().unwrap_or_default(),
+            false => Map::new(),
+        };
+        for (attr, value) in modification {
+            if attr != "recreate" {
+                column.insert(attr.to_string(), value.clone());
+            }
+        }
+
+        let previous_type = previous["type"].as_str().unwrap_or_default().to_uppercase();
+        let new_type = column.get("type").and_then(Value::as_str).map(str::to_uppercase).unwrap_or(previous_type.clone());
+        let (previous_kind, previous_rank) = type_rank(&previous_type);
+        let (new_kind, new_rank) = type_rank(&new_type);
+
+        if previous_kind != new_kind {
+            if !is_widening_kind_change(previous_kind, (new_kind, new_rank)) {
+                self.report(
+                    &LOSSY_TYPE_CHANGE,
+                    target,
+                    format!("Changing column `{target}` from {previous_type} to {new_type} converts its values and may lose data."),
+                    version_trace,
+                );
+            }
+        } else if new_rank < previous_rank {
+            self.report(
+                &NARROWED_TYPE,
+                target,
+                format!("Changing column `{target}` from {previous_type} to {new_type} narrows its type."),
+                version_trace,
+            );
+        } else if new_rank == 1 && previous_kind == "string" {
+            if let (Some(previous_length), Some(new_length)) = (previous["length"].as_u64(), column.get("length").and_then(Value::as_u64)) {
+                if new_length < previous_length {
+                    self.report(
+                        &NARROWED_LENGTH,
+                        target,
+                        format!("Reducing the length of column `{target}` from {previous_length} to {new_length} may truncate existing values."),
+                        version_trace,
+                    );
+                }
+            }
+        }
+
+        let was_nullable = previous["null"] == true;
+        let is_nullable = column.get("null") == Some(&Value::Bool(true));
+        let has_default = column.get("default").is_some_and(|d| !d.is_null());
+        if was_nullable && !is_nullable && !has_default {
+            self.report(
+                &NOT_NULL_WITHOUT_DEFAULT,
+                target,
+                format!("Column `{target}` is made NOT NULL without a default, which fails when existing rows hold NULL."),
+                version_trace,
+            );
+        }
+    }
+}
+
+/// Compare every `dropcolumn` and `modifycolumn` in `version` against the column definition
+/// before that version, and report destructive or lossy changes that are not acknowledged.
+pub fn lint_version(version_list: &[Value], version: &Value, version_output: &str, issues: &mut Vec<VerificationIssue>) {
+    let Some(version_number) = version_number_of(version) else {
+        return;
+    };
+
+    let acknowledgements = parse_acknowledgements(version, &VersionTrace::from([version_output, "_acknowledged"]), issues);
+    let mut linter = Linter { acknowledgements, issues };
+
+    let previous_versions: Vec<Value> = versions_until(version_list, version_number, false).into_iter().cloned().collect();
+    let previous_column = |table: &str, column: &str| {
+        consolidate_column(&previous_versions, column, table, None)
+            .ok()
+            .filter(|c| c.as_object().is_some_and(|c| !c.is_empty()))
+    };
+
+    for (table, table_data) in version["altertable"].as_object().into_iter().flatten() {
+        let mut version_trace = VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}")]);
+
+        if let Some(dropped) = table_data["dropcolumn"].as_array() {
+            version_trace.push("dropcolumn".to_string());
+            for column in dropped.iter().filter_map(Value::as_str) {
+                if previous_column(table, column).is_some() {
+                    let target = format!("{table}.{column}");
+                    linter.report(&DROPPED_COLUMN, &target, format!("Dropping column `{target}` deletes all of its data."), &version_trace);
+                }
+            }
+            version_trace.pop();
+        }
+
+        if let Some(modified) = table_data["modifycolumn"].as_object() {
+            version_trace.push("modifycolumn".to_string());
+            for (column, modification) in modified {
+                let (Some(previous), Some(modification)) = (previous_column(table, column), modification.as_object()) else {
+                    continue;
+                };
+
+                version_trace.push(format!("column:{column}"));
+                linter.lint_modification(&format!("{table}.{column}"), &previous, modification, &version_trace);
+                version_trace.pop();
+            }
+            version_trace.pop();
+        }
+    }
+
+    for acknowledgement in linter.acknowledgements.iter().filter(|a| !a.used) {
+        linter.issues.push(UNUSED_ACKNOWLEDGEMENT.issue(
+            format!(
+                "Acknowledged change `{}` does not trigger rule {} in this version.",
+                acknowledgement.target, acknowledgement.rule.id
+            ),
+            VersionTrace::from([version_output, "_acknowledged"]),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod lint_tests {
+    use super::lint_version;
+    use crate::core::verification::issue::VerificationIssue;
+    use serde_json::{json, Value};
+
+    fn lints(versions: &Value) -> Vec<(&'static str, String)> {
+        let version_list = versions.as_array().unwrap();
+        let mut issues: Vec<VerificationIssue> = Vec::new();
+        for version in version_list {
+            lint_version(version_list, version, version["_id"].as_str().unwrap(), &mut issues);
+        }
+        issues.into_iter().map(|i| (i.rule.unwrap_or_default(), i.message)).collect()
+    }
+
+    fn user_table() -> Value {
+        json!({"id": {"type": "BIGINT"}, "name": {"type": "VARCHAR", "length": 200, "null": true}, "bio": {"type": "TEXT"}, "age": {"type": "INT"}})
+    }
+
+    #[test]
+    fn destructive_changes() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": user_table()}},
+            {"_id": "0.0.2", "altertable": {"user": {
+                "dropcolumn": ["bio", "unknown"],
+                "modifycolumn": {
+                    "id": {"type": "INTEGER"},
+                    "name": {"recreate": false, "length": 100, "null": false},
+                    "age": {"type": "VARCHAR", "length": 3},
+                },
+            }}},
+        ]);
+
+        assert_eq!(
+            lints(&versions),
+            vec![
+                ("ADB0101", "Dropping column `user.bio` deletes all of its data.".to_string()),
+                ("ADB0103", "Changing column `user.id` from BIGINT to INTEGER narrows its type.".to_string()),
+                (
+                    "ADB0102",
+                    "Reducing the length of column `user.name` from 200 to 100 may truncate existing values.".to_string()
+                ),
+                (
+                    "ADB0105",
+                    "Column `user.name` is made NOT NULL without a default, which fails when existing rows hold NULL.".to_string()
+                ),
+                (
+                    "ADB0104",
+                    "Changing column `user.age` from INT to VARCHAR converts its values and may lose data.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn safe_changes() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": user_table()}},
+            {"_id": "0.0.2", "altertable": {"user": {
+                "modifycolumn": {
+                    "age": {"type": "BIGINT"},
+                    "name": {"recreate": false, "length": 300, "default": "unknown"},
+                    "bio": {"type": "LONGTEXT"},
+                },
+            }}},
+            {"_id": "0.0.3", "altertable": {"user": {"modifycolumn": {"age": {"type": "DECIMAL"}}}}},
+        ]);
+
+        assert!(lints(&versions).is_empty());
+    }
+
+    #[test]
+    fn acknowledged_changes() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": user_table()}},
+            {
+                "_id": "0.0.2",
+                "_acknowledged": {"dropped-column": ["user.bio"], "ADB0103": ["user.id", "user.age"], "ADB9999": []},
+                "altertable": {"user": {"dropcolumn": ["bio", "age"], "modifycolumn": {"id": {"type": "INT"}}}},
+            },
+        ]);
+
+        assert_eq!(
+            lints(&versions),
+            vec![
+                ("ADB0008", "Rule `ADB9999` does not exist.".to_string()),
+                ("ADB0101", "Dropping column `user.age` deletes all of its data.".to_string()),
+                ("ADB0106", "Acknowledged change `user.age` does not trigger rule ADB0103 in this version.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn renamed_columns() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": user_table()}},
+            {"_id": "0.0.2", "altertable": {"user": {"renamecolumn": {"bio": "about"}}}},
+            {"_id": "0.0.3", "altertable": {"user": {"modifycolumn": {"about": {"type": "VARCHAR", "length": 50}}}}},
+        ]);
+
+        assert_eq!(
+            lints(&versions),
+            vec![("ADB0103", "Changing column `user.about` from TEXT to VARCHAR narrows its type.".to_string())]
+        );
+    }
+
+    #[test]
+    fn malformed_history() {
+        let versions = json!([
+            {"_id": "0.0.1", "createtable": {"user": user_table(), "broken": "not-an-object"}},
+            {"_id": "0.0.2", "createtable": "not-an-object"},
+            {"_id": "0.0.3", "altertable": {"user": {"dropcolumn": ["bio"]}, "broken": {"dropcolumn": ["id"]}}},
+        ]);
+
+        assert_eq!(lints(&versions), vec![]);
+    }
+}
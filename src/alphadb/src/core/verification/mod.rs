@@ -14,8 +14,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 pub mod compatibility;
+pub mod config;
+pub mod custom;
+pub mod default_data;
 pub mod foreign_key;
+pub mod history;
+pub mod identifier;
 pub mod index;
 pub mod issue;
 pub mod json;
+pub mod lint;
 pub mod primary_key;
+pub mod report;
+pub mod rules;
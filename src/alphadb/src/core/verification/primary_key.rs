@@ -2,27 +2,23 @@ use serde_json::Value;
 
 use crate::{
     core::{
-        query::primary_key::format_primary_key_columns,
+        query::primary_key::primary_key_columns,
         utils::json::exists_in_object,
-        verification::issue::{VerificationIssue, VerificationIssueLevel},
+        verification::{issue::VerificationIssue, rules::UNKNOWN_PRIMARY_KEY_COLUMN},
     },
     verification::VersionTrace,
 };
 
 pub fn verify_primary_key(primary_key: &Value, table: &Value) -> Result<(), VerificationIssue> {
-    format_primary_key_columns(primary_key)?;
+    primary_key_columns(primary_key)?;
 
     if let Some(primary_keys) = primary_key.as_array() {
         for value in primary_keys {
-            // Unwrap is safe here because of format_primary_key_columns function
+            // Unwrap is safe here because of primary_key_columns
             let pk = value.as_str().unwrap();
 
             if !exists_in_object(table, pk)? {
-                return Err(VerificationIssue {
-                    level: VerificationIssueLevel::Critical,
-                    message: format!("Primary key part '{pk}' does not match any column name"),
-                    version_trace: VersionTrace::new(),
-                });
+                return Err(UNKNOWN_PRIMARY_KEY_COLUMN.issue(format!("Primary key part '{pk}' does not match any column name"), VersionTrace::new()));
             }
         }
     }
@@ -0,0 +1,319 @@
+use std::{fmt, path::Path, str::FromStr};
+
+use serde_json::{json, Map, Value};
+
+use crate::core::{
+    utils::errors::{get_version_trace_string, AlphaDBError},
+    verification::{
+        issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+        rules::{find_rule_in, Rule, RULES},
+    },
+};
+
+/// Machine readable formats a list of verification issues can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// SARIF 2.1.0 log, read by code review tools
+    Sarif,
+
+    /// JUnit XML, read by CI test reporters
+    Junit,
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportFormat::Sarif => write!(f, "sarif"),
+            ReportFormat::Junit => write!(f, "junit"),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = AlphaDBError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sarif" => Ok(ReportFormat::Sarif),
+            "junit" => Ok(ReportFormat::Junit),
+            _ => Err(AlphaDBError {
+                message: format!("\"{s}\" is not a supported report format (expected one of: sarif, junit)"),
+                error: "unsupported-report-format".to_string(),
+                version_trace: VersionTrace::new(),
+                location: None,
+            }),
+        }
+    }
+}
+
+/// Write the issues in the given format
+///
+/// # Arguments
+/// * `issues` - Issues returned by `AlphaDBVerification::verify`
+/// * `format` - Format to write
+/// * `custom_rules` - Custom rules the issues may come from, see `AlphaDBVerification::custom_rules`
+/// * `source` - Version source file the issues are reported against, used for issues whose location does not name a file
+pub fn verification_report(issues: &[VerificationIssue], format: ReportFormat, custom_rules: &[Rule], source: Option<&Path>) -> String {
+    match format {
+        ReportFormat::Sarif => serde_json::to_string_pretty(&sarif_report(issues, custom_rules, source)).unwrap_or_default(),
+        ReportFormat::Junit => junit_report(issues, custom_rules, source),
+    }
+}
+
+fn sarif_level(level: VerificationIssueLevel) -> &'static str {
+    match level {
+        VerificationIssueLevel::Low => "note",
+        VerificationIssueLevel::High => "warning",
+        VerificationIssueLevel::Critical => "error",
+    }
+}
+
+fn junit_level(level: VerificationIssueLevel) -> &'static str {
+    match level {
+        VerificationIssueLevel::Low => "low",
+        VerificationIssueLevel::High => "high",
+        VerificationIssueLevel::Critical => "critical",
+    }
+}
+
+/// Forward slashed path, as SARIF expects URIs
+fn uri(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Write the issues as a SARIF 2.1.0 log. Each issue becomes a result with its rule id,
+/// level (`note`, `warning` or `error`) and, when known, its file, line and column.
+/// Custom rules are described next to the built-in rules.
+pub fn sarif_report(issues: &[VerificationIssue], custom_rules: &[Rule], source: Option<&Path>) -> Value {
+    // Only the rules that produced an issue are described, built-in rules in rule id order
+    let rules: Vec<Value> = RULES
+        .iter()
+        .chain(custom_rules)
+        .filter(|rule| issues.iter().any(|issue| issue.rule == Some(rule.id)))
+        .map(|rule| {
+            json!({
+                "id": rule.id,
+                "name": rule.name,
+                "shortDescription": {"text": rule.summary},
+                "fullDescription": {"text": rule.explanation},
+                "defaultConfiguration": {"level": sarif_level(rule.level)},
+            })
+        })
+        .collect();
+
+    let results: Vec<Value> = issues
+        .iter()
+        .map(|issue| {
+            let mut result = Map::new();
+            if let Some(rule) = issue.rule {
+                result.insert("ruleId".to_string(), json!(rule));
+            }
+            result.insert("level".to_string(), json!(sarif_level(issue.level)));
+            result.insert("message".to_string(), json!({"text": issue.message}));
+
+            let file = issue.location.as_ref().and_then(|l| l.file.as_deref()).or(source);
+            if let Some(file) = file {
+                let mut physical_location = json!({"artifactLocation": {"uri": uri(file)}});
+                if let Some(location) = &issue.location {
+                    physical_location["region"] = json!({"startLine": location.line, "startColumn": location.column});
+                }
+                result.insert("locations".to_string(), json!([{"physicalLocation": physical_location}]));
+            }
+
+            if !issue.version_trace.is_empty() {
+                result.insert(
+                    "logicalLocations".to_string(),
+                    json!([{"fullyQualifiedName": get_version_trace_string(&issue.version_trace)}]),
+                );
+            }
+
+            Value::Object(result)
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "AlphaDB",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_HOMEPAGE"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write the issues as a JUnit XML report. Every issue is a failing test case, grouped by
+/// version, with the issue level as failure type. Without issues, the report holds a single
+/// passing test case.
+pub fn junit_report(issues: &[VerificationIssue], custom_rules: &[Rule], source: Option<&Path>) -> String {
+    let suite = match source {
+        Some(source) => uri(source),
+        None => "version source".to_string(),
+    };
+    let tests = issues.len().max(1);
+
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    report.push_str(&format!("<testsuites name=\"AlphaDB verification\" tests=\"{tests}\" failures=\"{}\">\n", issues.len()));
+    report.push_str(&format!("  <testsuite name=\"{}\" tests=\"{tests}\" failures=\"{}\">\n", escape_xml(&suite), issues.len()));
+
+    if issues.is_empty() {
+        report.push_str(&format!("    <testcase classname=\"{}\" name=\"verify\"/>\n", escape_xml(&suite)));
+    }
+
+    for issue in issues {
+        let mut trace = issue.version_trace.iter();
+        let classname = match trace.next() {
+            Some(version) => format!("Version {version}"),
+            None => suite.clone(),
+        };
+
+        let mut name = match (issue.rule.and_then(|key| find_rule_in(key, custom_rules)), issue.rule) {
+            (Some(rule), _) => format!("[{}] {}", rule.id, rule.name),
+            (None, Some(id)) => format!("[{id}]"),
+            (None, None) => "verification".to_string(),
+        };
+        let path = trace.cloned().collect::<Vec<String>>().join("->");
+        if !path.is_empty() {
+            name = format!("{name}: {path}");
+        }
+
+        let mut body = String::new();
+        if let Some(location) = &issue.location {
+            match (&location.file, source) {
+                (None, Some(source)) => body.push_str(&format!("{}:{}:{}\n", uri(source), location.line, location.column)),
+                _ => body.push_str(&format!("{location}\n")),
+            }
+        }
+        body.push_str(&issue.message);
+
+        report.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"{}\">\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n    </testcase>\n",
+            escape_xml(&classname),
+            escape_xml(&name),
+            junit_level(issue.level),
+            escape_xml(&issue.message),
+            escape_xml(&body)
+        ));
+    }
+
+    report.push_str("  </testsuite>\n</testsuites>\n");
+    report
+}
+
+#[cfg(test)]
+mod report_tests {
+    use super::{junit_report, sarif_report, ReportFormat};
+    use crate::core::{
+        utils::source_map::SourceLocation,
+        verification::{
+            issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+            rules::{Rule, MISSING_COLUMN_TYPE, MISSING_NAME},
+        },
+    };
+    use std::path::Path;
+
+    const MISSING_CREATED_AT: Rule = Rule {
+        id: "ACME001",
+        name: "missing-created-at",
+        level: VerificationIssueLevel::High,
+        summary: "Every table must have a `created_at` column.",
+        explanation: "Add a `created_at` DATETIME column to the table.",
+    };
+
+    fn issues() -> Vec<VerificationIssue> {
+        let mut missing_type = MISSING_COLUMN_TYPE.issue(
+            "Does not contain a column type".to_string(),
+            VersionTrace::from(["0.0.1", "createtable", "table:user", "column:<id>"]),
+        );
+        missing_type.location = Some(SourceLocation { file: None, line: 7, column: 17 });
+
+        vec![MISSING_NAME.issue("No rootlevel name specified.".to_string(), VersionTrace::new()), missing_type]
+    }
+
+    #[test]
+    fn sarif() {
+        let report = sarif_report(&issues(), &[], Some(Path::new("db/structure.json")));
+        let run = &report["runs"][0];
+
+        let rules: Vec<&str> = run["tool"]["driver"]["rules"].as_array().unwrap().iter().map(|r| r["id"].as_str().unwrap()).collect();
+        assert_eq!(rules, vec!["ADB0002", "ADB0010"]);
+
+        assert_eq!(run["results"][0]["ruleId"], "ADB0002");
+        assert_eq!(run["results"][0]["level"], "error");
+        assert_eq!(run["results"][0]["locations"][0]["physicalLocation"].get("region"), None);
+        assert_eq!(run["results"][1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "db/structure.json");
+        assert_eq!(run["results"][1]["locations"][0]["physicalLocation"]["region"]["startLine"], 7);
+        assert_eq!(
+            run["results"][1]["logicalLocations"][0]["fullyQualifiedName"],
+            "0.0.1->createtable->table:user->column:<id>"
+        );
+
+        // Without a file there is nothing to point at
+        assert_eq!(sarif_report(&issues(), &[], None)["runs"][0]["results"][1].get("locations"), None);
+    }
+
+    #[test]
+    fn junit() {
+        let report = junit_report(&issues(), &[], Some(Path::new("structure.json")));
+
+        assert!(report.contains("<testsuites name=\"AlphaDB verification\" tests=\"2\" failures=\"2\">"));
+        assert!(report.contains("<testcase classname=\"Version 0.0.1\" name=\"[ADB0010] missing-column-type: createtable-&gt;table:user-&gt;column:&lt;id&gt;\">"));
+        assert!(report.contains("<failure type=\"critical\" message=\"Does not contain a column type\">structure.json:7:17\nDoes not contain a column type</failure>"));
+        assert!(report.contains("<testcase classname=\"structure.json\" name=\"[ADB0002] missing-name\">"));
+
+        assert!(junit_report(&[], &[], None).contains("<testcase classname=\"version source\" name=\"verify\"/>"));
+    }
+
+    #[test]
+    fn custom_rule() {
+        let issues = vec![
+            MISSING_CREATED_AT.issue(
+                "Table user has no created_at column".to_string(),
+                VersionTrace::from(["0.0.1", "createtable", "table:user"]),
+            ),
+            VerificationIssue {
+                rule: Some("ACME002"),
+                ..MISSING_CREATED_AT.issue("From a rule that is not passed".to_string(), VersionTrace::new())
+            },
+        ];
+
+        let report = sarif_report(&issues, &[MISSING_CREATED_AT], None);
+        let rules = &report["runs"][0]["tool"]["driver"]["rules"];
+        assert_eq!(rules.as_array().unwrap().len(), 1);
+        assert_eq!(rules[0]["id"], "ACME001");
+        assert_eq!(rules[0]["name"], "missing-created-at");
+        assert_eq!(rules[0]["defaultConfiguration"]["level"], "warning");
+        assert_eq!(report["runs"][0]["results"][0]["ruleId"], "ACME001");
+
+        let report = junit_report(&issues, &[MISSING_CREATED_AT], None);
+        assert!(report.contains("<testcase classname=\"Version 0.0.1\" name=\"[ACME001] missing-created-at: createtable-&gt;table:user\">"));
+        assert!(report.contains("<testcase classname=\"version source\" name=\"[ACME002]\">"));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!("SARIF".parse::<ReportFormat>().unwrap(), ReportFormat::Sarif);
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+}
@@ -0,0 +1,529 @@
+use crate::core::verification::issue::{VerificationIssue, VerificationIssueDraft, VerificationIssueLevel, VersionTrace};
+
+/// A verification rule. Its `id` is stable across releases, so it can be
+/// referenced from version sources and verification configs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Stable identifier, e.g. `ADB0101`
+    pub id: &'static str,
+
+    /// Short kebab-case name, accepted wherever the id is
+    pub name: &'static str,
+
+    /// Level of the issues produced by this rule, unless overridden by a `VerificationConfig`
+    pub level: VerificationIssueLevel,
+
+    /// One line description of what the rule checks
+    pub summary: &'static str,
+
+    /// Longer explanation of why the rule exists and how to resolve its issues
+    pub explanation: &'static str,
+}
+
+impl Rule {
+    /// Create an issue for this rule
+    pub fn issue(&self, message: String, version_trace: VersionTrace) -> VerificationIssue {
+        VerificationIssue {
+            level: self.level,
+            message,
+            version_trace,
+            rule: Some(self.id),
+            location: None,
+        }
+    }
+
+    /// Create an issue draft for this rule, as returned by engine verification hooks
+    pub fn draft(&self, message: String) -> VerificationIssueDraft {
+        VerificationIssueDraft {
+            level: self.level,
+            message,
+            rule: self.id,
+        }
+    }
+
+    /// Whether `key` refers to this rule, by id or by name
+    pub fn matches(&self, key: &str) -> bool {
+        self.id.eq_ignore_ascii_case(key) || self.name == key
+    }
+}
+
+// Version source structure
+
+pub const INVALID_STRUCTURE: Rule = Rule {
+    id: "ADB0001",
+    name: "invalid-structure",
+    level: VerificationIssueLevel::Critical,
+    summary: "A part of the version source could not be processed.",
+    explanation: "AlphaDB expected a different structure at this location, e.g. an object where a string was given, or a value it could not parse. \
+                  The version can not be converted to queries until the structure is fixed.",
+};
+
+pub const MISSING_NAME: Rule = Rule {
+    id: "ADB0002",
+    name: "missing-name",
+    level: VerificationIssueLevel::Critical,
+    summary: "The version source has no root level `name`.",
+    explanation: "The name identifies the version source in the database configuration table. Add a `name` key at the root of the version source.",
+};
+
+pub const NO_VERSIONS: Rule = Rule {
+    id: "ADB0003",
+    name: "no-versions",
+    level: VerificationIssueLevel::Low,
+    summary: "The version source does not contain any versions.",
+    explanation: "Without a `version` array there is nothing to apply. Updating the database will only initialize it.",
+};
+
+pub const MISSING_VERSION_NUMBER: Rule = Rule {
+    id: "ADB0004",
+    name: "missing-version-number",
+    level: VerificationIssueLevel::Critical,
+    summary: "A version has no `_id`, or its `_id` is not a valid version number.",
+    explanation: "Versions are ordered and recorded by their `_id`, e.g. `\"_id\": \"0.1.0\"`. \
+                  Versions without a valid number can not be placed in the history and are skipped by most checks.",
+};
+
+pub const UNKNOWN_METHOD: Rule = Rule {
+    id: "ADB0005",
+    name: "unknown-method",
+    level: VerificationIssueLevel::High,
+    summary: "A version contains a key that is not a known method.",
+    explanation: "Unknown keys are ignored when the version is applied. This is usually a typo, e.g. `altetable` instead of `altertable`.",
+};
+
+pub const EMPTY_BLOCK: Rule = Rule {
+    id: "ADB0006",
+    name: "empty-block",
+    level: VerificationIssueLevel::Low,
+    summary: "A `createtable` or `altertable` block does not contain any data.",
+    explanation: "The block has no effect on the database and can be removed.",
+};
+
+pub const INVALID_SUPPRESSION: Rule = Rule {
+    id: "ADB0007",
+    name: "invalid-suppression",
+    level: VerificationIssueLevel::High,
+    summary: "An `_ignore` or `_acknowledged` key does not have the expected structure.",
+    explanation: "`_ignore` takes a list of rule ids, e.g. `\"_ignore\": [\"ADB0012\"]`. \
+                  `_acknowledged` maps rule ids to the changes they cover, e.g. `\"_acknowledged\": {\"ADB0101\": [\"user.email\"]}`.",
+};
+
+pub const UNKNOWN_RULE: Rule = Rule {
+    id: "ADB0008",
+    name: "unknown-rule",
+    level: VerificationIssueLevel::Low,
+    summary: "A rule referenced by `_ignore`, `_acknowledged` or a verification config does not exist.",
+    explanation: "The reference has no effect. Check the id against the rule list, e.g. with `alphadb verify --explain <id>`.",
+};
+
+pub const INVALID_DEFINITION_TYPE: Rule = Rule {
+    id: "ADB0009",
+    name: "invalid-definition-type",
+    level: VerificationIssueLevel::High,
+    summary: "A definition is not specified as the expected JSON type.",
+    explanation: "Some definitions are lists and others are objects, e.g. `index` takes an array of index objects. \
+                  Definitions of the wrong type are skipped by the other checks.",
+};
+
+// Columns
+
+pub const MISSING_COLUMN_TYPE: Rule = Rule {
+    id: "ADB0010",
+    name: "missing-column-type",
+    level: VerificationIssueLevel::Critical,
+    summary: "A column definition does not contain a type.",
+    explanation: "Every created column, and every modified column that is recreated, needs a `type`. \
+                  Use `\"recreate\": false` to modify a column while keeping its current type.",
+};
+
+pub const INCOMPATIBLE_ATTRIBUTES: Rule = Rule {
+    id: "ADB0011",
+    name: "incompatible-attributes",
+    level: VerificationIssueLevel::Critical,
+    summary: "A column combines attributes that the engine does not allow together.",
+    explanation: "Some attributes exclude each other, e.g. an AUTO_INCREMENT column can not be NULL. Remove one of the attributes.",
+};
+
+pub const INCOMPATIBLE_TYPE_ATTRIBUTE: Rule = Rule {
+    id: "ADB0012",
+    name: "incompatible-type-attribute",
+    level: VerificationIssueLevel::Critical,
+    summary: "A column attribute is not supported for the column type.",
+    explanation: "Some attributes only work on specific types, e.g. AUTO_INCREMENT on integer columns. \
+                  Change the type or remove the attribute.",
+};
+
+pub const INVALID_BOOLEAN_DEFAULT: Rule = Rule {
+    id: "ADB0013",
+    name: "invalid-boolean-default",
+    level: VerificationIssueLevel::High,
+    summary: "The default value of a BOOLEAN column is not `true` or `false`.",
+    explanation: "Any other default value will be treated as false.",
+};
+
+pub const DROPPED_PRIMARY_KEY: Rule = Rule {
+    id: "ADB0014",
+    name: "dropped-primary-key",
+    level: VerificationIssueLevel::Low,
+    summary: "The column that is the table's primary key is dropped.",
+    explanation: "Dropping the column removes the primary key as well. Define a new primary key if the table still needs one.",
+};
+
+pub const UNKNOWN_PRIMARY_KEY_COLUMN: Rule = Rule {
+    id: "ADB0015",
+    name: "unknown-primary-key-column",
+    level: VerificationIssueLevel::Critical,
+    summary: "The primary key refers to a column that does not exist in the table.",
+    explanation: "The `primary_key` of a table must name one of its columns.",
+};
+
+pub const UNSUPPORTED_COLUMN_TYPE: Rule = Rule {
+    id: "ADB0016",
+    name: "unsupported-column-type",
+    level: VerificationIssueLevel::Critical,
+    summary: "The column type is not supported on the engine.",
+    explanation: "No query can be generated for the column. Use a type the engine supports, \
+                  or a type every engine supports when the version source is used on several engines.",
+};
+
+// Indexes
+
+pub const INVALID_INDEX: Rule = Rule {
+    id: "ADB0020",
+    name: "invalid-index",
+    level: VerificationIssueLevel::Critical,
+    summary: "An index definition is incomplete or malformed.",
+    explanation: "Indexes are defined as objects with a `name` and a non-empty `columns` array, inside an `index`, \
+                  `add_index` or `modify_index` array. `drop_index` lists index names.",
+};
+
+pub const UNKNOWN_INDEX_COLUMN: Rule = Rule {
+    id: "ADB0021",
+    name: "unknown-index-column",
+    level: VerificationIssueLevel::Critical,
+    summary: "An index refers to a column that does not exist on the table at that version.",
+    explanation: "Index columns are resolved against the consolidated table, so renamed and dropped columns are taken into account.",
+};
+
+pub const DUPLICATE_INDEX_NAME: Rule = Rule {
+    id: "ADB0022",
+    name: "duplicate-index-name",
+    level: VerificationIssueLevel::Critical,
+    summary: "An index name is already in use.",
+    explanation: "Index names must be unique per table on MySQL and MariaDB, and per schema on PostgreSQL and SQLite.",
+};
+
+pub const UNSUPPORTED_INDEX_TYPE: Rule = Rule {
+    id: "ADB0023",
+    name: "unsupported-index-type",
+    level: VerificationIssueLevel::Critical,
+    summary: "The index `type` is not supported by the engine.",
+    explanation: "Each engine supports its own index methods, e.g. `gin` only exists on PostgreSQL and SQLite has none.",
+};
+
+pub const INVALID_INDEX_CONDITION: Rule = Rule {
+    id: "ADB0024",
+    name: "invalid-index-condition",
+    level: VerificationIssueLevel::Critical,
+    summary: "The index `condition` is invalid, or the engine does not support partial indexes.",
+    explanation: "Conditions use the same format as other AlphaDB conditions. MySQL and MariaDB do not support partial indexes.",
+};
+
+pub const UNKNOWN_INDEX: Rule = Rule {
+    id: "ADB0025",
+    name: "unknown-index",
+    level: VerificationIssueLevel::Critical,
+    summary: "`drop_index` or `modify_index` refers to an index that does not exist at that point in the history.",
+    explanation: "The index was never created on this table, or it was dropped in an earlier version.",
+};
+
+// Foreign keys
+
+pub const INVALID_FOREIGN_KEY: Rule = Rule {
+    id: "ADB0030",
+    name: "invalid-foreign-key",
+    level: VerificationIssueLevel::Critical,
+    summary: "A foreign key definition is incomplete or malformed.",
+    explanation: "Foreign keys are objects with a `name`, `from`, `references` and `to`, inside a `foreign_key`, \
+                  `add_foreign_key` or `modify_foreign_key` array.",
+};
+
+pub const UNKNOWN_FOREIGN_KEY_COLUMN: Rule = Rule {
+    id: "ADB0031",
+    name: "unknown-foreign-key-column",
+    level: VerificationIssueLevel::Critical,
+    summary: "The `from` column of a foreign key does not exist on its table.",
+    explanation: "Foreign key columns are resolved against the consolidated table at the foreign key's version.",
+};
+
+pub const UNKNOWN_REFERENCE: Rule = Rule {
+    id: "ADB0032",
+    name: "unknown-reference",
+    level: VerificationIssueLevel::Critical,
+    summary: "A foreign key references a table or column that does not exist at that version.",
+    explanation: "The referenced table must be created, and the referenced column added, in the same or an earlier version.",
+};
+
+pub const NON_UNIQUE_REFERENCE: Rule = Rule {
+    id: "ADB0033",
+    name: "non-unique-reference",
+    level: VerificationIssueLevel::Critical,
+    summary: "A foreign key references a column that is not a primary key or unique column.",
+    explanation: "Databases require the referenced column to be unique, so every row points at exactly one referenced row.",
+};
+
+pub const INCOMPATIBLE_FOREIGN_KEY_TYPES: Rule = Rule {
+    id: "ADB0034",
+    name: "incompatible-foreign-key-types",
+    level: VerificationIssueLevel::Critical,
+    summary: "A foreign key column and the column it references have incompatible types.",
+    explanation: "Both columns must hold the same kind of value, e.g. two integer columns.",
+};
+
+pub const FOREIGN_KEY_TYPE_MISMATCH: Rule = Rule {
+    id: "ADB0035",
+    name: "foreign-key-type-mismatch",
+    level: VerificationIssueLevel::High,
+    summary: "A foreign key column and the column it references have different types of the same kind.",
+    explanation: "Some engines, like MySQL, reject foreign keys between e.g. INT and BIGINT. Use the same type on both sides.",
+};
+
+pub const SET_NULL_ON_NOT_NULL: Rule = Rule {
+    id: "ADB0036",
+    name: "set-null-on-not-null",
+    level: VerificationIssueLevel::Critical,
+    summary: "A foreign key uses a `set null` action on a column that does not allow NULL.",
+    explanation: "Deleting or updating a referenced row would have to store NULL in a NOT NULL column. Make the column nullable or pick another action.",
+};
+
+pub const REFERENCED_COLUMN_REMOVED: Rule = Rule {
+    id: "ADB0037",
+    name: "referenced-column-removed",
+    level: VerificationIssueLevel::Critical,
+    summary: "A column is dropped or renamed while a foreign key still references it.",
+    explanation: "Drop or modify the foreign key in the same or an earlier version, before removing the column it points at.",
+};
+
+// Default data
+
+pub const DEFAULT_DATA_UNKNOWN_TABLE: Rule = Rule {
+    id: "ADB0040",
+    name: "default-data-unknown-table",
+    level: VerificationIssueLevel::Critical,
+    summary: "Default data is specified for a table that does not exist at that version.",
+    explanation: "Default data can only be inserted into tables created in the same or an earlier version.",
+};
+
+pub const INVALID_DEFAULT_DATA: Rule = Rule {
+    id: "ADB0041",
+    name: "invalid-default-data",
+    level: VerificationIssueLevel::Critical,
+    summary: "A default data item is not an object.",
+    explanation: "Default data rows map column names to values, e.g. `{\"name\": \"admin\"}`.",
+};
+
+pub const DEFAULT_DATA_UNKNOWN_COLUMN: Rule = Rule {
+    id: "ADB0042",
+    name: "default-data-unknown-column",
+    level: VerificationIssueLevel::Critical,
+    summary: "Default data specifies a value for a column that does not exist.",
+    explanation: "Every column in a default data row must exist on the table at the version the data is inserted.",
+};
+
+pub const DEFAULT_DATA_TYPE_MISMATCH: Rule = Rule {
+    id: "ADB0043",
+    name: "default-data-type-mismatch",
+    level: VerificationIssueLevel::Critical,
+    summary: "A default data value does not match the column type.",
+    explanation: "String columns take strings, integer columns integers and float columns numbers.",
+};
+
+pub const DEFAULT_DATA_TOO_LONG: Rule = Rule {
+    id: "ADB0044",
+    name: "default-data-too-long",
+    level: VerificationIssueLevel::Critical,
+    summary: "A default data value is longer than the column's `length`.",
+    explanation: "Inserting the value fails, or truncates it, depending on the engine.",
+};
+
+pub const DEFAULT_DATA_MISSING_VALUE: Rule = Rule {
+    id: "ADB0045",
+    name: "default-data-missing-value",
+    level: VerificationIssueLevel::Critical,
+    summary: "A default data row has no value for a NOT NULL column without a default.",
+    explanation: "Provide a value in every row, give the column a default or allow NULL.",
+};
+
+pub const DEFAULT_DATA_DUPLICATE_KEY: Rule = Rule {
+    id: "ADB0046",
+    name: "default-data-duplicate-key",
+    level: VerificationIssueLevel::Critical,
+    summary: "Default data repeats a primary key or unique value.",
+    explanation: "The second insert would violate the primary key or unique constraint.",
+};
+
+pub const DEFAULT_DATA_UNKNOWN_REFERENCE: Rule = Rule {
+    id: "ADB0047",
+    name: "default-data-unknown-reference",
+    level: VerificationIssueLevel::High,
+    summary: "A default data value for a foreign key column is missing from the referenced table's default data.",
+    explanation: "Unless the referenced row is inserted by other means, the insert violates the foreign key.",
+};
+
+// Identifiers
+
+pub const RESERVED_IDENTIFIER: Rule = Rule {
+    id: "ADB0050",
+    name: "reserved-identifier",
+    level: VerificationIssueLevel::Low,
+    summary: "A table, column, index or constraint name is a reserved word of the engine.",
+    explanation: "AlphaDB quotes every name it writes, so its own queries work. Any query written by hand, and many tools, must quote the name as well. \
+                  Pick another name to avoid this.",
+};
+
+pub const INVALID_IDENTIFIER: Rule = Rule {
+    id: "ADB0051",
+    name: "invalid-identifier",
+    level: VerificationIssueLevel::High,
+    summary: "A name contains characters other than letters, digits and underscores, or starts with a digit.",
+    explanation: "Such names only work quoted. Some characters, like trailing spaces on MySQL, are rejected even when quoted.",
+};
+
+pub const IDENTIFIER_TOO_LONG: Rule = Rule {
+    id: "ADB0052",
+    name: "identifier-too-long",
+    level: VerificationIssueLevel::High,
+    summary: "A name is longer than the engine allows (63 characters on PostgreSQL, 64 on MySQL and MariaDB).",
+    explanation: "MySQL and MariaDB reject the query. PostgreSQL silently truncates the name, which can make two names collide.",
+};
+
+// Safety lints
+
+pub const DROPPED_COLUMN: Rule = Rule {
+    id: "ADB0101",
+    name: "dropped-column",
+    level: VerificationIssueLevel::High,
+    summary: "A column is dropped, deleting all of its data.",
+    explanation: "Acknowledge the change with `\"_acknowledged\": {\"ADB0101\": [\"table.column\"]}` in the version if the data is no longer needed.",
+};
+
+pub const NARROWED_LENGTH: Rule = Rule {
+    id: "ADB0102",
+    name: "narrowed-length",
+    level: VerificationIssueLevel::High,
+    summary: "The length of a column is reduced, which may truncate or reject existing values.",
+    explanation: "Check that no existing value is longer than the new length, then acknowledge the change in the version's `_acknowledged`.",
+};
+
+pub const NARROWED_TYPE: Rule = Rule {
+    id: "ADB0103",
+    name: "narrowed-type",
+    level: VerificationIssueLevel::High,
+    summary: "A column type is changed to a smaller type of the same kind (e.g. BIGINT to INTEGER).",
+    explanation: "Values outside the range of the new type are truncated or rejected. Check the existing data, then acknowledge the change.",
+};
+
+pub const LOSSY_TYPE_CHANGE: Rule = Rule {
+    id: "ADB0104",
+    name: "lossy-type-change",
+    level: VerificationIssueLevel::High,
+    summary: "A column type is changed to a different kind of type (e.g. TEXT to INTEGER).",
+    explanation: "Values that can not be converted are lost or make the change fail. Check the existing data, then acknowledge the change.",
+};
+
+pub const NOT_NULL_WITHOUT_DEFAULT: Rule = Rule {
+    id: "ADB0105",
+    name: "not-null-without-default",
+    level: VerificationIssueLevel::High,
+    summary: "A nullable column is made NOT NULL without a default, which fails when rows hold NULL.",
+    explanation: "Give the column a default, or make sure no row holds NULL before the version is applied, then acknowledge the change.",
+};
+
+pub const UNUSED_ACKNOWLEDGEMENT: Rule = Rule {
+    id: "ADB0106",
+    name: "unused-acknowledgement",
+    level: VerificationIssueLevel::Low,
+    summary: "An `_acknowledged` entry does not match any change in its version.",
+    explanation: "The acknowledged change is not made in this version, so the entry has no effect and can be removed.",
+};
+
+// MariaDB
+
+pub const INVALID_SEQUENCE: Rule = Rule {
+    id: "ADB0401",
+    name: "invalid-sequence",
+    level: VerificationIssueLevel::Critical,
+    summary: "A `createsequence` or `altersequence` definition is invalid.",
+    explanation: "Sequence methods take an array of sequences, each given as a name or as an object with a `name` and options like `start` and `increment`.",
+};
+
+pub const INVALID_SYSTEM_VERSIONING: Rule = Rule {
+    id: "ADB0402",
+    name: "invalid-system-versioning",
+    level: VerificationIssueLevel::Critical,
+    summary: "The `system_versioning` value of a table is not a boolean.",
+    explanation: "Use `\"system_versioning\": true` to enable system versioning on a table, or `false` to disable it.",
+};
+
+/// All rules, including the ones that only apply to a specific engine
+pub const RULES: [Rule; 49] = [
+    INVALID_STRUCTURE,
+    MISSING_NAME,
+    NO_VERSIONS,
+    MISSING_VERSION_NUMBER,
+    UNKNOWN_METHOD,
+    EMPTY_BLOCK,
+    INVALID_SUPPRESSION,
+    UNKNOWN_RULE,
+    INVALID_DEFINITION_TYPE,
+    MISSING_COLUMN_TYPE,
+    INCOMPATIBLE_ATTRIBUTES,
+    INCOMPATIBLE_TYPE_ATTRIBUTE,
+    INVALID_BOOLEAN_DEFAULT,
+    DROPPED_PRIMARY_KEY,
+    UNKNOWN_PRIMARY_KEY_COLUMN,
+    UNSUPPORTED_COLUMN_TYPE,
+    INVALID_INDEX,
+    UNKNOWN_INDEX_COLUMN,
+    DUPLICATE_INDEX_NAME,
+    UNSUPPORTED_INDEX_TYPE,
+    INVALID_INDEX_CONDITION,
+    UNKNOWN_INDEX,
+    INVALID_FOREIGN_KEY,
+    UNKNOWN_FOREIGN_KEY_COLUMN,
+    UNKNOWN_REFERENCE,
+    NON_UNIQUE_REFERENCE,
+    INCOMPATIBLE_FOREIGN_KEY_TYPES,
+    FOREIGN_KEY_TYPE_MISMATCH,
+    SET_NULL_ON_NOT_NULL,
+    REFERENCED_COLUMN_REMOVED,
+    DEFAULT_DATA_UNKNOWN_TABLE,
+    INVALID_DEFAULT_DATA,
+    DEFAULT_DATA_UNKNOWN_COLUMN,
+    DEFAULT_DATA_TYPE_MISMATCH,
+    DEFAULT_DATA_TOO_LONG,
+    DEFAULT_DATA_MISSING_VALUE,
+    DEFAULT_DATA_DUPLICATE_KEY,
+    DEFAULT_DATA_UNKNOWN_REFERENCE,
+    RESERVED_IDENTIFIER,
+    INVALID_IDENTIFIER,
+    IDENTIFIER_TOO_LONG,
+    DROPPED_COLUMN,
+    NARROWED_LENGTH,
+    NARROWED_TYPE,
+    LOSSY_TYPE_CHANGE,
+    NOT_NULL_WITHOUT_DEFAULT,
+    UNUSED_ACKNOWLEDGEMENT,
+    INVALID_SEQUENCE,
+    INVALID_SYSTEM_VERSIONING,
+];
+
+/// Find a rule by id or name
+pub fn find_rule(key: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.matches(key))
+}
+
+/// Find a rule by id or name among the built-in rules and `custom_rules`
+pub fn find_rule_in<'a>(key: &str, custom_rules: &'a [Rule]) -> Option<&'a Rule> {
+    RULES.iter().chain(custom_rules).find(|rule| rule.matches(key))
+}
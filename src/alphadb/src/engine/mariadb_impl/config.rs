@@ -0,0 +1,74 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::methods;
+use crate::{
+    core::{
+        connect_options::ConnectOptions,
+        method_types::{HistoryEntry, Init, Status},
+        runtime_config::{RuntimeConfig, RuntimeHooks},
+        utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
+    },
+    engine::mysql_impl::methods as mysql_methods,
+};
+use mysql::PooledConn;
+
+fn mariadb_connect(options: &ConnectOptions) -> Result<(PooledConn, String), AlphaDBError> {
+    methods::connect(options).map_err(|e| e.into())
+}
+
+fn mariadb_init(db_name: &str, connection: &mut PooledConn) -> Result<Init, AlphaDBError> {
+    mysql_methods::init(db_name, connection).map_err(|e| e.into())
+}
+
+fn mariadb_status(db_name: &str, connection: &mut PooledConn) -> Result<Status, AlphaDBError> {
+    mysql_methods::status(db_name, connection).map_err(|e| e.into())
+}
+
+fn mariadb_history(db_name: &str, connection: &mut PooledConn) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+    mysql_methods::history(db_name, connection).map_err(|e| e.into())
+}
+
+fn mariadb_update(
+    db_name: &str,
+    connection: &mut PooledConn,
+    version_source: String,
+    target_version: Option<&str>,
+    no_data: bool,
+    tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+) -> Result<(), AlphaDBError> {
+    methods::update(db_name, connection, version_source, target_version, no_data, tolerated_verification_issue_level).map_err(|e| e.into())
+}
+
+fn mariadb_vacate(connection: &mut PooledConn) -> Result<(), AlphaDBError> {
+    mysql_methods::vacate(connection).map_err(|e| e.into())
+}
+
+/// MariaDB runtime configuration. Uses the MySQL driver; the server version
+/// is detected on connect and again before generating queries.
+pub fn mariadb_runtime_config() -> RuntimeConfig<PooledConn> {
+    RuntimeConfig {
+        name: "mariadb",
+        hooks: RuntimeHooks {
+            connect: mariadb_connect,
+            init: mariadb_init,
+            status: mariadb_status,
+            history: mariadb_history,
+            update_queries: methods::update_queries,
+            update: mariadb_update,
+            vacate: mariadb_vacate,
+        },
+    }
+}
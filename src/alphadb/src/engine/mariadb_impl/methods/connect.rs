@@ -0,0 +1,48 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::connect_options::ConnectOptions;
+use crate::engine::mariadb_impl::utils::server_version::ServerVersion;
+use crate::engine::mysql_impl::methods as mysql_methods;
+use crate::engine::mysql_impl::utils::errors::AlphaDBMysqlError;
+use mysql::PooledConn;
+use std::time::Duration;
+
+/// MariaDB has no `max_execution_time`, `max_statement_time` is in seconds
+/// and also limits data changing statements.
+fn statement_timeout_query(timeout: Duration) -> String {
+    format!("SET SESSION max_statement_time = {}", timeout.as_secs_f64())
+}
+
+/// Connect like MySQL, then make sure the server is MariaDB so its
+/// dialect can be used.
+pub fn connect(options: &ConnectOptions) -> Result<(PooledConn, String), AlphaDBMysqlError> {
+    let (mut conn, db_name) = mysql_methods::connect_with(options, statement_timeout_query)?;
+    ServerVersion::detect(&mut conn)?;
+
+    Ok((conn, db_name))
+}
+
+#[cfg(test)]
+mod connect_tests {
+    use super::statement_timeout_query;
+    use std::time::Duration;
+
+    #[test]
+    fn statement_timeout() {
+        assert_eq!(statement_timeout_query(Duration::from_secs(30)), "SET SESSION max_statement_time = 30");
+        assert_eq!(statement_timeout_query(Duration::from_millis(1500)), "SET SESSION max_statement_time = 1.5");
+    }
+}
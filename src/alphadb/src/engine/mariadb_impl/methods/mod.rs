@@ -0,0 +1,7 @@
+mod connect;
+mod update;
+mod update_queries;
+
+pub use connect::connect;
+pub use update::update;
+pub use update_queries::{update_queries, MARIADB_LEGACY_UPDATE_QUERIES_CONFIG, MARIADB_UPDATE_QUERIES_CONFIG};
@@ -0,0 +1,33 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::utils::types::ToleratedVerificationIssueLevel;
+use crate::engine::mariadb_impl::methods::update_queries;
+use crate::engine::mysql_impl::methods::execute_queries;
+use crate::engine::mysql_impl::utils::errors::AlphaDBMysqlError;
+use mysql::PooledConn;
+
+/// Generate and execute MariaDB queries to update the tables
+pub fn update(
+    db_name: &str,
+    connection: &mut PooledConn,
+    version_source: String,
+    target_version: Option<&str>,
+    no_data: bool,
+    _tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+) -> Result<(), AlphaDBMysqlError> {
+    let queries = update_queries(db_name, connection, version_source, target_version, no_data)?;
+    execute_queries(connection, queries)
+}
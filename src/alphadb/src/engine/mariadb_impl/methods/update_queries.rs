@@ -0,0 +1,260 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::{Query, QueryValue, Status};
+use crate::core::update_queries::{update_queries as build_update_queries, UpdateQueriesConfig};
+use crate::core::utils::errors::{AlphaDBError, Get};
+use crate::core::utils::globals::{CONFIG_TABLE_NAME, HISTORY_TABLE_NAME};
+use crate::core::utils::json::{array_iter, get_json_string, get_object_keys, object_iter};
+use crate::core::utils::version_number::parse_version_number;
+use crate::core::utils::version_source::{get_version_array, parse_version_source_string};
+use crate::core::verification::issue::VersionTrace;
+use crate::engine::mariadb_impl::query::sequence::{alter_sequence, create_sequence, drop_sequence, Sequence};
+use crate::engine::mariadb_impl::query::table::config::{MARIADB_LEGACY_TABLE_CONFIG, MARIADB_TABLE_CONFIG};
+use crate::engine::mariadb_impl::utils::server_version::{self, ServerVersion};
+use crate::engine::mysql_impl::methods::status;
+use crate::engine::mysql_impl::query::default_data::default_data;
+use crate::engine::mysql_impl::query::{createindex, dropindex};
+use crate::engine::mysql_impl::utils::history::create_history_table_query;
+use crate::engine::AlphaDBEngine;
+use mysql::*;
+use serde_json::Value;
+
+/// Update queries for MariaDB 10.5.2 and newer
+pub const MARIADB_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<PooledConn> = UpdateQueriesConfig {
+    engine: Some(AlphaDBEngine::MariaDB),
+    status: status_hook,
+    table_config: &MARIADB_TABLE_CONFIG,
+    create_index: createindex,
+    drop_index: dropindex,
+    default_data,
+    config_update_query,
+    history_query,
+    history_table_query: Some(history_table_query),
+    version_extras: Some(version_extras),
+    create_table: None,
+    alter_table: None,
+};
+
+/// Update queries for MariaDB servers without `RENAME COLUMN`
+pub const MARIADB_LEGACY_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<PooledConn> = UpdateQueriesConfig {
+    table_config: &MARIADB_LEGACY_TABLE_CONFIG,
+    ..MARIADB_UPDATE_QUERIES_CONFIG
+};
+
+/// Generate the update queries in the dialect of the connected server.
+/// Fails before generating anything when a pending version uses a
+/// feature the server does not have.
+pub fn update_queries(db_name: &str, connection: &mut PooledConn, version_source: String, target_version: Option<&str>, no_data: bool) -> Result<Vec<Query>, AlphaDBError> {
+    let server = ServerVersion::detect(connection)?;
+    let database_version = status_hook(db_name, connection)?.version;
+    check_server_support(&parse_version_source_string(version_source.clone())?, server, database_version.as_deref(), target_version)?;
+
+    let config = if server >= server_version::RENAME_COLUMN {
+        &MARIADB_UPDATE_QUERIES_CONFIG
+    } else {
+        &MARIADB_LEGACY_UPDATE_QUERIES_CONFIG
+    };
+
+    build_update_queries(config, db_name, connection, version_source, target_version, no_data)
+}
+
+fn status_hook(db_name: &str, connection: &mut PooledConn) -> Result<Status, AlphaDBError> {
+    status(db_name, connection).map_err(|e| e.into())
+}
+
+fn version_extras(version: &Value) -> Result<Vec<Query>, AlphaDBError> {
+    let mut queries = Vec::new();
+    let version_keys = get_object_keys(version)?;
+
+    for (key, build) in [
+        ("createsequence", create_sequence as fn(&Sequence) -> String),
+        ("altersequence", alter_sequence),
+        ("dropsequence", drop_sequence),
+    ] {
+        if version_keys.contains(&&key.to_string()) {
+            for sequence in array_iter(&version[key])? {
+                queries.push(Query {
+                    query: build(&Sequence::try_from(sequence)?),
+                    data: None,
+                });
+            }
+        }
+    }
+
+    Ok(queries)
+}
+
+/// Column types that only newer servers know
+const COLUMN_TYPE_VERSIONS: [(&str, ServerVersion); 2] = [("UUID", server_version::UUID), ("INET6", server_version::INET6)];
+
+fn check_column_types(columns: &Value, server: ServerVersion) -> Result<(), AlphaDBError> {
+    if let Some(columns) = columns.as_object() {
+        for column in columns.values() {
+            if let Some(column_type) = column["type"].as_str() {
+                for (required_type, required) in COLUMN_TYPE_VERSIONS {
+                    if column_type.eq_ignore_ascii_case(required_type) {
+                        server.require(required, &format!("The {required_type} column type"))?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the versions after `database_version`, up to and including
+/// `target_version`, for features the server is too old for. Versions
+/// that are already applied are not checked.
+fn check_server_support(version_source: &Value, server: ServerVersion, database_version: Option<&str>, target_version: Option<&str>) -> Result<(), AlphaDBError> {
+    // Invalid bounds are reported by the update driver
+    let database_version_int = database_version.and_then(|v| parse_version_number(v).ok());
+    let target_version_int = target_version.and_then(|v| parse_version_number(v).ok());
+
+    for version in get_version_array(version_source)? {
+        let version_number = get_json_string(&version["_id"])?;
+        let version_int = parse_version_number(version_number)?;
+
+        if database_version_int.is_some_and(|v| version_int <= v) || target_version_int.is_some_and(|v| version_int > v) {
+            continue;
+        }
+        let version_keys = get_object_keys(version)?;
+
+        let with_trace = |key: &str| {
+            let version_trace = VersionTrace::from([version_number, key]);
+            move |mut e: AlphaDBError| {
+                e.set_version_trace(&version_trace);
+                e
+            }
+        };
+
+        for key in ["createsequence", "altersequence", "dropsequence"] {
+            if version_keys.contains(&&key.to_string()) {
+                server.require(server_version::SEQUENCE, "SEQUENCE").map_err(with_trace(key))?;
+            }
+        }
+
+        for method in ["createtable", "altertable"] {
+            if !version_keys.contains(&&method.to_string()) {
+                continue;
+            }
+
+            for table in object_iter(&version[method])? {
+                let table_data = &version[method][table];
+
+                if !table_data["system_versioning"].is_null() {
+                    server.require(server_version::SYSTEM_VERSIONING, "System versioning").map_err(with_trace(method))?;
+                }
+
+                if method == "createtable" {
+                    check_column_types(table_data, server).map_err(with_trace(method))?;
+                } else {
+                    check_column_types(&table_data["addcolumn"], server).map_err(with_trace(method))?;
+                    check_column_types(&table_data["modifycolumn"], server).map_err(with_trace(method))?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn config_update_query(latest_version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("UPDATE `{CONFIG_TABLE_NAME}` SET `version`=?, `template`=? WHERE `db` = ?;"),
+        data: Some(Vec::from([
+            QueryValue::String(latest_version.to_string()),
+            QueryValue::String(template_name.to_string()),
+            QueryValue::String(db_name.to_string()),
+        ])),
+    }
+}
+
+fn history_table_query() -> Query {
+    Query {
+        query: create_history_table_query(),
+        data: None,
+    }
+}
+
+fn history_query(version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("INSERT INTO `{HISTORY_TABLE_NAME}` (`db`, `version`, `template`) VALUES (?, ?, ?);"),
+        data: Some(Vec::from([
+            QueryValue::String(db_name.to_string()),
+            QueryValue::String(version.to_string()),
+            QueryValue::String(template_name.to_string()),
+        ])),
+    }
+}
+
+#[cfg(test)]
+mod update_queries_tests {
+    use super::{check_server_support, version_extras};
+    use crate::engine::mariadb_impl::utils::server_version::ServerVersion;
+    use serde_json::json;
+
+    #[test]
+    fn sequences() {
+        let version = json!({
+            "_id": "0.0.2",
+            "createsequence": [{ "name": "invoice_number", "start": 1000 }],
+            "dropsequence": ["order_number"],
+        });
+
+        let queries: Vec<String> = version_extras(&version).unwrap().into_iter().map(|q| q.query).collect();
+        assert_eq!(
+            queries,
+            ["CREATE SEQUENCE IF NOT EXISTS `invoice_number` START WITH 1000;", "DROP SEQUENCE IF EXISTS `order_number`;"]
+        );
+    }
+
+    #[test]
+    fn server_support() {
+        let version_source = json!({
+            "name": "test",
+            "version": [
+                { "_id": "0.0.1", "createtable": { "host": { "address": { "type": "INET6" } } } },
+                { "_id": "0.0.2", "altertable": { "host": { "addcolumn": { "uid": { "type": "UUID" } } } } }
+            ]
+        });
+
+        assert!(check_server_support(&version_source, ServerVersion::new(11, 4, 2), None, None).is_ok());
+
+        let err = check_server_support(&version_source, ServerVersion::new(10, 6, 18), None, None).unwrap_err();
+        assert_eq!(err.message, "The UUID column type requires MariaDB 10.7.0 or newer, the server runs 10.6.18");
+        assert_eq!(Vec::<String>::from(err.version_trace), ["0.0.2", "altertable"]);
+    }
+
+    #[test]
+    fn server_support_pending_versions() {
+        let version_source = json!({
+            "name": "test",
+            "version": [
+                { "_id": "0.0.1", "createtable": { "host": { "uid": { "type": "UUID" } } } },
+                { "_id": "0.0.2", "createtable": { "user": { "name": { "type": "VARCHAR", "length": 50 } } } },
+                { "_id": "0.0.3", "altertable": { "host": { "addcolumn": { "address": { "type": "INET6" } } } } }
+            ]
+        });
+        let server = ServerVersion::new(10, 4, 0);
+
+        // 0.0.1 is already applied and 0.0.3 is past the target
+        assert!(check_server_support(&version_source, server, Some("0.0.1"), Some("0.0.2")).is_ok());
+
+        let err = check_server_support(&version_source, server, Some("0.0.1"), None).unwrap_err();
+        assert_eq!(Vec::<String>::from(err.version_trace), ["0.0.3", "altertable"]);
+    }
+}
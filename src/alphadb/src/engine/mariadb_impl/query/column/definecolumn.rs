@@ -0,0 +1,48 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::query::column::definecolumn::DefineColumn;
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::mariadb_impl::verification::compatibility::{COLUMN_TYPE_COMPATIBILITY_RULES, SUPPORTED_COLUMN_TYPES};
+use crate::engine::mysql_impl::query::column::definecolumn::definecolumn_with;
+use serde_json::Value;
+
+/// **Define column**
+///
+/// Generate a MariaDB query part that defines a single column. Same as MySQL,
+/// with the `UUID` and `INET6` types added.
+pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String, version: &str) -> Result<Option<DefineColumn>, AlphaDBError> {
+    definecolumn_with(column_data, table_name, column_name, version, &SUPPORTED_COLUMN_TYPES, &COLUMN_TYPE_COMPATIBILITY_RULES)
+}
+
+#[cfg(test)]
+mod definecolumn_tests {
+    use super::definecolumn;
+    use serde_json::json;
+
+    #[test]
+    fn uuid() {
+        let column = &json!({ "type": "UUID", "default": "UUID()" });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1").unwrap().unwrap();
+        assert_eq!(q.to_sql(), "`col` UUID NOT NULL DEFAULT UUID()");
+    }
+
+    #[test]
+    fn inet6_length() {
+        let column = &json!({ "type": "INET6", "length": 39 });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert_eq!(q.unwrap_err().message, "Column attributes 'LENGTH' and 'type==INET6' are not compatible.");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod column;
+pub mod sequence;
+pub mod table;
@@ -0,0 +1,140 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::mysql_impl::query::quote;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Sequence definition for `CREATE SEQUENCE` and `ALTER SEQUENCE` queries.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Sequence {
+    pub name: String,
+    pub start: Option<i64>,
+    pub restart: Option<i64>,
+    pub increment: Option<i64>,
+    pub minvalue: Option<i64>,
+    pub maxvalue: Option<i64>,
+    pub cache: Option<i64>,
+    pub cycle: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SequenceInput {
+    Name(String),
+    Options(Sequence),
+}
+
+impl TryFrom<&Value> for Sequence {
+    type Error = AlphaDBError;
+
+    /// Parses a sequence from a string name or an object with a `name` field.
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match serde_json::from_value(value.clone()) {
+            Ok(SequenceInput::Name(name)) => Ok(Sequence { name, ..Default::default() }),
+            Ok(SequenceInput::Options(sequence)) => Ok(sequence),
+            Err(_) => Err(AlphaDBError {
+                message: format!("Invalid sequence {value}, expected a name or an object with name, start, restart, increment, minvalue, maxvalue, cache and cycle"),
+                error: "invalid-sequence".to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+/// Shared `INCREMENT BY` ... `CYCLE` options of create and alter
+fn sequence_options(sequence: &Sequence) -> String {
+    let mut options = String::new();
+
+    for (keyword, value) in [("INCREMENT BY", sequence.increment), ("MINVALUE", sequence.minvalue), ("MAXVALUE", sequence.maxvalue), ("CACHE", sequence.cache)] {
+        if let Some(value) = value {
+            options.push_str(&format!(" {keyword} {value}"));
+        }
+    }
+
+    match sequence.cycle {
+        Some(true) => options.push_str(" CYCLE"),
+        Some(false) => options.push_str(" NOCYCLE"),
+        None => (),
+    }
+
+    options
+}
+
+/// Builds a `CREATE SEQUENCE IF NOT EXISTS` query for the given sequence.
+pub fn create_sequence(sequence: &Sequence) -> String {
+    let start = match sequence.start {
+        Some(start) => format!(" START WITH {start}"),
+        None => String::new(),
+    };
+
+    format!("CREATE SEQUENCE IF NOT EXISTS {}{start}{};", quote(&sequence.name), sequence_options(sequence))
+}
+
+/// Builds an `ALTER SEQUENCE IF EXISTS` query for the given sequence.
+pub fn alter_sequence(sequence: &Sequence) -> String {
+    let restart = match sequence.restart {
+        Some(restart) => format!(" RESTART WITH {restart}"),
+        None => String::new(),
+    };
+
+    format!("ALTER SEQUENCE IF EXISTS {}{restart}{};", quote(&sequence.name), sequence_options(sequence))
+}
+
+/// Builds a `DROP SEQUENCE IF EXISTS` query for the given sequence.
+pub fn drop_sequence(sequence: &Sequence) -> String {
+    format!("DROP SEQUENCE IF EXISTS {};", quote(&sequence.name))
+}
+
+#[cfg(test)]
+mod sequence_tests {
+    use super::{alter_sequence, create_sequence, drop_sequence, Sequence};
+    use serde_json::json;
+
+    #[test]
+    fn create_sequence_query() {
+        let sequence = Sequence::try_from(&json!("invoice_number")).unwrap();
+        assert_eq!(create_sequence(&sequence), "CREATE SEQUENCE IF NOT EXISTS `invoice_number`;");
+    }
+
+    #[test]
+    fn create_sequence_options_query() {
+        let sequence = Sequence::try_from(&json!({ "name": "invoice_number", "start": 1000, "increment": 10, "cache": 0, "cycle": false })).unwrap();
+        assert_eq!(
+            create_sequence(&sequence),
+            "CREATE SEQUENCE IF NOT EXISTS `invoice_number` START WITH 1000 INCREMENT BY 10 CACHE 0 NOCYCLE;"
+        );
+    }
+
+    #[test]
+    fn alter_sequence_query() {
+        let sequence = Sequence::try_from(&json!({ "name": "invoice_number", "restart": 1, "maxvalue": 9999, "cycle": true })).unwrap();
+        assert_eq!(alter_sequence(&sequence), "ALTER SEQUENCE IF EXISTS `invoice_number` RESTART WITH 1 MAXVALUE 9999 CYCLE;");
+    }
+
+    #[test]
+    fn drop_sequence_query() {
+        let sequence = Sequence::try_from(&json!("invoice_number")).unwrap();
+        assert_eq!(drop_sequence(&sequence), "DROP SEQUENCE IF EXISTS `invoice_number`;");
+    }
+
+    #[test]
+    fn unknown_option() {
+        let err = Sequence::try_from(&json!({ "name": "invoice_number", "step": 2 })).unwrap_err();
+        assert_eq!(err.error, "invalid-sequence");
+    }
+}
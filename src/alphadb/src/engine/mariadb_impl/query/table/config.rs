@@ -0,0 +1,187 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::query::column::DefineColumn;
+use crate::core::query::table::TableQueryConfig;
+use crate::core::utils::consolidate::column::consolidate_column;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::json::{exists_in_object, get_json_boolean};
+use crate::engine::mariadb_impl::query::column::definecolumn::definecolumn;
+use crate::engine::mysql_impl::query::table::config::{add_primary_key, drop_foreign_key, drop_primary_key, prepare_primary_key_change};
+use crate::engine::mysql_impl::query::{create_check_constraint, create_foreign_key_constraint, quote, IDENTIFIER_QUOTE};
+use serde_json::Value;
+
+/// MariaDB table query configuration, for servers with `RENAME COLUMN` (10.5.2 and newer).
+pub const MARIADB_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
+    name: "mariadb",
+    identifier_quote: IDENTIFIER_QUOTE,
+    define_column: definecolumn,
+    foreign_key_constraint: create_foreign_key_constraint,
+    check_constraint: create_check_constraint,
+    table_options: Some("ENGINE = InnoDB"),
+    modify_column,
+    drop_primary_key,
+    add_primary_key,
+    drop_foreign_key,
+    preprocess: Some(prepare_primary_key_change),
+    rename_column: None,
+    column_if_exists: true,
+    create_options: Some(create_options),
+    alter_options: Some(alter_options),
+};
+
+/// MariaDB table query configuration for servers older than 10.5.2, which
+/// rename columns with `CHANGE COLUMN`.
+pub const MARIADB_LEGACY_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
+    modify_column: legacy_modify_column,
+    rename_column: Some(change_column),
+    ..MARIADB_TABLE_CONFIG
+};
+
+fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name: &str, column: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
+    if exists_in_object(modify_entry, "recreate")? && modify_entry["recreate"] == false {
+        *modify_entry = consolidate_column(version_list, column, table_name, None)?;
+    }
+
+    let mut definitions = Vec::new();
+    if let Some(mut definition) = definecolumn(modify_entry, table_name, &column.to_string(), version)? {
+        definition.method("MODIFY COLUMN IF EXISTS");
+        definitions.push(definition);
+    }
+
+    Ok(definitions)
+}
+
+/// A column that is also renamed in this version is modified by its `CHANGE COLUMN`,
+/// MariaDB rejects a second alter specification for the same column
+fn legacy_modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name: &str, column: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
+    let renamed = version_list
+        .iter()
+        .any(|v| v["_id"] == version && v["altertable"][table_name]["renamecolumn"].get(column).is_some());
+    if renamed {
+        return Ok(Vec::new());
+    }
+
+    modify_column(version_list, modify_entry, table_name, column, version)
+}
+
+/// `CHANGE COLUMN` has to repeat the complete column definition
+fn change_column(version_list: &Vec<Value>, table_name: &str, old_name: &str, new_name: &str, version: &str) -> Result<DefineColumn, AlphaDBError> {
+    let column = consolidate_column(version_list, new_name, table_name, Some(version))?;
+
+    match definecolumn(&column, table_name, &new_name.to_string(), version)? {
+        Some(mut definition) => {
+            definition.method(format!("CHANGE COLUMN IF EXISTS {}", quote(old_name)));
+            Ok(definition)
+        }
+        None => Err(AlphaDBError {
+            message: format!("Unable to find the definition of column '{old_name}' to rename it"),
+            ..Default::default()
+        }),
+    }
+}
+
+fn create_options(table_data: &Value) -> Result<Option<String>, AlphaDBError> {
+    if exists_in_object(table_data, "system_versioning")? && get_json_boolean(&table_data["system_versioning"])? {
+        return Ok(Some("WITH SYSTEM VERSIONING".to_string()));
+    }
+
+    Ok(None)
+}
+
+fn alter_options(table_data: &Value, _table_name: &str, _version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
+    let mut definitions = Vec::new();
+
+    if exists_in_object(table_data, "system_versioning")? {
+        let mut definition = DefineColumn::new();
+        definition
+            .method(if get_json_boolean(&table_data["system_versioning"])? { "ADD" } else { "DROP" })
+            .name("SYSTEM VERSIONING");
+        definitions.push(definition);
+    }
+
+    Ok(definitions)
+}
+
+#[cfg(test)]
+mod table_tests {
+    use super::{MARIADB_LEGACY_TABLE_CONFIG, MARIADB_TABLE_CONFIG};
+    use crate::core::query::table::{alter_table, create_table};
+    use serde_json::json;
+
+    #[test]
+    fn system_versioned_table() {
+        let json = &json!({
+            "createtable": {
+                "ledger": {
+                    "primary_key": "id",
+                    "system_versioning": true,
+                    "id": {"type": "UUID", "default": "UUID()"},
+                    "address": {"type": "INET6", "null": true},
+                }
+            }
+        });
+
+        assert_eq!(
+            create_table(&MARIADB_TABLE_CONFIG, json, "ledger", "0.0.1").unwrap(),
+            "CREATE TABLE `ledger` (`id` UUID NOT NULL DEFAULT UUID(), `address` INET6 NULL, PRIMARY KEY (`id`)) ENGINE = InnoDB WITH SYSTEM VERSIONING;"
+        );
+    }
+
+    fn version_source() -> serde_json::Value {
+        json!({
+            "name": "test",
+            "version": [
+                {
+                    "_id": "0.0.1",
+                    "createtable": {
+                        "ledger": {
+                            "amount": {"type": "INT", "default": 0},
+                            "note": {"type": "TEXT"},
+                        }
+                    }
+                },
+                {
+                    "_id": "0.0.2",
+                    "altertable": {
+                        "ledger": {
+                            "system_versioning": false,
+                            "dropcolumn": ["note"],
+                            "addcolumn": {"remark": {"type": "VARCHAR", "length": 100}},
+                            "modifycolumn": {"amount": {"recreate": false, "null": true}},
+                            "renamecolumn": {"amount": "total"},
+                        }
+                    }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn alter_table_if_exists() {
+        assert_eq!(
+            alter_table(&MARIADB_TABLE_CONFIG, &version_source(), "ledger", "0.0.2").unwrap(),
+            "ALTER TABLE `ledger` DROP COLUMN IF EXISTS `note`, ADD COLUMN IF NOT EXISTS `remark` VARCHAR(100) NOT NULL, MODIFY COLUMN IF EXISTS `amount` INT NULL DEFAULT '0', RENAME COLUMN IF EXISTS `amount` TO `total`, DROP SYSTEM VERSIONING;"
+        );
+    }
+
+    #[test]
+    fn alter_table_change_column() {
+        assert_eq!(
+            alter_table(&MARIADB_LEGACY_TABLE_CONFIG, &version_source(), "ledger", "0.0.2").unwrap(),
+            "ALTER TABLE `ledger` DROP COLUMN IF EXISTS `note`, ADD COLUMN IF NOT EXISTS `remark` VARCHAR(100) NOT NULL, CHANGE COLUMN IF EXISTS `amount` `total` INT NULL DEFAULT '0', DROP SYSTEM VERSIONING;"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::utils::errors::AlphaDBError;
+use mysql::{prelude::*, PooledConn};
+use std::fmt;
+
+/// Version of a MariaDB server, as reported by `SELECT VERSION()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ServerVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+/// First release with `ALTER TABLE ... RENAME COLUMN`. Older servers use `CHANGE`.
+pub const RENAME_COLUMN: ServerVersion = ServerVersion::new(10, 5, 2);
+
+/// First release with system-versioned tables
+pub const SYSTEM_VERSIONING: ServerVersion = ServerVersion::new(10, 3, 4);
+
+/// First release with `SEQUENCE` objects
+pub const SEQUENCE: ServerVersion = ServerVersion::new(10, 3, 0);
+
+/// First release with the `INET6` column type
+pub const INET6: ServerVersion = ServerVersion::new(10, 5, 0);
+
+/// First release with the `UUID` column type
+pub const UUID: ServerVersion = ServerVersion::new(10, 7, 0);
+
+impl ServerVersion {
+    pub const fn new(major: u16, minor: u16, patch: u16) -> ServerVersion {
+        ServerVersion { major, minor, patch }
+    }
+
+    /// Parse a `VERSION()` string like `11.4.2-MariaDB-ubu2404`.
+    /// Returns `None` when the server is not MariaDB.
+    pub fn parse(version: &str) -> Option<ServerVersion> {
+        if !version.contains("MariaDB") {
+            return None;
+        }
+
+        // Replication may prefix the version with `5.5.5-`
+        let version = version.strip_prefix("5.5.5-").unwrap_or(version);
+        let mut parts = version.split(|c: char| !c.is_ascii_digit()).map(|p| p.parse::<u16>());
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Some(ServerVersion::new(major, minor, patch)),
+            _ => None,
+        }
+    }
+
+    /// Query the version of the server behind the connection
+    pub fn detect(connection: &mut PooledConn) -> Result<ServerVersion, AlphaDBError> {
+        let version: Option<String> = connection.query_first("SELECT VERSION()").map_err(|e| AlphaDBError {
+            message: e.to_string(),
+            ..Default::default()
+        })?;
+        let version = version.unwrap_or_default();
+
+        ServerVersion::parse(&version).ok_or_else(|| AlphaDBError {
+            message: format!("The server does not seem to be MariaDB (version '{version}'), use the mysql engine instead"),
+            error: "unsupported-server".to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Error unless the server is at least `required`
+    pub fn require(&self, required: ServerVersion, feature: &str) -> Result<(), AlphaDBError> {
+        if *self < required {
+            return Err(AlphaDBError {
+                message: format!("{feature} requires MariaDB {required} or newer, the server runs {self}"),
+                error: "unsupported-feature".to_string(),
+                ..Default::default()
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod server_version_tests {
+    use super::{ServerVersion, RENAME_COLUMN};
+
+    #[test]
+    fn parse() {
+        assert_eq!(ServerVersion::parse("11.4.2-MariaDB-ubu2404"), Some(ServerVersion::new(11, 4, 2)));
+        assert_eq!(ServerVersion::parse("5.5.5-10.4.34-MariaDB-log"), Some(ServerVersion::new(10, 4, 34)));
+        assert_eq!(ServerVersion::parse("8.0.36"), None);
+    }
+
+    #[test]
+    fn require() {
+        assert!(ServerVersion::new(10, 5, 2).require(RENAME_COLUMN, "RENAME COLUMN").is_ok());
+
+        let err = ServerVersion::new(10, 4, 34).require(RENAME_COLUMN, "RENAME COLUMN").unwrap_err();
+        assert_eq!(err.error, "unsupported-feature");
+        assert_eq!(err.message, "RENAME COLUMN requires MariaDB 10.5.2 or newer, the server runs 10.4.34");
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::verification::compatibility::ColumnCompatibilityRule;
+
+/// All columns supported by AlphaDB for MariaDB
+pub const SUPPORTED_COLUMN_TYPES: [&str; 12] = ["INT", "TINYINT", "BIGINT", "TEXT", "LONGTEXT", "FLOAT", "DECIMAL", "VARCHAR", "DATETIME", "JSON", "UUID", "INET6"];
+
+/// All type compatibility rules
+pub const COLUMN_TYPE_COMPATIBILITY_RULES: [ColumnCompatibilityRule; 3] = [
+    ColumnCompatibilityRule {
+        incompatible: &["varchar", "text", "longtext", "datetime", "decimal", "json", "uuid", "inet6"],
+        attribute: "auto_increment",
+    },
+    ColumnCompatibilityRule {
+        incompatible: &["json"],
+        attribute: "unique",
+    },
+    // UUID and INET6 have a fixed size
+    ColumnCompatibilityRule {
+        incompatible: &["uuid", "inet6"],
+        attribute: "length",
+    },
+];
+
+pub const COLUMN_ATTRIBUTE_COMPATIBILITY_RULES: [ColumnCompatibilityRule; 1] = [ColumnCompatibilityRule {
+    incompatible: &["null"],
+    attribute: "auto_increment",
+}];
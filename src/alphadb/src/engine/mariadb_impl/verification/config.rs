@@ -0,0 +1,97 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::{
+    engine_config::{AltertableHookParams, CreatetableHookParams, EngineConfig, IndexNameScope, VerificationHooks, VerifyHookParams},
+    verification::{
+        issue::VerificationIssueDraft,
+        rules::{INVALID_SEQUENCE, INVALID_SYSTEM_VERSIONING},
+    },
+};
+use crate::engine::mariadb_impl::query::sequence::Sequence;
+use crate::engine::mariadb_impl::verification::compatibility::{COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, SUPPORTED_COLUMN_TYPES};
+use crate::engine::mysql_impl::verification::config::RESERVED_WORDS;
+
+/// MariaDB engine configuration for verification
+pub const MARIADB_CONFIG: EngineConfig = EngineConfig {
+    name: "mariadb",
+    non_column_table_keys: &["primary_key", "system_versioning"],
+    string_columns: &["TEXT", "LONGTEXT", "VARCHAR", "DATETIME", "JSON", "UUID", "INET6"],
+    int_columns: &["INT", "TINYINT", "BIGINT", "DATETIME"],
+    float_columns: &["FLOAT", "DECIMAL"],
+    supported_column_types: &SUPPORTED_COLUMN_TYPES,
+    version_methods: &["createsequence", "altersequence", "dropsequence"],
+
+    index_types: &["btree", "hash", "rtree"],
+    partial_indexes: false,
+    index_name_scope: IndexNameScope::Table,
+    reserved_words: &RESERVED_WORDS,
+    max_identifier_length: Some(64),
+
+    type_compatibility_rules: &COLUMN_TYPE_COMPATIBILITY_RULES,
+    attribute_compatibility_rules: &COLUMN_ATTRIBUTE_COMPATIBILITY_RULES,
+
+    verification_hooks: VerificationHooks {
+        verify: &[verify_sequences],
+        createtable: &[verify_system_versioning],
+        altertable: &[verify_system_versioning_change],
+        default_data: &[],
+        column_compatibility: &[],
+    },
+};
+
+/// Every sequence must be a name or a valid sequence object
+fn verify_sequences(params: &VerifyHookParams) -> Result<(), VerificationIssueDraft> {
+    let versions = match params.version_source["version"].as_array() {
+        Some(versions) => versions,
+        None => return Ok(()),
+    };
+
+    for version in versions {
+        for key in ["createsequence", "altersequence", "dropsequence"] {
+            if version[key].is_null() {
+                continue;
+            }
+
+            let sequences = version[key]
+                .as_array()
+                .ok_or_else(|| INVALID_SEQUENCE.draft(format!("Version {}: {key} must be an array of sequences", version["_id"])))?;
+
+            for sequence in sequences {
+                if let Err(e) = Sequence::try_from(sequence) {
+                    return Err(INVALID_SEQUENCE.draft(format!("Version {}: {}", version["_id"], e.message)));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_system_versioning_value(table_data: &serde_json::Value) -> Result<(), VerificationIssueDraft> {
+    if !table_data["system_versioning"].is_null() && !table_data["system_versioning"].is_boolean() {
+        return Err(INVALID_SYSTEM_VERSIONING.draft("system_versioning must be either true or false".to_string()));
+    }
+
+    Ok(())
+}
+
+fn verify_system_versioning(params: &CreatetableHookParams) -> Result<(), VerificationIssueDraft> {
+    verify_system_versioning_value(params.table_data)
+}
+
+fn verify_system_versioning_change(params: &AltertableHookParams) -> Result<(), VerificationIssueDraft> {
+    verify_system_versioning_value(params.alter_data)
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::{
+    connect_options::ConnectOptions,
+    method_types::{HistoryEntry, Init, Query, Status},
+    runtime_config::{RuntimeConfig, RuntimeHooks},
+    utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
+};
+use crate::engine::mock_impl::model::MockDatabase;
+use crate::engine::mock_impl::plan::plan;
+use crate::engine::AlphaDBEngine;
+
+/// The database is named after `database`, there is nothing to connect to
+fn mock_connect(options: &ConnectOptions) -> Result<(MockDatabase, String), AlphaDBError> {
+    let db_name = options.database.clone().unwrap_or_else(|| "mock".to_string());
+    Ok((MockDatabase::new(&db_name), db_name))
+}
+
+fn mock_init(_db_name: &str, connection: &mut MockDatabase) -> Result<Init, AlphaDBError> {
+    if connection.initialized {
+        return Ok(Init::AlreadyInitialized);
+    }
+
+    connection.initialized = true;
+    connection.version = Some("0.0.0".to_string());
+    Ok(Init::Success)
+}
+
+pub(crate) fn mock_status(db_name: &str, connection: &mut MockDatabase) -> Result<Status, AlphaDBError> {
+    Ok(Status {
+        init: connection.initialized,
+        version: connection.version.clone(),
+        name: db_name.to_string(),
+        template: connection.template.clone(),
+    })
+}
+
+fn mock_history(_db_name: &str, connection: &mut MockDatabase) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+    Ok(connection.history.clone())
+}
+
+fn mock_update_queries(db_name: &str, connection: &mut MockDatabase, version_source: String, target_version: Option<&str>, no_data: bool) -> Result<Vec<Query>, AlphaDBError> {
+    Ok(plan(db_name, connection, version_source, target_version, no_data)?.iter().map(|o| o.to_query()).collect())
+}
+
+/// Operations are applied to a copy, so a failing update leaves the database untouched.
+/// The database takes the engine of the version source, if it has none yet.
+fn mock_update(
+    db_name: &str,
+    connection: &mut MockDatabase,
+    version_source: String,
+    target_version: Option<&str>,
+    no_data: bool,
+    _tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+) -> Result<(), AlphaDBError> {
+    let operations = plan(db_name, connection, version_source.clone(), target_version, no_data)?;
+
+    let mut updated = connection.clone();
+    for operation in operations {
+        operation.apply(&mut updated)?;
+    }
+
+    if updated.engine.is_none() {
+        updated.engine = AlphaDBEngine::from_version_source(&version_source).ok();
+    }
+
+    *connection = updated;
+    Ok(())
+}
+
+fn mock_vacate(connection: &mut MockDatabase) -> Result<(), AlphaDBError> {
+    *connection = MockDatabase::new(&connection.name);
+    Ok(())
+}
+
+/// Mock runtime configuration
+pub fn mock_runtime_config() -> RuntimeConfig<MockDatabase> {
+    RuntimeConfig {
+        name: "mock",
+        hooks: RuntimeHooks {
+            connect: mock_connect,
+            init: mock_init,
+            status: mock_status,
+            history: mock_history,
+            update_queries: mock_update_queries,
+            update: mock_update,
+            vacate: mock_vacate,
+        },
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::mock_runtime_config;
+    use crate::core::connect_options::ConnectOptions;
+    use crate::core::utils::types::ToleratedVerificationIssueLevel;
+    use crate::AlphaDB;
+    use serde_json::json;
+
+    fn version_source() -> String {
+        json!({
+            "name": "shop",
+            "version": [
+                {
+                    "_id": "0.0.1",
+                    "createtable": {
+                        "customer": {
+                            "primary_key": "id",
+                            "id": {"type": "INT", "auto_increment": true},
+                            "name": {"type": "VARCHAR", "length": 100},
+                            "index": [{"name": "customer_name", "columns": ["name"]}],
+                        }
+                    },
+                    "default_data": {"customer": [{"name": "Alice"}, {"name": "Bob"}]}
+                },
+                {
+                    "_id": "0.0.2",
+                    "altertable": {
+                        "customer": {
+                            "renamecolumn": {"name": "full_name"},
+                            "addcolumn": {"active": {"type": "TINYINT", "default": 1}},
+                        }
+                    }
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn upgrade_path() {
+        let mut db = AlphaDB::new(mock_runtime_config());
+        db.connect(&ConnectOptions::from_path("shop")).unwrap();
+        db.init().unwrap();
+
+        db.update(version_source(), None, false, ToleratedVerificationIssueLevel::Low).unwrap();
+
+        let status = db.status().unwrap();
+        assert_eq!(status.version.as_deref(), Some("0.0.2"));
+        assert_eq!(status.template.as_deref(), Some("shop"));
+        assert_eq!(db.history().unwrap().iter().map(|h| h.version.as_str()).collect::<Vec<_>>(), ["0.0.1", "0.0.2"]);
+
+        let database = db.into_connection().unwrap();
+        let customer = database.table("customer").unwrap();
+        assert_eq!(customer.column_names(), ["id", "full_name", "active"]);
+        assert_eq!(customer.index("customer_name").unwrap()["columns"], json!(["full_name"]));
+        assert_eq!(customer.rows.len(), 2);
+        assert_eq!(customer.rows[1]["full_name"], "Bob");
+        assert_eq!(customer.rows[1]["active"], 1);
+    }
+
+    #[test]
+    fn update_queries() {
+        let mut db = AlphaDB::new(mock_runtime_config());
+        db.connect(&ConnectOptions::default()).unwrap();
+        db.init().unwrap();
+
+        let queries: Vec<String> = db.update_queries(version_source(), None, false).unwrap().into_iter().map(|q| q.query).collect();
+        assert_eq!(
+            queries,
+            [
+                "CREATE TABLE customer (id, name)",
+                "CREATE INDEX customer_name ON customer",
+                "ALTER TABLE customer ADD COLUMN active",
+                "ALTER TABLE customer RENAME COLUMN name TO full_name",
+                // Default data is consolidated, so it uses the renamed column
+                "INSERT INTO customer (full_name)",
+                "INSERT INTO customer (full_name)",
+                "RECORD VERSION 0.0.1",
+                "RECORD VERSION 0.0.2",
+                "SET VERSION 0.0.2",
+            ]
+        );
+
+        // Planning does not change the database
+        assert!(db.into_connection().unwrap().tables().is_empty());
+    }
+
+    #[test]
+    fn update_driver_checks() {
+        let mut db = AlphaDB::new(mock_runtime_config());
+        db.connect(&ConnectOptions::default()).unwrap();
+        db.init().unwrap();
+
+        let err = db.update_queries(version_source(), Some("latest"), false).unwrap_err();
+        assert_eq!(err.error, "invalid-version-number");
+
+        let mysql_source = json!({"name": "shop", "engine": "mysql", "version": [{"_id": "0.0.1", "createtable": {"customer": {"id": {"type": "INT"}}}}]});
+        db.update(mysql_source.to_string(), None, false, ToleratedVerificationIssueLevel::Low).unwrap();
+
+        // The database now stands in for MySQL
+        let postgres_source = json!({"name": "shop", "engine": "postgres", "version": [{"_id": "0.0.2", "createtable": {"order": {"id": {"type": "INT"}}}}]});
+        let err = db.update_queries(postgres_source.to_string(), None, false).unwrap_err();
+        assert_eq!(err.error, "incompatible-version-source");
+
+        let mut database = db.into_connection().unwrap();
+        database.version = None;
+        let mut db = AlphaDB::from_connection(mock_runtime_config(), database, "mock");
+        let err = db.update_queries(version_source(), None, false).unwrap_err();
+        assert_eq!(err.error, "no-version-number");
+    }
+
+    #[test]
+    fn failed_update_is_rolled_back() {
+        let mut db = AlphaDB::new(mock_runtime_config());
+        db.connect(&ConnectOptions::default()).unwrap();
+        db.init().unwrap();
+
+        let version_source = json!({
+            "name": "shop",
+            "version": [
+                {"_id": "0.0.1", "createtable": {"customer": {"id": {"type": "INT"}}}},
+                {"_id": "0.0.2", "altertable": {"customer": {"dropcolumn": ["name"]}}}
+            ]
+        });
+
+        let err = db.update(version_source.to_string(), None, false, ToleratedVerificationIssueLevel::Low).unwrap_err();
+        assert_eq!(err.error, "column-not-found");
+
+        assert_eq!(db.status().unwrap().version.as_deref(), Some("0.0.0"));
+        db.vacate().unwrap();
+        assert!(!db.status().unwrap().init);
+    }
+}
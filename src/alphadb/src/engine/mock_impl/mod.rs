@@ -0,0 +1,29 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! In-memory engine for testing version sources without a database.
+//!
+//! The connection is a [`MockDatabase`], a schema model with rows. Updates
+//! are planned as typed [`Operation`]s and applied to the model, so a full
+//! upgrade path can be run and its resulting schema asserted offline.
+
+pub mod config;
+pub mod model;
+pub mod operation;
+pub mod plan;
+
+pub use config::mock_runtime_config;
+pub use model::{MockColumn, MockDatabase, MockTable};
+pub use operation::Operation;
@@ -0,0 +1,135 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::HistoryEntry;
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::AlphaDBEngine;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A column and its version source attributes (`type`, `length`, `null`, ...)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockColumn {
+    pub name: String,
+    pub definition: Value,
+}
+
+impl MockColumn {
+    pub fn column_type(&self) -> Option<&str> {
+        self.definition["type"].as_str()
+    }
+
+    pub fn nullable(&self) -> bool {
+        self.definition["null"] == true
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MockTable {
+    /// Columns in definition order
+    pub columns: Vec<MockColumn>,
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<Value>,
+    pub checks: Vec<Value>,
+    pub indexes: Vec<Value>,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+impl MockTable {
+    pub fn column(&self, name: &str) -> Option<&MockColumn> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    pub fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    pub fn index(&self, name: &str) -> Option<&Value> {
+        self.indexes.iter().find(|i| i["name"] == name)
+    }
+
+    pub fn foreign_key(&self, name: &str) -> Option<&Value> {
+        self.foreign_keys.iter().find(|f| f["name"] == name)
+    }
+}
+
+/// In-memory database, used as the connection of the mock engine.
+/// Create one with [`MockDatabase::new`] and pass it to `AlphaDB::from_connection`,
+/// or let `connect` create an empty one.
+#[derive(Debug, Clone, Default)]
+pub struct MockDatabase {
+    pub name: String,
+    pub(crate) initialized: bool,
+    pub(crate) version: Option<String>,
+    pub(crate) template: Option<String>,
+
+    /// Engine of the first version source that named one
+    pub(crate) engine: Option<AlphaDBEngine>,
+    pub(crate) history: Vec<HistoryEntry>,
+    pub(crate) tables: BTreeMap<String, MockTable>,
+}
+
+impl MockDatabase {
+    pub fn new(name: &str) -> MockDatabase {
+        MockDatabase {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn tables(&self) -> &BTreeMap<String, MockTable> {
+        &self.tables
+    }
+
+    pub fn table(&self, name: &str) -> Option<&MockTable> {
+        self.tables.get(name)
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn engine(&self) -> Option<AlphaDBEngine> {
+        self.engine
+    }
+
+    pub(crate) fn table_mut(&mut self, name: &str) -> Result<&mut MockTable, AlphaDBError> {
+        self.tables.get_mut(name).ok_or_else(|| AlphaDBError {
+            message: format!("Table '{name}' does not exist"),
+            error: "table-not-found".to_string(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Current UTC time as `YYYY-MM-DD HH:MM:SS`, like the history table of the other engines
+pub(crate) fn timestamp() -> String {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let (days, time) = (seconds / 86400, seconds % 86400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}", time / 3600, time % 3600 / 60, time % 60)
+}
@@ -0,0 +1,394 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::{HistoryEntry, Query, QueryValue};
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::mock_impl::model::{timestamp, MockColumn, MockDatabase, MockTable};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// A single change to a [`MockDatabase`], planned from a version source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Operation {
+    CreateTable { table: String, definition: Value },
+    DropColumn { table: String, column: String },
+    AddColumn { table: String, column: String, definition: Value },
+    /// `recreate: false` keeps the attributes that are not mentioned
+    ModifyColumn { table: String, column: String, definition: Value, recreate: bool },
+    RenameColumn { table: String, column: String, new_name: String },
+    /// An empty column list drops the primary key
+    SetPrimaryKey { table: String, columns: Vec<String> },
+    DropForeignKey { table: String, name: String },
+    AddForeignKey { table: String, definition: Value },
+    CreateIndex { table: String, definition: Value },
+    DropIndex { table: String, name: String },
+    Insert { table: String, row: Map<String, Value> },
+    RecordVersion { version: String, template: String },
+    SetVersion { version: String, template: String },
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::CreateTable { table, definition } => {
+                let columns = definition.as_object().map(|d| d.iter().filter(|(_, v)| v.is_object()).map(|(k, _)| k.as_str()).collect::<Vec<_>>());
+                write!(f, "CREATE TABLE {table} ({})", columns.unwrap_or_default().join(", "))
+            }
+            Operation::DropColumn { table, column } => write!(f, "ALTER TABLE {table} DROP COLUMN {column}"),
+            Operation::AddColumn { table, column, .. } => write!(f, "ALTER TABLE {table} ADD COLUMN {column}"),
+            Operation::ModifyColumn { table, column, .. } => write!(f, "ALTER TABLE {table} MODIFY COLUMN {column}"),
+            Operation::RenameColumn { table, column, new_name } => write!(f, "ALTER TABLE {table} RENAME COLUMN {column} TO {new_name}"),
+            Operation::SetPrimaryKey { table, columns } if columns.is_empty() => write!(f, "ALTER TABLE {table} DROP PRIMARY KEY"),
+            Operation::SetPrimaryKey { table, columns } => write!(f, "ALTER TABLE {table} PRIMARY KEY ({})", columns.join(", ")),
+            Operation::DropForeignKey { table, name } => write!(f, "ALTER TABLE {table} DROP FOREIGN KEY {name}"),
+            Operation::AddForeignKey { table, definition } => write!(f, "ALTER TABLE {table} ADD FOREIGN KEY {}", definition["name"].as_str().unwrap_or_default()),
+            Operation::CreateIndex { table, definition } => write!(f, "CREATE INDEX {} ON {table}", definition["name"].as_str().unwrap_or_default()),
+            Operation::DropIndex { table, name } => write!(f, "DROP INDEX {name} ON {table}"),
+            Operation::Insert { table, row } => write!(f, "INSERT INTO {table} ({})", row.keys().map(String::as_str).collect::<Vec<_>>().join(", ")),
+            Operation::RecordVersion { version, .. } => write!(f, "RECORD VERSION {version}"),
+            Operation::SetVersion { version, .. } => write!(f, "SET VERSION {version}"),
+        }
+    }
+}
+
+fn schema_err(message: String, error: &str) -> AlphaDBError {
+    AlphaDBError {
+        message,
+        error: error.to_string(),
+        ..Default::default()
+    }
+}
+
+fn column_exists(table_name: &str, table: &MockTable, column: &str) -> Result<(), AlphaDBError> {
+    match table.column(column) {
+        Some(_) => Ok(()),
+        None => Err(schema_err(format!("Column '{column}' does not exist in table '{table_name}'"), "column-not-found")),
+    }
+}
+
+/// Value a new row gets for a column that was not provided
+fn missing_value(table_name: &str, table: &MockTable, column: &MockColumn) -> Result<Value, AlphaDBError> {
+    if column.definition["auto_increment"] == true {
+        let next = table.rows.iter().filter_map(|r| r.get(&column.name).and_then(Value::as_i64)).max().unwrap_or(0) + 1;
+        return Ok(Value::from(next));
+    }
+
+    if !column.definition["default"].is_null() {
+        return Ok(column.definition["default"].clone());
+    }
+
+    if column.nullable() {
+        return Ok(Value::Null);
+    }
+
+    Err(schema_err(
+        format!("Column '{}' of table '{table_name}' is NOT NULL and has no default value", column.name),
+        "not-null",
+    ))
+}
+
+impl Operation {
+    /// Describe the operation as a query, as returned by `update_queries`
+    pub fn to_query(&self) -> Query {
+        let data = match self {
+            Operation::Insert { row, .. } => Some(row.values().map(QueryValue::from_json).collect()),
+            _ => None,
+        };
+
+        Query { query: self.to_string(), data }
+    }
+
+    /// Apply the operation, erroring like a database would on an invalid change
+    pub fn apply(&self, database: &mut MockDatabase) -> Result<(), AlphaDBError> {
+        match self {
+            Operation::CreateTable { table, definition } => {
+                if database.tables.contains_key(table) {
+                    return Err(schema_err(format!("Table '{table}' already exists"), "table-exists"));
+                }
+
+                let mut new_table = MockTable::default();
+                if let Some(definition) = definition.as_object() {
+                    for (key, value) in definition {
+                        match key.as_str() {
+                            "primary_key" => new_table.primary_key = primary_key_columns(value),
+                            "foreign_key" => new_table.foreign_keys = value.as_array().cloned().unwrap_or_default(),
+                            "check" => new_table.checks = value.as_array().cloned().unwrap_or_default(),
+                            _ if value.is_object() => new_table.columns.push(MockColumn {
+                                name: key.clone(),
+                                definition: value.clone(),
+                            }),
+                            _ => (),
+                        }
+                    }
+                }
+
+                for column in &new_table.primary_key {
+                    column_exists(table, &new_table, column)?;
+                }
+
+                database.tables.insert(table.clone(), new_table);
+            }
+
+            Operation::DropColumn { table: table_name, column } => {
+                let table = database.table_mut(table_name)?;
+                column_exists(table_name, table, column)?;
+
+                table.columns.retain(|c| &c.name != column);
+                table.primary_key.retain(|c| c != column);
+                for row in table.rows.iter_mut() {
+                    row.remove(column);
+                }
+            }
+
+            Operation::AddColumn { table: table_name, column, definition } => {
+                let table = database.table_mut(table_name)?;
+                if table.column(column).is_some() {
+                    return Err(schema_err(format!("Column '{column}' already exists in table '{table_name}'"), "column-exists"));
+                }
+
+                let new_column = MockColumn {
+                    name: column.clone(),
+                    definition: definition.clone(),
+                };
+
+                // Existing rows get the default, which a NOT NULL column must have
+                if !table.rows.is_empty() {
+                    let value = missing_value(table_name, table, &new_column)?;
+                    for row in table.rows.iter_mut() {
+                        row.insert(column.clone(), value.clone());
+                    }
+                }
+
+                table.columns.push(new_column);
+            }
+
+            Operation::ModifyColumn { table: table_name, column, definition, recreate } => {
+                let table = database.table_mut(table_name)?;
+                column_exists(table_name, table, column)?;
+
+                let existing = table.columns.iter_mut().find(|c| &c.name == column).unwrap();
+                if *recreate {
+                    existing.definition = definition.clone();
+                } else if let (Some(existing), Some(changes)) = (existing.definition.as_object_mut(), definition.as_object()) {
+                    for (key, value) in changes {
+                        existing.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+
+            Operation::RenameColumn { table: table_name, column, new_name } => {
+                let table = database.table_mut(table_name)?;
+                column_exists(table_name, table, column)?;
+                if table.column(new_name).is_some() {
+                    return Err(schema_err(format!("Column '{new_name}' already exists in table '{table_name}'"), "column-exists"));
+                }
+
+                table.columns.iter_mut().filter(|c| &c.name == column).for_each(|c| c.name = new_name.clone());
+                table.primary_key.iter_mut().filter(|c| *c == column).for_each(|c| *c = new_name.clone());
+
+                for index in table.indexes.iter_mut() {
+                    if let Some(columns) = index["columns"].as_array_mut() {
+                        columns.iter_mut().filter(|c| *c == column).for_each(|c| *c = Value::from(new_name.as_str()));
+                    }
+                }
+
+                for row in table.rows.iter_mut() {
+                    if let Some(value) = row.remove(column) {
+                        row.insert(new_name.clone(), value);
+                    }
+                }
+            }
+
+            Operation::SetPrimaryKey { table: table_name, columns } => {
+                let table = database.table_mut(table_name)?;
+                for column in columns {
+                    column_exists(table_name, table, column)?;
+                }
+
+                table.primary_key = columns.clone();
+            }
+
+            Operation::DropForeignKey { table: table_name, name } => {
+                let table = database.table_mut(table_name)?;
+                if table.foreign_key(name).is_none() {
+                    return Err(schema_err(format!("Foreign key '{name}' does not exist on table '{table_name}'"), "foreign-key-not-found"));
+                }
+
+                table.foreign_keys.retain(|f| f["name"] != name.as_str());
+            }
+
+            Operation::AddForeignKey { table: table_name, definition } => {
+                if let Some(references) = definition["references"].as_str() {
+                    if !database.tables.contains_key(references) {
+                        return Err(schema_err(format!("Table '{references}' referenced by table '{table_name}' does not exist"), "table-not-found"));
+                    }
+                }
+
+                database.table_mut(table_name)?.foreign_keys.push(definition.clone());
+            }
+
+            Operation::CreateIndex { table: table_name, definition } => {
+                let table = database.table_mut(table_name)?;
+                let name = definition["name"].as_str().unwrap_or_default();
+                if table.index(name).is_some() {
+                    return Err(schema_err(format!("Index '{name}' already exists on table '{table_name}'"), "index-exists"));
+                }
+
+                for column in definition["columns"].as_array().into_iter().flatten() {
+                    column_exists(table_name, table, column.as_str().unwrap_or_default())?;
+                }
+
+                table.indexes.push(definition.clone());
+            }
+
+            Operation::DropIndex { table: table_name, name } => {
+                let table = database.table_mut(table_name)?;
+                if table.index(name).is_none() {
+                    return Err(schema_err(format!("Index '{name}' does not exist on table '{table_name}'"), "index-not-found"));
+                }
+
+                table.indexes.retain(|i| i["name"] != name.as_str());
+            }
+
+            Operation::Insert { table: table_name, row } => {
+                let table = database.table_mut(table_name)?;
+                for column in row.keys() {
+                    column_exists(table_name, table, column)?;
+                }
+
+                let mut new_row = Map::new();
+                for column in &table.columns {
+                    let value = match row.get(&column.name) {
+                        Some(value) => value.clone(),
+                        None => missing_value(table_name, table, column)?,
+                    };
+                    new_row.insert(column.name.clone(), value);
+                }
+
+                if !table.primary_key.is_empty() {
+                    let key = |r: &Map<String, Value>| table.primary_key.iter().map(|c| r.get(c).cloned().unwrap_or_default()).collect::<Vec<_>>();
+                    if table.rows.iter().any(|r| key(r) == key(&new_row)) {
+                        return Err(schema_err(format!("Duplicate primary key {:?} in table '{table_name}'", key(&new_row)), "duplicate-key"));
+                    }
+                }
+
+                table.rows.push(new_row);
+            }
+
+            Operation::RecordVersion { version, template } => database.history.push(HistoryEntry {
+                version: version.clone(),
+                template: Some(template.clone()),
+                applied_at: timestamp(),
+            }),
+
+            Operation::SetVersion { version, template } => {
+                database.version = Some(version.clone());
+                database.template = Some(template.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Column names of a `primary_key` value, a single name or a list of names
+pub(crate) fn primary_key_columns(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(column) => vec![column.clone()],
+        Value::Array(columns) => columns.iter().filter_map(|c| c.as_str().map(String::from)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod operation_tests {
+    use super::Operation;
+    use crate::engine::mock_impl::model::MockDatabase;
+    use serde_json::json;
+
+    fn users() -> MockDatabase {
+        let mut database = MockDatabase::new("test");
+        Operation::CreateTable {
+            table: "users".to_string(),
+            definition: json!({
+                "primary_key": "id",
+                "id": {"type": "INT", "auto_increment": true},
+                "name": {"type": "VARCHAR", "length": 50},
+            }),
+        }
+        .apply(&mut database)
+        .unwrap();
+        database
+    }
+
+    fn insert(database: &mut MockDatabase, row: serde_json::Value) -> Result<(), crate::core::utils::errors::AlphaDBError> {
+        Operation::Insert {
+            table: "users".to_string(),
+            row: row.as_object().unwrap().clone(),
+        }
+        .apply(database)
+    }
+
+    #[test]
+    fn insert_rows() {
+        let mut database = users();
+        insert(&mut database, json!({"name": "a"})).unwrap();
+        insert(&mut database, json!({"name": "b"})).unwrap();
+
+        let rows = &database.table("users").unwrap().rows;
+        assert_eq!(rows[1]["id"], 2);
+
+        assert_eq!(insert(&mut database, json!({"id": 2, "name": "c"})).unwrap_err().error, "duplicate-key");
+        assert_eq!(insert(&mut database, json!({})).unwrap_err().error, "not-null");
+        assert_eq!(insert(&mut database, json!({"name": "d", "age": 3})).unwrap_err().error, "column-not-found");
+    }
+
+    #[test]
+    fn add_column_to_filled_table() {
+        let mut database = users();
+        insert(&mut database, json!({"name": "a"})).unwrap();
+
+        let add = |column: &str, definition: serde_json::Value| Operation::AddColumn {
+            table: "users".to_string(),
+            column: column.to_string(),
+            definition,
+        };
+
+        assert_eq!(add("age", json!({"type": "INT"})).apply(&mut database).unwrap_err().error, "not-null");
+
+        add("active", json!({"type": "TINYINT", "default": 1})).apply(&mut database).unwrap();
+        assert_eq!(database.table("users").unwrap().rows[0]["active"], 1);
+    }
+
+    #[test]
+    fn rename_column() {
+        let mut database = users();
+        insert(&mut database, json!({"name": "a"})).unwrap();
+
+        Operation::RenameColumn {
+            table: "users".to_string(),
+            column: "id".to_string(),
+            new_name: "user_id".to_string(),
+        }
+        .apply(&mut database)
+        .unwrap();
+
+        let table = database.table("users").unwrap();
+        assert_eq!(table.column_names(), ["user_id", "name"]);
+        assert_eq!(table.primary_key, ["user_id"]);
+        assert_eq!(table.rows[0]["user_id"], 1);
+    }
+}
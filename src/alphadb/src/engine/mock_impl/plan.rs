@@ -0,0 +1,224 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::Query;
+use crate::core::query::column::DefineColumn;
+use crate::core::query::identifier::IdentifierQuote;
+use crate::core::query::table::TableQueryConfig;
+use crate::core::update_queries::{update_queries, UpdateQueriesConfig};
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::json::{get_json_object, get_json_string};
+use crate::core::utils::version_source::get_version_array;
+use crate::engine::mock_impl::config::mock_status;
+use crate::engine::mock_impl::model::MockDatabase;
+use crate::engine::mock_impl::operation::{primary_key_columns, Operation};
+use serde_json::Value;
+
+/// The mock renders no SQL, its tables are planned by the `create_table` and
+/// `alter_table` hooks of [`MOCK_UPDATE_QUERIES_CONFIG`], which do not use these.
+const MOCK_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
+    name: "mock",
+    identifier_quote: IdentifierQuote::DoubleQuote,
+    define_column: |_, _, _, _| Ok(None),
+    foreign_key_constraint: |_, _| Ok(String::new()),
+    check_constraint: |_, _| Ok(String::new()),
+    table_options: None,
+    modify_column: |_, _, _, _, _| Ok(Vec::new()),
+    drop_primary_key: |_| Vec::new(),
+    add_primary_key: |_, _| Vec::new(),
+    drop_foreign_key: |_| DefineColumn::new(),
+    preprocess: None,
+    rename_column: None,
+    column_if_exists: false,
+    create_options: None,
+    alter_options: None,
+};
+
+/// Plans [`Operation`]s instead of queries. The driver passes them around as
+/// queries, serialized to JSON, and [`plan`] reads them back.
+pub const MOCK_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<MockDatabase> = UpdateQueriesConfig {
+    engine: None,
+    status: mock_status,
+    table_config: &MOCK_TABLE_CONFIG,
+    create_index,
+    drop_index,
+    default_data,
+    config_update_query,
+    history_query,
+    history_table_query: None,
+    version_extras: None,
+    create_table: Some(create_table),
+    alter_table: Some(alter_table),
+};
+
+/// Serializing strings and JSON values can not fail
+fn to_query(operation: Operation) -> Query {
+    Query {
+        query: serde_json::to_string(&operation).unwrap_or_default(),
+        data: None,
+    }
+}
+
+/// Plan the operations that update `database` to `target_version` (the latest
+/// version when `None`), through the same update driver as the SQL engines.
+/// A database that was updated before only accepts version sources for the
+/// same engine.
+pub fn plan(db_name: &str, database: &mut MockDatabase, version_source: String, target_version: Option<&str>, no_data: bool) -> Result<Vec<Operation>, AlphaDBError> {
+    let config = UpdateQueriesConfig {
+        engine: database.engine,
+        ..MOCK_UPDATE_QUERIES_CONFIG
+    };
+
+    update_queries(&config, db_name, database, version_source, target_version, no_data)?
+        .iter()
+        .map(|query| {
+            serde_json::from_str(&query.query).map_err(|e| AlphaDBError {
+                message: format!("Invalid mock operation: {e}"),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+fn create_table(_: &TableQueryConfig, version: &Value, table_name: &str, _: &str) -> Result<Vec<Query>, AlphaDBError> {
+    Ok(vec![to_query(Operation::CreateTable {
+        table: table_name.to_string(),
+        definition: version["createtable"][table_name].clone(),
+    })])
+}
+
+fn alter_table(_: &TableQueryConfig, version_source: &Value, table_name: &str, version: &str) -> Result<Vec<Query>, AlphaDBError> {
+    let mut operations = Vec::new();
+
+    for v in get_version_array(version_source)?.iter().filter(|v| v["_id"] == version) {
+        plan_altertable(table_name, &v["altertable"][table_name], &mut operations)?;
+    }
+
+    Ok(operations.into_iter().map(to_query).collect())
+}
+
+fn create_index(index: &Value, table_name: &str) -> Result<String, AlphaDBError> {
+    Ok(to_query(Operation::CreateIndex {
+        table: table_name.to_string(),
+        definition: index.clone(),
+    })
+    .query)
+}
+
+fn drop_index(index_name: &Value, table_name: &str) -> Result<String, AlphaDBError> {
+    Ok(to_query(Operation::DropIndex {
+        table: table_name.to_string(),
+        name: get_json_string(index_name)?.to_string(),
+    })
+    .query)
+}
+
+fn default_data(table_name: &str, item: &Value) -> Result<Query, AlphaDBError> {
+    Ok(to_query(Operation::Insert {
+        table: table_name.to_string(),
+        row: get_json_object(item)?.clone(),
+    }))
+}
+
+fn config_update_query(latest_version: &str, template_name: &str, _db_name: &str) -> Query {
+    to_query(Operation::SetVersion {
+        version: latest_version.to_string(),
+        template: template_name.to_string(),
+    })
+}
+
+fn history_query(version: &str, template_name: &str, _db_name: &str) -> Query {
+    to_query(Operation::RecordVersion {
+        version: version.to_string(),
+        template: template_name.to_string(),
+    })
+}
+
+/// Operations of an `altertable` block, its indexes are planned by the update driver
+fn plan_altertable(table: &str, changes: &Value, operations: &mut Vec<Operation>) -> Result<(), AlphaDBError> {
+    let table = table.to_string();
+
+    for column in changes["dropcolumn"].as_array().into_iter().flatten() {
+        operations.push(Operation::DropColumn {
+            table: table.clone(),
+            column: get_json_string(column)?.to_string(),
+        });
+    }
+
+    for (column, definition) in changes["addcolumn"].as_object().into_iter().flatten() {
+        operations.push(Operation::AddColumn {
+            table: table.clone(),
+            column: column.clone(),
+            definition: definition.clone(),
+        });
+    }
+
+    for (column, definition) in changes["modifycolumn"].as_object().into_iter().flatten() {
+        let mut definition = definition.clone();
+        let recreate = definition["recreate"] != false;
+        if let Some(definition) = definition.as_object_mut() {
+            definition.remove("recreate");
+        }
+
+        operations.push(Operation::ModifyColumn {
+            table: table.clone(),
+            column: column.clone(),
+            definition,
+            recreate,
+        });
+    }
+
+    for (column, new_name) in changes["renamecolumn"].as_object().into_iter().flatten() {
+        operations.push(Operation::RenameColumn {
+            table: table.clone(),
+            column: column.clone(),
+            new_name: get_json_string(new_name)?.to_string(),
+        });
+    }
+
+    if let Some(primary_key) = changes.get("primary_key") {
+        operations.push(Operation::SetPrimaryKey {
+            table: table.clone(),
+            columns: primary_key_columns(primary_key),
+        });
+    }
+
+    for name in changes["drop_foreign_key"].as_array().into_iter().flatten() {
+        operations.push(Operation::DropForeignKey {
+            table: table.clone(),
+            name: get_json_string(name)?.to_string(),
+        });
+    }
+
+    for foreign_key in changes["modify_foreign_key"].as_array().into_iter().flatten() {
+        operations.push(Operation::DropForeignKey {
+            table: table.clone(),
+            name: get_json_string(&foreign_key["name"])?.to_string(),
+        });
+        operations.push(Operation::AddForeignKey {
+            table: table.clone(),
+            definition: foreign_key.clone(),
+        });
+    }
+
+    for foreign_key in changes["add_foreign_key"].as_array().into_iter().flatten() {
+        operations.push(Operation::AddForeignKey {
+            table: table.clone(),
+            definition: foreign_key.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -15,20 +15,61 @@ pub mod postgres_impl;
 #[cfg(feature = "postgres")]
 pub use postgres_impl::postgres_runtime_config as postgres;
 
-use crate::{core::utils::errors::AlphaDBError, verification::VersionTrace};
+#[cfg(feature = "mysql")]
+pub mod mariadb_impl;
+
+#[cfg(feature = "mysql")]
+pub use mariadb_impl::mariadb_runtime_config as mariadb;
+
+#[cfg(feature = "mock")]
+pub mod mock_impl;
+
+#[cfg(feature = "mock")]
+pub use mock_impl::mock_runtime_config as mock;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite_impl;
+
+#[cfg(feature = "sqlite")]
+pub use sqlite_impl::sqlite_runtime_config as sqlite;
+
+use crate::{
+    core::utils::{errors::AlphaDBError, json::get_json_string, version_source::parse_version_source_string},
+    verification::VersionTrace,
+};
 
 /// Supported AlphaDB database engines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AlphaDBEngine {
     PostgreSQL,
     MySQL,
+    MariaDB,
+    SQLite,
 }
 
 impl AlphaDBEngine {
-    /// Cased name for messages ("MySQL"/"PostgreSQL"); `Display` gives the lowercase id.
+    /// Cased name for messages ("MySQL"/"MariaDB"/"PostgreSQL"/"SQLite"); `Display` gives the lowercase id.
     pub fn display_name(&self) -> &'static str {
         match self {
             AlphaDBEngine::PostgreSQL => "PostgreSQL",
             AlphaDBEngine::MySQL => "MySQL",
+            AlphaDBEngine::MariaDB => "MariaDB",
+            AlphaDBEngine::SQLite => "SQLite",
+        }
+    }
+
+    /// Read the engine from the `engine` field of a version source
+    pub fn from_version_source(version_source: &str) -> Result<Self, AlphaDBError> {
+        let version_source = parse_version_source_string(version_source.to_string())?;
+
+        match version_source.get("engine") {
+            Some(engine) => get_json_string(engine)?.parse(),
+            None => Err(AlphaDBError {
+                message: "No engine specified in the version source.".to_string(),
+                error: "no-engine".to_string(),
+                version_trace: VersionTrace::new(),
+                location: None,
+            }),
         }
     }
 }
@@ -38,6 +79,8 @@ impl fmt::Display for AlphaDBEngine {
         let engine = match self {
             AlphaDBEngine::PostgreSQL => "postgres",
             AlphaDBEngine::MySQL => "mysql",
+            AlphaDBEngine::MariaDB => "mariadb",
+            AlphaDBEngine::SQLite => "sqlite",
         };
 
         write!(f, "{engine}")
@@ -51,11 +94,31 @@ impl FromStr for AlphaDBEngine {
         match s {
             "postgres" => Ok(AlphaDBEngine::PostgreSQL),
             "mysql" => Ok(AlphaDBEngine::MySQL),
+            "mariadb" => Ok(AlphaDBEngine::MariaDB),
+            "sqlite" => Ok(AlphaDBEngine::SQLite),
             _ => Err(AlphaDBError {
-                message: format!("\"{s}\" is not a supported AlphaDB engine (expected one of: postgres, mysql)"),
+                message: format!("\"{s}\" is not a supported AlphaDB engine (expected one of: postgres, mysql, mariadb, sqlite)"),
                 error: "unsupported-engine".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             }),
         }
     }
 }
+
+#[cfg(test)]
+mod engine_tests {
+    use super::AlphaDBEngine;
+
+    #[test]
+    fn from_version_source() {
+        let engine = AlphaDBEngine::from_version_source(r#"{"name": "test", "engine": "postgres", "version": []}"#).unwrap();
+        assert_eq!(engine, AlphaDBEngine::PostgreSQL);
+
+        let err = AlphaDBEngine::from_version_source(r#"{"name": "test", "version": []}"#).unwrap_err();
+        assert_eq!(err.error, "no-engine");
+
+        let err = AlphaDBEngine::from_version_source(r#"{"name": "test", "engine": "oracle", "version": []}"#).unwrap_err();
+        assert_eq!(err.error, "unsupported-engine");
+    }
+}
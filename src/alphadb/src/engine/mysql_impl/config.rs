@@ -16,7 +16,8 @@
 use super::methods;
 use crate::{
     core::{
-        method_types::{Init, Status},
+        connect_options::ConnectOptions,
+        method_types::{HistoryEntry, Init, Status},
         runtime_config::{RuntimeConfig, RuntimeHooks},
         update_queries::update_queries,
         utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
@@ -25,8 +26,8 @@ use crate::{
 };
 use mysql::PooledConn;
 
-fn mysql_connect(host: &str, user: &str, password: &str, database: &str, port: u16) -> Result<PooledConn, AlphaDBError> {
-    methods::connect(host, user, password, database, port).map_err(|e| e.into())
+fn mysql_connect(options: &ConnectOptions) -> Result<(PooledConn, String), AlphaDBError> {
+    methods::connect(options).map_err(|e| e.into())
 }
 
 fn mysql_init(db_name: &str, connection: &mut PooledConn) -> Result<Init, AlphaDBError> {
@@ -37,6 +38,10 @@ fn mysql_status(db_name: &str, connection: &mut PooledConn) -> Result<Status, Al
     methods::status(db_name, connection).map_err(|e| e.into())
 }
 
+fn mysql_history(db_name: &str, connection: &mut PooledConn) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+    methods::history(db_name, connection).map_err(|e| e.into())
+}
+
 fn mysql_update(
     db_name: &str,
     connection: &mut PooledConn,
@@ -60,6 +65,7 @@ pub fn mysql_runtime_config() -> RuntimeConfig<PooledConn> {
             connect: mysql_connect,
             init: mysql_init,
             status: mysql_status,
+            history: mysql_history,
             update_queries: |db_name, connection, version_source, target_version, no_data| {
                 update_queries(&MYSQL_UPDATE_QUERIES_CONFIG, db_name, connection, version_source, target_version, no_data)
             },
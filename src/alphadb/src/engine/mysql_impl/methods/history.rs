@@ -0,0 +1,49 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::HistoryEntry;
+use crate::core::utils::globals::HISTORY_TABLE_NAME;
+use crate::engine::mysql_impl::utils::errors::AlphaDBMysqlError;
+use mysql::prelude::*;
+use mysql::*;
+
+/// Get every version applied to the database, oldest first
+///
+/// Returns an empty list if the database has no history table.
+pub fn history(db_name: &str, connection: &mut PooledConn) -> Result<Vec<HistoryEntry>, AlphaDBMysqlError> {
+    let table_check: Option<String> = connection.exec_first(
+        "SELECT table_name FROM information_schema.tables WHERE table_schema = ? AND table_name = ?",
+        (db_name, HISTORY_TABLE_NAME),
+    )?;
+
+    if table_check.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let history = connection.exec_map(
+        format!(
+            "SELECT version, template, DATE_FORMAT(applied_at, '%Y-%m-%d %H:%i:%s') FROM {} WHERE db = ? ORDER BY id",
+            HISTORY_TABLE_NAME
+        ),
+        (db_name,),
+        |(version, template, applied_at): (String, Option<String>, String)| HistoryEntry {
+            version,
+            template,
+            applied_at,
+        },
+    )?;
+
+    Ok(history)
+}
@@ -16,11 +16,12 @@
 use crate::core::method_types::Init;
 use crate::core::utils::globals::CONFIG_TABLE_NAME;
 use crate::engine::mysql_impl::utils::check::check;
+use crate::engine::mysql_impl::utils::history::create_history_table;
 use crate::engine::mysql_impl::utils::errors::AlphaDBMysqlError;
 use mysql::prelude::*;
 use mysql::*;
 
-/// Initialize the database with configuration table
+/// Initialize the database with configuration and history tables
 pub fn init(db_name: &str, connection: &mut PooledConn) -> Result<Init, AlphaDBMysqlError> {
     let checked = check(db_name, connection);
 
@@ -40,5 +41,7 @@ pub fn init(db_name: &str, connection: &mut PooledConn) -> Result<Init, AlphaDBM
 
     connection.exec_drop(format!("INSERT INTO {} (db, version) VALUES (?, ?)", CONFIG_TABLE_NAME), (db_name, "0.0.0"))?;
 
+    create_history_table(connection)?;
+
     return Ok(Init::Success);
 }
@@ -1,13 +1,16 @@
 mod connect;
+mod history;
 mod init;
 mod status;
 mod update;
 mod update_queries;
 mod vacate;
 
-pub use connect::connect;
+pub use connect::{connect, connect_from_pool, connect_with};
+pub use history::history;
 pub use init::init;
 pub use status::status;
 pub use update::update;
+pub(crate) use update::execute_queries;
 pub use update_queries::MYSQL_UPDATE_QUERIES_CONFIG;
 pub use vacate::vacate;
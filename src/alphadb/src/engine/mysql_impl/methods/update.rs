@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::core::method_types::QueryValue;
+use crate::core::method_types::{Query, QueryValue};
 use crate::core::update_queries::update_queries;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::types::ToleratedVerificationIssueLevel;
@@ -29,6 +29,7 @@ fn query_value_to_mysql_value(value: &QueryValue) -> mysql::Value {
         QueryValue::Unsigned(u) => mysql::Value::from(*u),
         QueryValue::Float(f) => mysql::Value::from(*f),
         QueryValue::Bool(b) => mysql::Value::from(*b),
+        QueryValue::Null => mysql::Value::NULL,
     }
 }
 
@@ -42,7 +43,11 @@ pub fn update(
     _tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
 ) -> Result<(), AlphaDBMysqlError> {
     let queries = update_queries(&MYSQL_UPDATE_QUERIES_CONFIG, db_name, connection, version_source, target_version, no_data)?;
+    execute_queries(connection, queries)
+}
 
+/// Execute generated update queries
+pub(crate) fn execute_queries(connection: &mut PooledConn, queries: Vec<Query>) -> Result<(), AlphaDBMysqlError> {
     for query in queries {
         if let Some(data) = query.data {
             let mysql_params: Vec<mysql::Value> = data.iter().map(query_value_to_mysql_value).collect();
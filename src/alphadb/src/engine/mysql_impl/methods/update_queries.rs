@@ -16,23 +16,28 @@
 use crate::core::method_types::{Query, QueryValue, Status};
 use crate::core::update_queries::UpdateQueriesConfig;
 use crate::core::utils::errors::AlphaDBError;
-use crate::core::utils::globals::CONFIG_TABLE_NAME;
+use crate::core::utils::globals::{CONFIG_TABLE_NAME, HISTORY_TABLE_NAME};
 use crate::engine::mysql_impl::methods::status;
 use crate::engine::mysql_impl::query::default_data::default_data;
 use crate::engine::mysql_impl::query::table::config::MYSQL_TABLE_CONFIG;
 use crate::engine::mysql_impl::query::{createindex, dropindex};
+use crate::engine::mysql_impl::utils::history::create_history_table_query;
 use crate::engine::AlphaDBEngine;
 use mysql::*;
 
 pub const MYSQL_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<PooledConn> = UpdateQueriesConfig {
-    engine: AlphaDBEngine::MySQL,
+    engine: Some(AlphaDBEngine::MySQL),
     status: status_hook,
     table_config: &MYSQL_TABLE_CONFIG,
     create_index: createindex,
     drop_index: dropindex,
     default_data,
     config_update_query,
+    history_query,
+    history_table_query: Some(history_table_query),
     version_extras: None,
+    create_table: None,
+    alter_table: None,
 };
 
 fn status_hook(db_name: &str, connection: &mut PooledConn) -> Result<Status, AlphaDBError> {
@@ -49,3 +54,21 @@ fn config_update_query(latest_version: &str, template_name: &str, db_name: &str)
         ])),
     }
 }
+
+fn history_table_query() -> Query {
+    Query {
+        query: create_history_table_query(),
+        data: None,
+    }
+}
+
+fn history_query(version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("INSERT INTO `{HISTORY_TABLE_NAME}` (`db`, `version`, `template`) VALUES (?, ?, ?);"),
+        data: Some(Vec::from([
+            QueryValue::String(db_name.to_string()),
+            QueryValue::String(version.to_string()),
+            QueryValue::String(template_name.to_string()),
+        ])),
+    }
+}
@@ -16,6 +16,7 @@
 use mysql::prelude::*;
 use mysql::*;
 
+use crate::engine::mysql_impl::query::quote;
 use crate::engine::mysql_impl::utils::errors::AlphaDBMysqlError;
 
 /// Remove all tables from the database
@@ -25,7 +26,7 @@ pub fn vacate(connection: &mut PooledConn) -> Result<(), AlphaDBMysqlError> {
     let tables: Vec<String> = connection.query_map("SHOW TABLES", |table: String| table)?;
 
     for table in tables {
-        connection.query_drop(format!("DROP TABLE {}", table))?;
+        connection.query_drop(format!("DROP TABLE {}", quote(&table)))?;
     }
 
     connection.query_drop("SET FOREIGN_KEY_CHECKS = 1")?;
@@ -14,7 +14,7 @@
 
 pub mod config;
 pub mod methods;
-mod query;
+pub(crate) mod query;
 pub mod utils;
 pub mod verification;
 
@@ -16,17 +16,30 @@ use crate::core::query::column::definecolumn::DefineColumn;
 use crate::core::utils::error_messages::{incompatible_column_attributes_err, incomplete_version_object_err, simple_err};
 use crate::core::utils::errors::{AlphaDBError, Get};
 use crate::core::utils::json::{get_json_float, get_json_int, get_json_string, get_json_value_as_string, get_object_keys};
-use crate::core::verification::compatibility::{check_column_attributes_compatibility, check_column_type_compatibility};
+use crate::core::verification::compatibility::{check_column_attributes_compatibility, check_column_type_compatibility, ColumnCompatibilityRule};
 use crate::core::verification::issue::VersionTrace;
 use core::f64;
 use serde_json::Value;
 
+use crate::engine::mysql_impl::query::quote;
 use crate::engine::mysql_impl::verification::compatibility::{ALLOW_DECIMAL_LENGTH, COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, SUPPORTED_COLUMN_TYPES};
 
 /// **Define column**
 ///
 /// Generate a MySQL query part that defines a single column
 pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String, version: &str) -> Result<Option<DefineColumn>, AlphaDBError> {
+    definecolumn_with(column_data, table_name, column_name, version, &SUPPORTED_COLUMN_TYPES, &COLUMN_TYPE_COMPATIBILITY_RULES)
+}
+
+/// Define a column for a MySQL compatible server with its own set of column types
+pub(crate) fn definecolumn_with(
+    column_data: &Value,
+    table_name: &str,
+    column_name: &String,
+    version: &str,
+    supported_column_types: &[&str],
+    type_compatibility_rules: &[ColumnCompatibilityRule],
+) -> Result<Option<DefineColumn>, AlphaDBError> {
     let mut query = DefineColumn::new();
     let column_keys = get_object_keys(column_data);
     let version_trace = VersionTrace::from([version.to_string(), table_name.to_string(), column_name.to_string()]);
@@ -50,8 +63,8 @@ pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String,
             null = true;
         }
 
-        for rule in COLUMN_TYPE_COMPATIBILITY_RULES {
-            if !check_column_type_compatibility(column_type, &rule, &column_keys) {
+        for rule in type_compatibility_rules {
+            if !check_column_type_compatibility(column_type, rule, &column_keys) {
                 return Err(incompatible_column_attributes_err(
                     rule.attribute.to_uppercase().as_str(),
                     format!("type=={column_type}").as_str(),
@@ -116,12 +129,12 @@ pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String,
             }
         }
 
-        if !SUPPORTED_COLUMN_TYPES.contains(&column_type) {
+        if !supported_column_types.contains(&column_type) {
             return Err(simple_err(format!("Column type '{}' is not (yet) supported", column_type).as_str(), version_trace));
         }
 
         query.datatype(column_type);
-        query.name(column_name);
+        query.name(quote(column_name));
 
         if length != -1.0 {
             let length_string = length.to_string();
@@ -216,7 +229,7 @@ mod definecolumn_tests {
         });
         let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
         assert!(q.is_ok());
-        assert_eq!(q.unwrap().unwrap().to_string(), "col VARCHAR NOT NULL DEFAULT 'test'");
+        assert_eq!(q.unwrap().unwrap().to_string(), "`col` VARCHAR NOT NULL DEFAULT 'test'");
     }
 
     // AUTO_INCREMENT with NULL
@@ -3,6 +3,7 @@ use crate::core::utils::error_messages::incomplete_version_object_err;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::json::{get_json_string, get_object_keys};
 use crate::core::verification::issue::VersionTrace;
+use crate::engine::mysql_impl::query::{quote, IDENTIFIER_QUOTE};
 use serde_json::Value;
 
 /// Generate a MySQL CHECK constraint query.
@@ -31,9 +32,9 @@ pub fn create_check_constraint(check: &Value, version_trace: &VersionTrace) -> R
     }
 
     let name = get_json_string(&check["name"])?;
-    let condition = condition_to_sql(&check["condition"])?;
+    let condition = condition_to_sql(&check["condition"], IDENTIFIER_QUOTE)?;
 
-    Ok(format!("CONSTRAINT {name} CHECK ({condition})"))
+    Ok(format!("CONSTRAINT {} CHECK ({condition})", quote(name)))
 }
 
 #[cfg(test)]
@@ -83,6 +84,6 @@ mod createcheckconstraint_tests {
 
         let result = create_check_constraint(&check, &VersionTrace::new()).unwrap();
 
-        assert_eq!(result, "CONSTRAINT events_valid_time CHECK (end_at > start_at)");
+        assert_eq!(result, "CONSTRAINT `events_valid_time` CHECK (`end_at` > `start_at`)");
     }
 }
@@ -14,14 +14,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::core::{method_types::Query, query::default_data::parse_default_data, utils::errors::AlphaDBError};
+use crate::engine::mysql_impl::query::{quote, IDENTIFIER_QUOTE};
 use serde_json::Value;
 
 pub fn default_data(table_name: &str, item: &Value) -> Result<Query, AlphaDBError> {
     let data = parse_default_data(item)?;
 
     let q = format!(
-        "INSERT INTO `{table_name}` ({}) VALUES ({});",
-        data.columns.join(","),
+        "INSERT INTO {} ({}) VALUES ({});",
+        quote(table_name),
+        IDENTIFIER_QUOTE.quote_list(&data.columns),
         data.values.iter().map(|_| "?").collect::<Vec<_>>().join(",")
     );
 
@@ -53,7 +55,7 @@ mod default_data_tests {
         });
 
         let q = default_data("test", &test_item).unwrap();
-        assert_eq!(q.query, "INSERT INTO `test` (col1,col2,col4,col5,col6) VALUES (?,?,?,?,?);");
+        assert_eq!(q.query, "INSERT INTO `test` (`col1`, `col2`, `col4`, `col5`, `col6`) VALUES (?,?,?,?,?);");
         assert_eq!(
             q.data.unwrap(),
             Vec::from([
@@ -2,6 +2,7 @@ use crate::core::utils::error_messages::incomplete_version_object_err;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::json::{array_iter, get_json_boolean, get_json_string, get_object_keys};
 use crate::core::verification::issue::VersionTrace;
+use crate::engine::mysql_impl::query::{quote, IDENTIFIER_QUOTE};
 use serde_json::Value;
 
 /// Generate a MySQL CREATE INDEX query
@@ -61,12 +62,18 @@ pub fn createindex(index: &Value, table_name: &str) -> Result<String, AlphaDBErr
         Some(ref t) => format!(
             "CREATE {}INDEX {} USING {} ON {} ({});",
             if unique { "UNIQUE " } else { "" },
-            name,
+            quote(name),
             t,
-            table_name,
-            columns.join(", ")
+            quote(table_name),
+            IDENTIFIER_QUOTE.quote_list(&columns)
+        ),
+        None => format!(
+            "CREATE {}INDEX {} ON {} ({});",
+            if unique { "UNIQUE " } else { "" },
+            quote(name),
+            quote(table_name),
+            IDENTIFIER_QUOTE.quote_list(&columns)
         ),
-        None => format!("CREATE {}INDEX {} ON {} ({});", if unique { "UNIQUE " } else { "" }, name, table_name, columns.join(", ")),
     };
 
     Ok(sql)
@@ -77,7 +84,7 @@ pub fn createindex(index: &Value, table_name: &str) -> Result<String, AlphaDBErr
 /// `index_name` is the JSON string value holding the index name.
 pub fn dropindex(index_name: &Value, table_name: &str) -> Result<String, AlphaDBError> {
     let name = get_json_string(index_name)?;
-    Ok(format!("DROP INDEX {name} ON {table_name};"))
+    Ok(format!("DROP INDEX {} ON {};", quote(name), quote(table_name)))
 }
 
 #[cfg(test)]
@@ -120,35 +127,35 @@ mod createindex_tests {
     fn basic_index() {
         let index = json!({ "name": "idx_col1", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_col1 ON my_table (col1);");
+        assert_eq!(result, "CREATE INDEX `idx_col1` ON `my_table` (`col1`);");
     }
 
     #[test]
     fn index_with_type() {
         let index = json!({ "name": "idx_col1", "type": "btree", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_col1 USING BTREE ON my_table (col1);");
+        assert_eq!(result, "CREATE INDEX `idx_col1` USING BTREE ON `my_table` (`col1`);");
     }
 
     #[test]
     fn multi_column_index() {
         let index = json!({ "name": "idx_multi", "columns": ["col1", "col2", "col3"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_multi ON my_table (col1, col2, col3);");
+        assert_eq!(result, "CREATE INDEX `idx_multi` ON `my_table` (`col1`, `col2`, `col3`);");
     }
 
     #[test]
     fn unique_index() {
         let index = json!({ "name": "idx_unique", "unique": true, "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE UNIQUE INDEX idx_unique ON my_table (col1);");
+        assert_eq!(result, "CREATE UNIQUE INDEX `idx_unique` ON `my_table` (`col1`);");
     }
 
     #[test]
     fn unique_index_with_type() {
         let index = json!({ "name": "idx_unique_btree", "unique": true, "type": "btree", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE UNIQUE INDEX idx_unique_btree USING BTREE ON my_table (col1);");
+        assert_eq!(result, "CREATE UNIQUE INDEX `idx_unique_btree` USING BTREE ON `my_table` (`col1`);");
     }
 }
 
@@ -160,7 +167,7 @@ mod dropindex_tests {
     #[test]
     fn basic_drop() {
         let result = dropindex(&json!("idx_col1"), "my_table").unwrap();
-        assert_eq!(result, "DROP INDEX idx_col1 ON my_table;");
+        assert_eq!(result, "DROP INDEX `idx_col1` ON `my_table`;");
     }
 
     #[test]
@@ -23,11 +23,12 @@ use crate::core::utils::version_number::parse_version_number;
 use serde_json::{json, Value};
 
 use crate::engine::mysql_impl::query::column::definecolumn::definecolumn;
-use crate::engine::mysql_impl::query::{create_check_constraint, create_foreign_key_constraint};
+use crate::engine::mysql_impl::query::{create_check_constraint, create_foreign_key_constraint, quote, IDENTIFIER_QUOTE};
 
 /// MySQL table query configuration.
 pub const MYSQL_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
     name: "mysql",
+    identifier_quote: IDENTIFIER_QUOTE,
     define_column: definecolumn,
     foreign_key_constraint: create_foreign_key_constraint,
     check_constraint: create_check_constraint,
@@ -37,6 +38,10 @@ pub const MYSQL_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
     add_primary_key,
     drop_foreign_key,
     preprocess: Some(prepare_primary_key_change),
+    rename_column: None,
+    column_if_exists: false,
+    create_options: None,
+    alter_options: None,
 };
 
 fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name: &str, column: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
@@ -53,25 +58,25 @@ fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name
     Ok(definitions)
 }
 
-fn drop_primary_key(_table_name: &str) -> Vec<DefineColumn> {
+pub(crate) fn drop_primary_key(_table_name: &str) -> Vec<DefineColumn> {
     let mut definition = DefineColumn::new();
     definition.method("DROP").name("PRIMARY KEY");
     vec![definition]
 }
 
-fn add_primary_key(_table_name: &str, columns: &str) -> Vec<DefineColumn> {
+pub(crate) fn add_primary_key(_table_name: &str, columns: &str) -> Vec<DefineColumn> {
     let mut definition = DefineColumn::new();
     definition.method("ADD").name(format!("PRIMARY KEY ({columns})"));
     vec![definition]
 }
 
-fn drop_foreign_key(foreign_key_name: &str) -> DefineColumn {
+pub(crate) fn drop_foreign_key(foreign_key_name: &str) -> DefineColumn {
     let mut definition = DefineColumn::new();
-    definition.method("DROP FOREIGN KEY").name(foreign_key_name);
+    definition.method("DROP FOREIGN KEY").name(quote(foreign_key_name));
     definition
 }
 
-fn prepare_primary_key_change(version_list: &Vec<Value>, table_data: &mut Value, table_name: &str, version: &str) -> Result<(), AlphaDBError> {
+pub(crate) fn prepare_primary_key_change(version_list: &Vec<Value>, table_data: &mut Value, table_name: &str, version: &str) -> Result<(), AlphaDBError> {
     if !exists_in_object(&table_data["altertable"][table_name], "primary_key")? {
         return Ok(());
     }
@@ -117,6 +122,8 @@ fn prepare_primary_key_change(version_list: &Vec<Value>, table_data: &mut Value,
 mod createtable_tests {
     use super::MYSQL_TABLE_CONFIG;
     use crate::core::query::table::create_table;
+    use crate::core::utils::column_types::resolve_portable_column_types;
+    use crate::engine::AlphaDBEngine;
     use serde_json::json;
 
     #[test]
@@ -145,7 +152,21 @@ mod createtable_tests {
 
         assert_eq!(
             create_table(&MYSQL_TABLE_CONFIG, json, "table", "0.0.1").unwrap(),
-            "CREATE TABLE table (id INT NOT NULL AUTO_INCREMENT, col1 VARCHAR(30) NOT NULL UNIQUE, PRIMARY KEY (id), CONSTRAINT table_key_fk FOREIGN KEY (key) REFERENCES other_table (key) ON DELETE CASCADE) ENGINE = InnoDB;"
+            "CREATE TABLE `table` (`id` INT NOT NULL AUTO_INCREMENT, `col1` VARCHAR(30) NOT NULL UNIQUE, PRIMARY KEY (`id`), CONSTRAINT `table_key_fk` FOREIGN KEY (`key`) REFERENCES `other_table` (`key`) ON DELETE CASCADE) ENGINE = InnoDB;"
+        );
+    }
+
+    #[test]
+    fn portable_boolean() {
+        let mut version_source = json!({"name": "test", "version": [{"_id": "0.0.1", "createtable": {"table": {
+            "active": {"type": "BOOLEAN", "default": true},
+            "archived": {"type": "boolean", "default": "false"},
+        }}}]});
+        resolve_portable_column_types(&mut version_source, AlphaDBEngine::MySQL);
+
+        assert_eq!(
+            create_table(&MYSQL_TABLE_CONFIG, &version_source["version"][0], "table", "0.0.1").unwrap(),
+            "CREATE TABLE `table` (`active` TINYINT NOT NULL DEFAULT '1', `archived` TINYINT NOT NULL DEFAULT '0') ENGINE = InnoDB;"
         );
     }
 }
@@ -171,7 +192,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP COLUMN col1, DROP COLUMN col2, DROP COLUMN col3;"
+            "ALTER TABLE `table` DROP COLUMN `col1`, DROP COLUMN `col2`, DROP COLUMN `col3`;"
         );
     }
 
@@ -186,7 +207,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table MODIFY COLUMN col INT NOT NULL AUTO_INCREMENT, DROP PRIMARY KEY;"
+            "ALTER TABLE `table` MODIFY COLUMN `col` INT NOT NULL AUTO_INCREMENT, DROP PRIMARY KEY;"
         );
     }
 
@@ -201,7 +222,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table ADD PRIMARY KEY (col);"
+            "ALTER TABLE `table` ADD PRIMARY KEY (`col`);"
         );
     }
 
@@ -216,7 +237,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table MODIFY COLUMN col INT NOT NULL AUTO_INCREMENT, DROP PRIMARY KEY, ADD PRIMARY KEY (other_col);"
+            "ALTER TABLE `table` MODIFY COLUMN `col` INT NOT NULL AUTO_INCREMENT, DROP PRIMARY KEY, ADD PRIMARY KEY (`other_col`);"
         );
     }
 
@@ -231,7 +252,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table ADD PRIMARY KEY (col, other_col);"
+            "ALTER TABLE `table` ADD PRIMARY KEY (`col`, `other_col`);"
         );
     }
 
@@ -250,7 +271,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table ADD CONSTRAINT table_account_fk FOREIGN KEY (account_id) REFERENCES accounts (id);"
+            "ALTER TABLE `table` ADD CONSTRAINT `table_account_fk` FOREIGN KEY (`account_id`) REFERENCES `accounts` (`id`);"
         );
     }
 
@@ -269,7 +290,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP FOREIGN KEY table_account_fk;"
+            "ALTER TABLE `table` DROP FOREIGN KEY `table_account_fk`;"
         );
     }
 
@@ -288,7 +309,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&MYSQL_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP FOREIGN KEY table_account_fk, ADD CONSTRAINT table_account_fk FOREIGN KEY (account_id) REFERENCES accounts (id) ON DELETE CASCADE;"
+            "ALTER TABLE `table` DROP FOREIGN KEY `table_account_fk`, ADD CONSTRAINT `table_account_fk` FOREIGN KEY (`account_id`) REFERENCES `accounts` (`id`) ON DELETE CASCADE;"
         );
     }
 }
@@ -43,6 +43,7 @@ impl From<AlphaDBMysqlError> for AlphaDBError {
             message: err.message(),
             error: err.error(),
             version_trace: err.version_trace().clone(),
+            location: None,
         }
     }
 }
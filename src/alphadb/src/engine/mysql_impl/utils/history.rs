@@ -0,0 +1,41 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::utils::globals::HISTORY_TABLE_NAME;
+use mysql::prelude::*;
+use mysql::PooledConn;
+
+/// Query that creates the history table if it does not exist yet
+///
+/// Databases initialized before the history table was introduced only have
+/// the configuration table, so every update starts with this query.
+pub fn create_history_table_query() -> String {
+    format!(
+        "CREATE TABLE IF NOT EXISTS {} (
+                id INT NOT NULL AUTO_INCREMENT,
+                db VARCHAR(100) NOT NULL,
+                version VARCHAR(50) NOT NULL,
+                template VARCHAR(50) NULL,
+                applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (id)
+            )",
+        HISTORY_TABLE_NAME
+    )
+}
+
+/// Create the history table if it does not exist yet
+pub fn create_history_table(connection: &mut PooledConn) -> Result<(), mysql::Error> {
+    connection.query_drop(create_history_table_query())
+}
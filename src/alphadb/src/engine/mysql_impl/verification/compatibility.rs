@@ -17,8 +17,9 @@ use crate::core::{
     utils::{consolidate::column::get_column_type, errors::AlphaDBError, version_number::parse_version_number},
     verification::{
         compatibility::{check_column_attributes_compatibility, column_contains_type, verify_column_type_compatibility, ColumnCompatibilityRule},
-        issue::{VerificationIssue, VerificationIssueLevel, VersionTrace},
+        issue::{VerificationIssue, VersionTrace},
         json::{get_json_boolean, get_json_string, get_object_keys},
+        rules::{INCOMPATIBLE_ATTRIBUTES, MISSING_COLUMN_TYPE},
     },
 };
 use serde_json::Value;
@@ -61,11 +62,10 @@ pub fn verify_column_compatibility(
     for rule in COLUMN_ATTRIBUTE_COMPATIBILITY_RULES {
         if let Err(incompatible_keys) = check_column_attributes_compatibility(&rule, &data_keys) {
             for key in incompatible_keys {
-                issues.push(VerificationIssue {
-                    level: VerificationIssueLevel::Critical,
-                    message: format!("Column attributes {} and {} are incompatible", rule.attribute.to_uppercase(), key.to_uppercase()),
-                    version_trace: version_trace.clone(),
-                });
+                issues.push(INCOMPATIBLE_ATTRIBUTES.issue(
+                    format!("Column attributes {} and {} are incompatible", rule.attribute.to_uppercase(), key.to_uppercase()),
+                    version_trace.clone(),
+                ));
             }
         }
     }
@@ -105,11 +105,7 @@ pub fn verify_column_compatibility(
             verify_column_type_compatibility(issues, &column_type, &COLUMN_TYPE_COMPATIBILITY_RULES, &data_keys, &version_trace);
         }
     } else {
-        issues.push(VerificationIssue {
-            level: VerificationIssueLevel::Critical,
-            message: format!("Does not contain a column type"),
-            version_trace: version_trace,
-        });
+        issues.push(MISSING_COLUMN_TYPE.issue(format!("Does not contain a column type"), version_trace));
     }
 
     Ok(())
@@ -16,7 +16,8 @@
 use super::methods;
 use crate::{
     core::{
-        method_types::{Init, Status},
+        connect_options::ConnectOptions,
+        method_types::{HistoryEntry, Init, Status},
         runtime_config::{RuntimeConfig, RuntimeHooks},
         update_queries::update_queries,
         utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
@@ -26,8 +27,8 @@ use crate::{
 
 use postgres::Client;
 
-fn postgres_connect(host: &str, user: &str, password: &str, database: &str, port: u16) -> Result<Client, AlphaDBError> {
-    methods::connect(host, user, password, database, port).map_err(|e| e.into())
+fn postgres_connect(options: &ConnectOptions) -> Result<(Client, String), AlphaDBError> {
+    methods::connect(options).map_err(|e| e.into())
 }
 
 fn postgres_init(db_name: &str, connection: &mut Client) -> Result<Init, AlphaDBError> {
@@ -38,6 +39,10 @@ fn postgres_status(db_name: &str, connection: &mut Client) -> Result<Status, Alp
     methods::status(db_name, connection).map_err(|e| e.into())
 }
 
+fn postgres_history(db_name: &str, connection: &mut Client) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+    methods::history(db_name, connection).map_err(|e| e.into())
+}
+
 fn postgres_update(
     db_name: &str,
     connection: &mut Client,
@@ -61,6 +66,7 @@ pub fn postgres_runtime_config() -> RuntimeConfig<Client> {
             connect: postgres_connect,
             init: postgres_init,
             status: postgres_status,
+            history: postgres_history,
             update_queries: |db_name, connection, version_source, target_version, no_data| {
                 update_queries(&POSTGRES_UPDATE_QUERIES_CONFIG, db_name, connection, version_source, target_version, no_data)
             },
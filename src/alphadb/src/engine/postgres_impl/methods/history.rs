@@ -0,0 +1,50 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::HistoryEntry;
+use crate::core::utils::globals::HISTORY_TABLE_NAME;
+use crate::engine::postgres_impl::utils::errors::AlphaDBPostgresError;
+use postgres::Client;
+
+/// Get every version applied to the database, oldest first
+///
+/// Returns an empty list if the database has no history table.
+pub fn history(db_name: &str, connection: &mut Client) -> Result<Vec<HistoryEntry>, AlphaDBPostgresError> {
+    let table_check = connection.query_opt(
+        "SELECT table_name FROM information_schema.tables WHERE table_catalog = $1 AND table_name = $2",
+        &[&db_name, &HISTORY_TABLE_NAME],
+    )?;
+
+    if table_check.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let rows = connection.query(
+        &format!(
+            "SELECT version, template, to_char(applied_at, 'YYYY-MM-DD HH24:MI:SS') FROM {} WHERE db = $1 ORDER BY id",
+            HISTORY_TABLE_NAME
+        ),
+        &[&db_name],
+    )?;
+
+    Ok(rows
+        .iter()
+        .map(|row| HistoryEntry {
+            version: row.get::<_, String>(0),
+            template: row.get::<_, Option<String>>(1),
+            applied_at: row.get::<_, String>(2),
+        })
+        .collect())
+}
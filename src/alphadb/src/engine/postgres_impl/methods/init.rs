@@ -16,10 +16,11 @@
 use crate::core::method_types::Init;
 use crate::core::utils::globals::CONFIG_TABLE_NAME;
 use crate::engine::postgres_impl::utils::check::check;
+use crate::engine::postgres_impl::utils::history::create_history_table;
 use crate::engine::postgres_impl::utils::errors::AlphaDBPostgresError;
 use postgres::Client;
 
-/// Initialize the database with configuration table
+/// Initialize the database with configuration and history tables
 pub fn init(db_name: &str, connection: &mut Client) -> Result<Init, AlphaDBPostgresError> {
     let checked = check(db_name, connection);
 
@@ -42,5 +43,7 @@ pub fn init(db_name: &str, connection: &mut Client) -> Result<Init, AlphaDBPostg
 
     connection.execute(&format!("INSERT INTO {} (db, version) VALUES ($1, $2)", CONFIG_TABLE_NAME), &[&db_name, &"0.0.0"])?;
 
+    create_history_table(connection)?;
+
     Ok(Init::Success)
 }
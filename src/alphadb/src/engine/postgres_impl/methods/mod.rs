@@ -1,4 +1,5 @@
 mod connect;
+mod history;
 mod init;
 mod status;
 mod update;
@@ -6,6 +7,7 @@ mod update_queries;
 mod vacate;
 
 pub use connect::connect;
+pub use history::history;
 pub use init::init;
 pub use status::status;
 pub use update::update;
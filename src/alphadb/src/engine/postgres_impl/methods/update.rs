@@ -18,9 +18,27 @@ use crate::core::update_queries::update_queries;
 use crate::core::utils::types::ToleratedVerificationIssueLevel;
 use crate::engine::postgres_impl::methods::POSTGRES_UPDATE_QUERIES_CONFIG;
 use crate::engine::postgres_impl::utils::errors::AlphaDBPostgresError;
-use postgres::types::ToSql;
+use bytes::BytesMut;
+use postgres::types::{to_sql_checked, IsNull, ToSql, Type};
+use std::error::Error;
 use postgres::Client;
 
+/// `NULL` for a parameter of any type, `Option::None` only accepts the types of its inner type
+#[derive(Debug)]
+struct Null;
+
+impl ToSql for Null {
+    fn to_sql(&self, _ty: &Type, _out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        Ok(IsNull::Yes)
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
 fn query_value_to_postgres_param(value: &QueryValue) -> Box<dyn ToSql + Sync> {
     match value {
         QueryValue::String(s) => Box::new(s.clone()),
@@ -28,6 +46,7 @@ fn query_value_to_postgres_param(value: &QueryValue) -> Box<dyn ToSql + Sync> {
         QueryValue::Unsigned(u) => Box::new(*u as i32),
         QueryValue::Float(f) => Box::new(*f as f32),
         QueryValue::Bool(b) => Box::new(*b),
+        QueryValue::Null => Box::new(Null),
     }
 }
 
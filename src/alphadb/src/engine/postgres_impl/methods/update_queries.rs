@@ -16,7 +16,7 @@
 use crate::core::method_types::{Query, QueryValue, Status};
 use crate::core::update_queries::UpdateQueriesConfig;
 use crate::core::utils::errors::AlphaDBError;
-use crate::core::utils::globals::CONFIG_TABLE_NAME;
+use crate::core::utils::globals::{CONFIG_TABLE_NAME, HISTORY_TABLE_NAME};
 use crate::core::utils::json::{array_iter, get_object_keys};
 use crate::engine::postgres_impl::methods::status;
 use crate::engine::postgres_impl::query::default_data::default_data;
@@ -24,19 +24,24 @@ use crate::engine::postgres_impl::query::table::config::POSTGRES_TABLE_CONFIG;
 use crate::engine::postgres_impl::query::{
     create_extension, createindex, drop_extension, dropindex, update_extension, CreateExtension, DropExtension, FromExtensionValue, UpdateExtension,
 };
+use crate::engine::postgres_impl::utils::history::create_history_table_query;
 use crate::engine::AlphaDBEngine;
 use postgres::Client;
 use serde_json::Value;
 
 pub const POSTGRES_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<Client> = UpdateQueriesConfig {
-    engine: AlphaDBEngine::PostgreSQL,
+    engine: Some(AlphaDBEngine::PostgreSQL),
     status: status_hook,
     table_config: &POSTGRES_TABLE_CONFIG,
     create_index: createindex,
     drop_index,
     default_data,
     config_update_query,
+    history_query,
+    history_table_query: Some(history_table_query),
     version_extras: Some(version_extras),
+    create_table: None,
+    alter_table: None,
 };
 
 fn status_hook(db_name: &str, connection: &mut Client) -> Result<Status, AlphaDBError> {
@@ -95,3 +100,21 @@ fn config_update_query(latest_version: &str, template_name: &str, db_name: &str)
         ])),
     }
 }
+
+fn history_table_query() -> Query {
+    Query {
+        query: create_history_table_query(),
+        data: None,
+    }
+}
+
+fn history_query(version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("INSERT INTO {HISTORY_TABLE_NAME} (db, version, template) VALUES ($1, $2, $3);"),
+        data: Some(Vec::from([
+            QueryValue::String(db_name.to_string()),
+            QueryValue::String(version.to_string()),
+            QueryValue::String(template_name.to_string()),
+        ])),
+    }
+}
@@ -15,6 +15,7 @@
 
 use postgres::Client;
 
+use crate::engine::postgres_impl::query::quote;
 use crate::engine::postgres_impl::utils::errors::AlphaDBPostgresError;
 
 /// Remove all tables from the database
@@ -25,7 +26,7 @@ pub fn vacate(connection: &mut Client) -> Result<(), AlphaDBPostgresError> {
 
     // Drop all tables with CASCADE to handle foreign key constraints
     for table in tables {
-        connection.execute(&format!("DROP TABLE IF EXISTS {} CASCADE", table), &[])?;
+        connection.execute(&format!("DROP TABLE IF EXISTS {} CASCADE", quote(&table)), &[])?;
     }
 
     Ok(())
@@ -21,6 +21,7 @@ use crate::core::verification::issue::VersionTrace;
 use core::f64;
 use serde_json::Value;
 
+use crate::engine::postgres_impl::query::quote;
 use crate::engine::postgres_impl::verification::compatibility::{
     ALLOW_DECIMAL_LENGTH, COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, NO_LENGTH_COLUMN_TYPES, SUPPORTED_COLUMN_TYPES,
 };
@@ -121,20 +122,20 @@ pub fn altercolumn(column_data: &Value, table_name: &str, column_name: &String,
 
         let mut type_stmt = DefineColumn::new();
         type_stmt.method("ALTER COLUMN");
-        type_stmt.name(column_name);
+        type_stmt.name(quote(column_name));
         type_stmt.constraint(type_constraint);
         statements.push(type_stmt);
 
         if null {
             let mut null_stmt = DefineColumn::new();
             null_stmt.method("ALTER COLUMN");
-            null_stmt.name(column_name);
+            null_stmt.name(quote(column_name));
             null_stmt.constraint("DROP NOT NULL");
             statements.push(null_stmt);
         } else {
             let mut null_stmt = DefineColumn::new();
             null_stmt.method("ALTER COLUMN");
-            null_stmt.name(column_name);
+            null_stmt.name(quote(column_name));
             null_stmt.constraint("SET NOT NULL");
             statements.push(null_stmt);
         }
@@ -154,7 +155,7 @@ pub fn altercolumn(column_data: &Value, table_name: &str, column_name: &String,
 
             let mut default_stmt = DefineColumn::new();
             default_stmt.method("ALTER COLUMN");
-            default_stmt.name(column_name);
+            default_stmt.name(quote(column_name));
             default_stmt.constraint(format!("SET DEFAULT {}", default_value));
             statements.push(default_stmt);
         }
@@ -162,14 +163,14 @@ pub fn altercolumn(column_data: &Value, table_name: &str, column_name: &String,
         if unique {
             let mut unique_stmt = DefineColumn::new();
             unique_stmt.method("ADD");
-            unique_stmt.constraint(format!("UNIQUE ({})", column_name));
+            unique_stmt.constraint(format!("UNIQUE ({})", quote(column_name)));
             statements.push(unique_stmt);
         }
 
         if let Some(generated) = generated {
             let mut generated_stmt = DefineColumn::new();
             generated_stmt.method("ALTER COLUMN");
-            generated_stmt.name(column_name);
+            generated_stmt.name(quote(column_name));
             generated_stmt.constraint(format!("ADD GENERATED {} AS IDENTITY", generated));
             statements.push(generated_stmt);
         }
@@ -226,9 +227,9 @@ mod altercolumn_tests {
         assert!(q.is_ok());
         let statements = q.unwrap();
         assert_eq!(statements.len(), 3);
-        assert_eq!(statements[0].to_string(), "ALTER COLUMN col TYPE VARCHAR");
-        assert_eq!(statements[1].to_string(), "ALTER COLUMN col SET NOT NULL");
-        assert_eq!(statements[2].to_string(), "ALTER COLUMN col SET DEFAULT 'TEST'");
+        assert_eq!(statements[0].to_string(), "ALTER COLUMN \"col\" TYPE VARCHAR");
+        assert_eq!(statements[1].to_string(), "ALTER COLUMN \"col\" SET NOT NULL");
+        assert_eq!(statements[2].to_string(), "ALTER COLUMN \"col\" SET DEFAULT 'TEST'");
     }
 
     #[test]
@@ -21,6 +21,7 @@ use crate::core::verification::issue::VersionTrace;
 use core::f64;
 use serde_json::Value;
 
+use crate::engine::postgres_impl::query::quote;
 use crate::engine::postgres_impl::verification::compatibility::{
     ALLOW_DECIMAL_LENGTH, COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, NO_LENGTH_COLUMN_TYPES, SUPPORTED_COLUMN_TYPES,
 };
@@ -129,7 +130,7 @@ pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String,
         }
 
         query.datatype(column_type);
-        query.name(column_name);
+        query.name(quote(column_name));
 
         if length != -1.0 {
             let length_string = length.to_string();
@@ -225,7 +226,7 @@ mod definecolumn_tests {
         });
         let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
         assert!(q.is_ok());
-        assert_eq!(q.unwrap().unwrap().to_string(), "col VARCHAR NOT NULL DEFAULT 'test'");
+        assert_eq!(q.unwrap().unwrap().to_string(), "\"col\" VARCHAR NOT NULL DEFAULT 'test'");
     }
 
     // AUTO_INCREMENT with NULL
@@ -2,6 +2,7 @@ use crate::core::utils::error_messages::incomplete_version_object_err;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::json::get_json_string;
 use crate::core::verification::issue::VersionTrace;
+use crate::engine::postgres_impl::query::quote;
 use serde_json::Value;
 
 /// Generate PostgreSQL FOREIGN KEY table constraints.
@@ -22,6 +23,7 @@ pub fn create_foreign_key_constraint(foreign_key_value: &Value, version_trace: &
         message: "foreign_key items must be objects".to_string(),
         error: "invalid-structure".to_string(),
         version_trace: version_trace.clone(),
+        location: None,
     })?;
 
     let foreign_key_keys = foreign_key.keys().collect::<Vec<&String>>();
@@ -45,10 +47,10 @@ pub fn create_foreign_key_constraint(foreign_key_value: &Value, version_trace: &
 
     let mut foreign_key_string = format!(
         "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
-        get_json_string(&foreign_key_value["name"])?,
-        get_json_string(&foreign_key_value["from"])?,
-        get_json_string(&foreign_key_value["references"])?,
-        get_json_string(&foreign_key_value["to"])?
+        quote(get_json_string(&foreign_key_value["name"])?),
+        quote(get_json_string(&foreign_key_value["from"])?),
+        quote(get_json_string(&foreign_key_value["references"])?),
+        quote(get_json_string(&foreign_key_value["to"])?)
     );
 
     if foreign_key_keys.iter().any(|&i| i == "on_delete") {
@@ -119,7 +121,7 @@ mod createforeignkeyconstraint_tests {
 
         assert_eq!(
             result,
-            "CONSTRAINT table_key_fk FOREIGN KEY (key) REFERENCES other_table (key) ON DELETE CASCADE ON UPDATE RESTRICT"
+            "CONSTRAINT \"table_key_fk\" FOREIGN KEY (\"key\") REFERENCES \"other_table\" (\"key\") ON DELETE CASCADE ON UPDATE RESTRICT"
         );
     }
 }
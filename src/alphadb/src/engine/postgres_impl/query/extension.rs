@@ -1,4 +1,5 @@
 use crate::core::utils::errors::AlphaDBError;
+use crate::engine::postgres_impl::query::quote;
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -107,19 +108,19 @@ fn parse_extension(value: &Value) -> Result<ExtensionOptions, AlphaDBError> {
 
 /// Builds a `CREATE EXTENSION IF NOT EXISTS` query for the given extension.
 pub fn create_extension(extension: &CreateExtension) -> String {
-    format!("CREATE EXTENSION IF NOT EXISTS {};", extension.name)
+    format!("CREATE EXTENSION IF NOT EXISTS {};", quote(&extension.name))
 }
 
 /// Builds a `DROP EXTENSION IF EXISTS` query for the given extension.
 pub fn drop_extension(extension: &DropExtension) -> String {
-    format!("DROP EXTENSION IF EXISTS {}{};", extension.name, if extension.cascade { " CASCADE" } else { "" })
+    format!("DROP EXTENSION IF EXISTS {}{};", quote(&extension.name), if extension.cascade { " CASCADE" } else { "" })
 }
 
 /// Builds an `ALTER EXTENSION ... UPDATE` query for the given extension.
 pub fn update_extension(extension: &UpdateExtension) -> String {
     match &extension.version {
-        Some(version) => format!("ALTER EXTENSION {} UPDATE TO '{version}';", extension.name),
-        None => format!("ALTER EXTENSION {} UPDATE;", extension.name),
+        Some(version) => format!("ALTER EXTENSION {} UPDATE TO '{version}';", quote(&extension.name)),
+        None => format!("ALTER EXTENSION {} UPDATE;", quote(&extension.name)),
     }
 }
 
@@ -132,41 +133,41 @@ mod create_extension_tests {
     fn btree_gist_extension() {
         let extension = CreateExtension::from_json(&json!("btree_gist")).unwrap();
         let result = create_extension(&extension);
-        assert_eq!(result, "CREATE EXTENSION IF NOT EXISTS btree_gist;");
+        assert_eq!(result, "CREATE EXTENSION IF NOT EXISTS \"btree_gist\";");
     }
 
     #[test]
     fn pgcrypto_extension() {
         let extension = CreateExtension::from_json(&json!("pgcrypto")).unwrap();
         let result = create_extension(&extension);
-        assert_eq!(result, "CREATE EXTENSION IF NOT EXISTS pgcrypto;");
+        assert_eq!(result, "CREATE EXTENSION IF NOT EXISTS \"pgcrypto\";");
     }
 
     #[test]
     fn drop_extension_query() {
         let extension = DropExtension::from_json(&json!("btree_gist")).unwrap();
         let result = drop_extension(&extension);
-        assert_eq!(result, "DROP EXTENSION IF EXISTS btree_gist;");
+        assert_eq!(result, "DROP EXTENSION IF EXISTS \"btree_gist\";");
     }
 
     #[test]
     fn drop_extension_cascade_query() {
         let extension = DropExtension::from_json(&json!({ "name": "btree_gist", "cascade": true })).unwrap();
         let result = drop_extension(&extension);
-        assert_eq!(result, "DROP EXTENSION IF EXISTS btree_gist CASCADE;");
+        assert_eq!(result, "DROP EXTENSION IF EXISTS \"btree_gist\" CASCADE;");
     }
 
     #[test]
     fn update_extension_query() {
         let extension = UpdateExtension::from_json(&json!("pgcrypto")).unwrap();
         let result = update_extension(&extension);
-        assert_eq!(result, "ALTER EXTENSION pgcrypto UPDATE;");
+        assert_eq!(result, "ALTER EXTENSION \"pgcrypto\" UPDATE;");
     }
 
     #[test]
     fn update_extension_to_version_query() {
         let extension = UpdateExtension::from_json(&json!({ "name": "btree_gist", "version": "1.7" })).unwrap();
         let result = update_extension(&extension);
-        assert_eq!(result, "ALTER EXTENSION btree_gist UPDATE TO '1.7';");
+        assert_eq!(result, "ALTER EXTENSION \"btree_gist\" UPDATE TO '1.7';");
     }
 }
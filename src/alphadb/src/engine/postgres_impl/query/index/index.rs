@@ -2,6 +2,7 @@ use crate::core::utils::error_messages::incomplete_version_object_err;
 use crate::core::utils::errors::AlphaDBError;
 use crate::core::utils::json::{array_iter, get_json_boolean, get_json_string, get_object_keys};
 use crate::core::verification::issue::VersionTrace;
+use crate::engine::postgres_impl::query::{quote, IDENTIFIER_QUOTE};
 use serde_json::Value;
 
 use crate::core::utils::condition_to_sql;
@@ -55,16 +56,22 @@ pub fn createindex(index: &Value, table_name: &str) -> Result<String, AlphaDBErr
         Some(ref t) => format!(
             "CREATE {}INDEX {} ON {} USING {} ({})",
             if unique { "UNIQUE " } else { "" },
-            name,
-            table_name,
+            quote(name),
+            quote(table_name),
             t,
-            columns.join(", ")
+            IDENTIFIER_QUOTE.quote_list(&columns)
+        ),
+        None => format!(
+            "CREATE {}INDEX {} ON {} ({})",
+            if unique { "UNIQUE " } else { "" },
+            quote(name),
+            quote(table_name),
+            IDENTIFIER_QUOTE.quote_list(&columns)
         ),
-        None => format!("CREATE {}INDEX {} ON {} ({})", if unique { "UNIQUE " } else { "" }, name, table_name, columns.join(", ")),
     };
 
     if keys.iter().any(|k| *k == "condition") {
-        let where_clause = condition_to_sql(&index["condition"])?;
+        let where_clause = condition_to_sql(&index["condition"], IDENTIFIER_QUOTE)?;
         sql = format!("{sql} WHERE {where_clause}");
     }
 
@@ -80,7 +87,7 @@ pub fn createindex(index: &Value, table_name: &str) -> Result<String, AlphaDBErr
 /// qualifier).
 pub fn dropindex(index_name: &Value) -> Result<String, AlphaDBError> {
     let name = get_json_string(index_name)?;
-    Ok(format!("DROP INDEX {name};"))
+    Ok(format!("DROP INDEX {};", quote(name)))
 }
 
 #[cfg(test)]
@@ -115,21 +122,21 @@ mod createindex_tests {
     fn basic_index() {
         let index = json!({ "name": "idx_col1", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_col1 ON my_table (col1);");
+        assert_eq!(result, "CREATE INDEX \"idx_col1\" ON \"my_table\" (\"col1\");");
     }
 
     #[test]
     fn index_with_type() {
         let index = json!({ "name": "idx_col1", "type": "btree", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_col1 ON my_table USING BTREE (col1);");
+        assert_eq!(result, "CREATE INDEX \"idx_col1\" ON \"my_table\" USING BTREE (\"col1\");");
     }
 
     #[test]
     fn multi_column_index() {
         let index = json!({ "name": "idx_multi", "columns": ["col1", "col2", "col3"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX idx_multi ON my_table (col1, col2, col3);");
+        assert_eq!(result, "CREATE INDEX \"idx_multi\" ON \"my_table\" (\"col1\", \"col2\", \"col3\");");
     }
 
     #[test]
@@ -151,21 +158,21 @@ mod createindex_tests {
             }
         });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE INDEX test_index ON my_table USING BTREE (col3) WHERE (status = 'pending');");
+        assert_eq!(result, "CREATE INDEX \"test_index\" ON \"my_table\" USING BTREE (\"col3\") WHERE (\"status\" = 'pending');");
     }
 
     #[test]
     fn unique_index() {
         let index = json!({ "name": "idx_unique", "unique": true, "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE UNIQUE INDEX idx_unique ON my_table (col1);");
+        assert_eq!(result, "CREATE UNIQUE INDEX \"idx_unique\" ON \"my_table\" (\"col1\");");
     }
 
     #[test]
     fn unique_index_with_type() {
         let index = json!({ "name": "idx_unique_btree", "unique": true, "type": "btree", "columns": ["col1"] });
         let result = createindex(&index, "my_table").unwrap();
-        assert_eq!(result, "CREATE UNIQUE INDEX idx_unique_btree ON my_table USING BTREE (col1);");
+        assert_eq!(result, "CREATE UNIQUE INDEX \"idx_unique_btree\" ON \"my_table\" USING BTREE (\"col1\");");
     }
 }
 
@@ -177,7 +184,7 @@ mod dropindex_tests {
     #[test]
     fn basic_drop() {
         let result = dropindex(&json!("idx_col1")).unwrap();
-        assert_eq!(result, "DROP INDEX idx_col1;");
+        assert_eq!(result, "DROP INDEX \"idx_col1\";");
     }
 
     #[test]
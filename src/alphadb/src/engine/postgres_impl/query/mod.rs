@@ -1,3 +1,5 @@
+use crate::core::query::identifier::IdentifierQuote;
+
 pub mod column;
 mod constraint;
 pub mod default_data;
@@ -9,3 +11,11 @@ pub use constraint::check::create_check_constraint;
 pub use constraint::foreign_key::create_foreign_key_constraint;
 pub use extension::{create_extension, drop_extension, update_extension, CreateExtension, DropExtension, FromExtensionValue, UpdateExtension};
 pub use index::index::{createindex, dropindex};
+
+/// How PostgreSQL quotes identifiers in generated queries
+pub(crate) const IDENTIFIER_QUOTE: IdentifierQuote = IdentifierQuote::LowercaseDoubleQuote;
+
+/// Quote a table, column, index or constraint name
+pub(crate) fn quote(identifier: &str) -> String {
+    IDENTIFIER_QUOTE.quote(identifier)
+}
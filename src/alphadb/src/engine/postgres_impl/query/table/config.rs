@@ -23,11 +23,12 @@ use serde_json::Value;
 
 use crate::engine::postgres_impl::query::column::altercolumn::altercolumn;
 use crate::engine::postgres_impl::query::column::definecolumn::definecolumn;
-use crate::engine::postgres_impl::query::{create_check_constraint, create_foreign_key_constraint};
+use crate::engine::postgres_impl::query::{create_check_constraint, create_foreign_key_constraint, quote, IDENTIFIER_QUOTE};
 
 /// PostgreSQL table query configuration.
 pub const POSTGRES_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
     name: "postgres",
+    identifier_quote: IDENTIFIER_QUOTE,
     define_column: definecolumn,
     foreign_key_constraint: create_foreign_key_constraint,
     check_constraint: create_check_constraint,
@@ -37,6 +38,10 @@ pub const POSTGRES_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
     add_primary_key,
     drop_foreign_key,
     preprocess: None,
+    rename_column: None,
+    column_if_exists: false,
+    create_options: None,
+    alter_options: None,
 };
 
 fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name: &str, column: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
@@ -54,6 +59,7 @@ fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name
                 message: "Cannot modify a column without knowing it's type, and this column has no type defined".to_string(),
                 error: "column-has-no-type".to_string(),
                 version_trace,
+                location: None,
             })
         }
     };
@@ -63,7 +69,7 @@ fn modify_column(version_list: &Vec<Value>, modify_entry: &mut Value, table_name
 
 fn drop_primary_key(table_name: &str) -> Vec<DefineColumn> {
     let mut definition = DefineColumn::new();
-    definition.method("DROP CONSTRAINT").name(format!("{}_pkey", table_name));
+    definition.method("DROP CONSTRAINT").name(quote(&format!("{}_pkey", table_name)));
     vec![definition]
 }
 
@@ -75,7 +81,7 @@ fn add_primary_key(_table_name: &str, columns: &str) -> Vec<DefineColumn> {
 
 fn drop_foreign_key(foreign_key_name: &str) -> DefineColumn {
     let mut definition = DefineColumn::new();
-    definition.method("DROP CONSTRAINT").name(foreign_key_name);
+    definition.method("DROP CONSTRAINT").name(quote(foreign_key_name));
     definition
 }
 
@@ -111,7 +117,7 @@ mod createtable_tests {
 
         assert_eq!(
             create_table(&POSTGRES_TABLE_CONFIG, json, "table", "0.0.1").unwrap(),
-            "CREATE TABLE table (id INTEGER NOT NULL, col1 VARCHAR(30) NOT NULL UNIQUE, PRIMARY KEY (id), CONSTRAINT table_key_fk FOREIGN KEY (key) REFERENCES other_table (key) ON DELETE CASCADE);"
+            "CREATE TABLE \"table\" (\"id\" INTEGER NOT NULL, \"col1\" VARCHAR(30) NOT NULL UNIQUE, PRIMARY KEY (\"id\"), CONSTRAINT \"table_key_fk\" FOREIGN KEY (\"key\") REFERENCES \"other_table\" (\"key\") ON DELETE CASCADE);"
         );
     }
 }
@@ -137,7 +143,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP COLUMN col1, DROP COLUMN col2, DROP COLUMN col3;"
+            "ALTER TABLE \"table\" DROP COLUMN \"col1\", DROP COLUMN \"col2\", DROP COLUMN \"col3\";"
         );
     }
 
@@ -152,7 +158,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table DROP CONSTRAINT table_pkey;"
+            "ALTER TABLE \"table\" DROP CONSTRAINT \"table_pkey\";"
         );
     }
 
@@ -167,7 +173,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table ADD PRIMARY KEY (col);"
+            "ALTER TABLE \"table\" ADD PRIMARY KEY (\"col\");"
         );
     }
 
@@ -182,7 +188,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table DROP CONSTRAINT table_pkey, ADD PRIMARY KEY (other_col);"
+            "ALTER TABLE \"table\" DROP CONSTRAINT \"table_pkey\", ADD PRIMARY KEY (\"other_col\");"
         );
     }
 
@@ -197,7 +203,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.2").unwrap(),
-            "ALTER TABLE table ADD PRIMARY KEY (col, other_col);"
+            "ALTER TABLE \"table\" ADD PRIMARY KEY (\"col\", \"other_col\");"
         );
     }
 
@@ -216,7 +222,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table ADD CONSTRAINT table_account_fk FOREIGN KEY (account_id) REFERENCES accounts (id);"
+            "ALTER TABLE \"table\" ADD CONSTRAINT \"table_account_fk\" FOREIGN KEY (\"account_id\") REFERENCES \"accounts\" (\"id\");"
         );
     }
 
@@ -235,7 +241,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP CONSTRAINT table_account_fk;"
+            "ALTER TABLE \"table\" DROP CONSTRAINT \"table_account_fk\";"
         );
     }
 
@@ -254,7 +260,7 @@ mod altertable_tests {
         });
         assert_eq!(
             alter_table(&POSTGRES_TABLE_CONFIG, column, "table", "0.0.1").unwrap(),
-            "ALTER TABLE table DROP CONSTRAINT table_account_fk, ADD CONSTRAINT table_account_fk FOREIGN KEY (account_id) REFERENCES accounts (id) ON DELETE CASCADE;"
+            "ALTER TABLE \"table\" DROP CONSTRAINT \"table_account_fk\", ADD CONSTRAINT \"table_account_fk\" FOREIGN KEY (\"account_id\") REFERENCES \"accounts\" (\"id\") ON DELETE CASCADE;"
         );
     }
 }
@@ -1,3 +1,5 @@
 pub mod check;
 pub mod connection;
 pub mod errors;
+pub mod history;
+pub mod tls;
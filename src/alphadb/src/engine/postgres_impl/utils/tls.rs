@@ -0,0 +1,212 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! TLS connectors for PostgreSQL. The backend is picked by the `native-tls` or `rustls`
+//! feature, without either only `TlsMode::Prefer` connects (falling back to plain TCP).
+//! `mode` is the resolved mode, `tls.mode` may be unset when the URL sets `sslmode`.
+
+use postgres::{Client, Config};
+use std::path::Path;
+
+use crate::core::connect_options::{TlsMode, TlsOptions};
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::postgres_impl::utils::errors::AlphaDBPostgresError;
+
+fn tls_error(message: String) -> AlphaDBPostgresError {
+    AlphaDBError {
+        message,
+        error: "invalid-tls-config".to_string(),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[allow(dead_code)]
+fn read_file(path: &Path) -> Result<Vec<u8>, AlphaDBPostgresError> {
+    std::fs::read(path).map_err(|e| tls_error(format!("Unable to read '{}': {e}", path.display())))
+}
+
+#[cfg(feature = "native-tls")]
+pub fn connect(config: &Config, mode: TlsMode, tls: &TlsOptions) -> Result<Client, AlphaDBPostgresError> {
+    use crate::core::connect_options::ClientCertificate;
+    use native_tls::{Certificate, Identity, TlsConnector};
+
+    let mut builder = TlsConnector::builder();
+
+    match mode {
+        TlsMode::Prefer | TlsMode::Require => builder.danger_accept_invalid_certs(true),
+        TlsMode::VerifyCa => builder.danger_accept_invalid_hostnames(true),
+        TlsMode::Disable | TlsMode::VerifyFull => &mut builder,
+    };
+
+    if let Some(ca_cert) = &tls.ca_cert {
+        let certificate = Certificate::from_pem(&read_file(ca_cert)?).map_err(|e| tls_error(format!("Invalid CA certificate: {e}")))?;
+        builder.add_root_certificate(certificate);
+    }
+
+    let identity = match &tls.client_cert {
+        Some(ClientCertificate::Pem { cert, key }) => Some(Identity::from_pkcs8(&read_file(cert)?, &read_file(key)?)),
+        Some(ClientCertificate::Pkcs12 { path, password }) => Some(Identity::from_pkcs12(&read_file(path)?, password.as_deref().unwrap_or(""))),
+        None => None,
+    };
+    if let Some(identity) = identity {
+        builder.identity(identity.map_err(|e| tls_error(format!("Invalid client certificate: {e}")))?);
+    }
+
+    let connector = builder.build().map_err(|e| tls_error(format!("Unable to set up TLS: {e}")))?;
+
+    Ok(config.connect(postgres_native_tls::MakeTlsConnector::new(connector))?)
+}
+
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+pub fn connect(config: &Config, mode: TlsMode, tls: &TlsOptions) -> Result<Client, AlphaDBPostgresError> {
+    use crate::core::connect_options::ClientCertificate;
+    use rustls::client::danger::ServerCertVerifier;
+    use rustls::client::WebPkiServerVerifier;
+    use rustls::{ClientConfig, RootCertStore};
+    use std::sync::Arc;
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let mut roots = RootCertStore::empty();
+    match &tls.ca_cert {
+        Some(ca_cert) => {
+            for certificate in rustls_pemfile::certs(&mut read_file(ca_cert)?.as_slice()) {
+                let certificate = certificate.map_err(|e| tls_error(format!("Invalid CA certificate: {e}")))?;
+                roots.add(certificate).map_err(|e| tls_error(format!("Invalid CA certificate: {e}")))?;
+            }
+        }
+        None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+    }
+
+    let verifier: Arc<dyn ServerCertVerifier> = match mode {
+        TlsMode::VerifyFull | TlsMode::VerifyCa => {
+            let webpki = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+                .build()
+                .map_err(|e| tls_error(format!("Unable to set up TLS: {e}")))?;
+
+            match mode {
+                TlsMode::VerifyCa => Arc::new(verifiers::SkipHostname(webpki)),
+                _ => webpki,
+            }
+        }
+        TlsMode::Disable | TlsMode::Prefer | TlsMode::Require => Arc::new(verifiers::AcceptAny(provider.clone())),
+    };
+
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| tls_error(format!("Unable to set up TLS: {e}")))?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier);
+
+    let client_config = match &tls.client_cert {
+        Some(ClientCertificate::Pem { cert, key }) => {
+            let certs = rustls_pemfile::certs(&mut read_file(cert)?.as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| tls_error(format!("Invalid client certificate: {e}")))?;
+            let key = match rustls_pemfile::private_key(&mut read_file(key)?.as_slice()) {
+                Ok(Some(key)) => key,
+                Ok(None) => return Err(tls_error(format!("No private key found in '{}'", key.display()))),
+                Err(e) => return Err(tls_error(format!("Invalid client key: {e}"))),
+            };
+
+            builder.with_client_auth_cert(certs, key).map_err(|e| tls_error(format!("Invalid client certificate: {e}")))?
+        }
+        Some(ClientCertificate::Pkcs12 { .. }) => {
+            return Err(tls_error("PKCS#12 client certificates require the native-tls feature".to_string()));
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    Ok(config.connect(tokio_postgres_rustls::MakeRustlsConnect::new(client_config))?)
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub fn connect(config: &Config, mode: TlsMode, _tls: &TlsOptions) -> Result<Client, AlphaDBPostgresError> {
+    match mode {
+        TlsMode::Prefer => Ok(config.connect(postgres::NoTls)?),
+        _ => Err(AlphaDBError {
+            message: "TLS connections to PostgreSQL require the native-tls or rustls feature".to_string(),
+            error: "unsupported-feature".to_string(),
+            ..Default::default()
+        }
+        .into()),
+    }
+}
+
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+mod verifiers {
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::client::WebPkiServerVerifier;
+    use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+    use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+    use rustls::{CertificateError, DigitallySignedStruct, Error, SignatureScheme};
+    use std::sync::Arc;
+
+    /// Accepts any certificate, for `TlsMode::Prefer` and `TlsMode::Require`.
+    /// Handshake signatures are still checked.
+    #[derive(Debug)]
+    pub struct AcceptAny(pub Arc<CryptoProvider>);
+
+    impl ServerCertVerifier for AcceptAny {
+        fn verify_server_cert(&self, _: &CertificateDer<'_>, _: &[CertificateDer<'_>], _: &ServerName<'_>, _: &[u8], _: UnixTime) -> Result<ServerCertVerified, Error> {
+            Ok(ServerCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+            verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+        }
+
+        fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+            verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.0.signature_verification_algorithms.supported_schemes()
+        }
+    }
+
+    /// Verifies the certificate chain but accepts any host name, for `TlsMode::VerifyCa`
+    #[derive(Debug)]
+    pub struct SkipHostname(pub Arc<WebPkiServerVerifier>);
+
+    impl ServerCertVerifier for SkipHostname {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, Error> {
+            match self.0.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now) {
+                Err(Error::InvalidCertificate(CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. })) => Ok(ServerCertVerified::assertion()),
+                result => result,
+            }
+        }
+
+        fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+            self.0.verify_tls12_signature(message, cert, dss)
+        }
+
+        fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+            self.0.verify_tls13_signature(message, cert, dss)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.0.supported_verify_schemes()
+        }
+    }
+}
@@ -14,9 +14,115 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::core::{
-    engine_config::{EngineConfig, VerificationHooks},
+    engine_config::{EngineConfig, IndexNameScope, VerificationHooks},
     verification::compatibility::ColumnCompatibilityRule,
 };
+use crate::engine::postgres_impl::verification::compatibility::SUPPORTED_COLUMN_TYPES;
+
+/// Reserved key words of PostgreSQL, see <https://www.postgresql.org/docs/current/sql-keywords-appendix.html>
+pub const RESERVED_WORDS: [&str; 101] = [
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLLATION",
+    "COLUMN",
+    "CONCURRENTLY",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FREEZE",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INITIALLY",
+    "INNER",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LATERAL",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NATURAL",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVERLAPS",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SESSION_USER",
+    "SIMILAR",
+    "SOME",
+    "SYMMETRIC",
+    "SYSTEM_USER",
+    "TABLE",
+    "TABLESAMPLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "VERBOSE",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
 
 /// PostgreSQL engine configuration for verification
 pub const POSTGRES_CONFIG: EngineConfig = EngineConfig {
@@ -26,29 +132,15 @@ pub const POSTGRES_CONFIG: EngineConfig = EngineConfig {
     int_columns: &["INTEGER", "SMALLINT", "BIGINT", "SERIAL", "BIGSERIAL"],
     float_columns: &["REAL", "DOUBLE PRECISION", "NUMERIC"],
 
-    supported_column_types: &[
-        "SERIAL",
-        "BIGSERIAL",
-        "INTEGER",
-        "SMALLINT",
-        "BIGINT",
-        "TEXT",
-        "VARCHAR",
-        "CHAR",
-        "REAL",
-        "DOUBLE PRECISION",
-        "NUMERIC",
-        "TIMESTAMP",
-        "TIMESTAMPTZ",
-        "DATE",
-        "TIME",
-        "INTERVAL",
-        "JSONB",
-        "JSON",
-        "BOOLEAN",
-        "UUID",
-        "BYTEA",
-    ],
+    supported_column_types: &SUPPORTED_COLUMN_TYPES,
+
+    version_methods: &["createextension", "dropextension", "alterextension"],
+
+    index_types: &["btree", "hash", "gist", "spgist", "gin", "brin"],
+    partial_indexes: true,
+    index_name_scope: IndexNameScope::Schema,
+    reserved_words: &RESERVED_WORDS,
+    max_identifier_length: Some(63),
 
     type_compatibility_rules: &[
         ColumnCompatibilityRule {
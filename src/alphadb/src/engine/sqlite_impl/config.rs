@@ -0,0 +1,76 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::methods;
+use crate::{
+    core::{
+        connect_options::ConnectOptions,
+        method_types::{HistoryEntry, Init, Status},
+        runtime_config::{RuntimeConfig, RuntimeHooks},
+        update_queries::update_queries,
+        utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
+    },
+    engine::sqlite_impl::methods::SQLITE_UPDATE_QUERIES_CONFIG,
+};
+
+use rusqlite::Connection;
+
+fn sqlite_connect(options: &ConnectOptions) -> Result<(Connection, String), AlphaDBError> {
+    methods::connect(options).map_err(|e| e.into())
+}
+
+fn sqlite_init(db_name: &str, connection: &mut Connection) -> Result<Init, AlphaDBError> {
+    methods::init(db_name, connection).map_err(|e| e.into())
+}
+
+fn sqlite_status(db_name: &str, connection: &mut Connection) -> Result<Status, AlphaDBError> {
+    methods::status(db_name, connection).map_err(|e| e.into())
+}
+
+fn sqlite_history(db_name: &str, connection: &mut Connection) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+    methods::history(db_name, connection).map_err(|e| e.into())
+}
+
+fn sqlite_update(
+    db_name: &str,
+    connection: &mut Connection,
+    version_source: String,
+    target_version: Option<&str>,
+    no_data: bool,
+    tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+) -> Result<(), AlphaDBError> {
+    methods::update(db_name, connection, version_source, target_version, no_data, tolerated_verification_issue_level).map_err(|e| e.into())
+}
+
+fn sqlite_vacate(connection: &mut Connection) -> Result<(), AlphaDBError> {
+    methods::vacate(connection).map_err(|e| e.into())
+}
+
+pub fn sqlite_runtime_config() -> RuntimeConfig<Connection> {
+    RuntimeConfig {
+        name: "sqlite",
+        hooks: RuntimeHooks {
+            connect: sqlite_connect,
+            init: sqlite_init,
+            status: sqlite_status,
+            history: sqlite_history,
+            update_queries: |db_name, connection, version_source, target_version, no_data| {
+                update_queries(&SQLITE_UPDATE_QUERIES_CONFIG, db_name, connection, version_source, target_version, no_data)
+            },
+            update: sqlite_update,
+            vacate: sqlite_vacate,
+        },
+    }
+}
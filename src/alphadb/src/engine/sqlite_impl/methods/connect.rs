@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use rusqlite::Connection;
+
+use crate::core::connect_options::ConnectOptions;
+use crate::core::utils::errors::AlphaDBError;
+use crate::engine::sqlite_impl::utils::errors::AlphaDBSqliteError;
+
+/// Get the database file from the connect options
+///
+/// `database` takes precedence over `url`. URLs may be written as
+/// `sqlite://path`, `sqlite:path` or as a SQLite `file:` URI.
+fn database_path(options: &ConnectOptions) -> Result<String, AlphaDBSqliteError> {
+    if let Some(database) = &options.database {
+        return Ok(database.to_string());
+    }
+
+    let url = match &options.url {
+        Some(url) => url,
+        None => {
+            return Err(AlphaDBError {
+                message: "No database file provided in the connect options".to_string(),
+                error: "no-database".to_string(),
+                ..Default::default()
+            }
+            .into())
+        }
+    };
+
+    if let Some(path) = url.strip_prefix("sqlite://").or_else(|| url.strip_prefix("sqlite:")) {
+        return Ok(path.to_string());
+    }
+
+    if url.starts_with("file:") {
+        return Ok(url.to_string());
+    }
+
+    Err(AlphaDBError {
+        message: format!("Invalid SQLite connection URL: '{url}' does not start with sqlite: or file:"),
+        error: "invalid-connection-url".to_string(),
+        ..Default::default()
+    }
+    .into())
+}
+
+/// Open a SQLite database file, creating it if it does not exist
+///
+/// Host, credentials and TLS settings do not apply to SQLite and are ignored.
+/// `connect_timeout` is used as the busy timeout, the time to wait for a lock
+/// held by another connection. Foreign key enforcement is enabled.
+pub fn connect(options: &ConnectOptions) -> Result<(Connection, String), AlphaDBSqliteError> {
+    let path = database_path(options)?;
+    let connection = Connection::open(&path)?;
+
+    if let Some(timeout) = options.connect_timeout {
+        connection.busy_timeout(timeout)?;
+    }
+
+    connection.pragma_update(None, "foreign_keys", true)?;
+
+    Ok((connection, path))
+}
+
+#[cfg(test)]
+mod connect_tests {
+    use super::*;
+    use crate::core::utils::errors::Get;
+
+    #[test]
+    fn test_connect() {
+        let (_, db_name) = connect(&ConnectOptions::from_path(":memory:")).unwrap();
+        assert_eq!(db_name, ":memory:");
+    }
+
+    #[test]
+    fn database_path_from_url() {
+        assert_eq!(database_path(&ConnectOptions::from_url("sqlite://data/app.db")).unwrap(), "data/app.db");
+        assert_eq!(database_path(&ConnectOptions::from_url("sqlite:app.db")).unwrap(), "app.db");
+        assert_eq!(database_path(&ConnectOptions::from_url("file:app.db?mode=ro")).unwrap(), "file:app.db?mode=ro");
+
+        let options = ConnectOptions {
+            database: Some("other.db".to_string()),
+            ..ConnectOptions::from_url("sqlite://app.db")
+        };
+        assert_eq!(database_path(&options).unwrap(), "other.db");
+
+        let err = database_path(&ConnectOptions::from_url("postgresql://localhost/app")).unwrap_err();
+        assert_eq!(err.error(), "invalid-connection-url");
+
+        let err = database_path(&ConnectOptions::default()).unwrap_err();
+        assert_eq!(err.error(), "no-database");
+    }
+}
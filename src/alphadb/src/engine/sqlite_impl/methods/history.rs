@@ -0,0 +1,41 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::HistoryEntry;
+use crate::core::utils::globals::HISTORY_TABLE_NAME;
+use crate::engine::sqlite_impl::utils::check::table_exists;
+use crate::engine::sqlite_impl::utils::errors::AlphaDBSqliteError;
+use rusqlite::Connection;
+
+pub fn history(db_name: &str, connection: &mut Connection) -> Result<Vec<HistoryEntry>, AlphaDBSqliteError> {
+    if !table_exists(connection, HISTORY_TABLE_NAME)? {
+        return Ok(Vec::new());
+    }
+
+    let mut statement = connection.prepare(&format!(
+        "SELECT version, template, strftime('%Y-%m-%d %H:%M:%S', applied_at) FROM {} WHERE db = ?1 ORDER BY id",
+        HISTORY_TABLE_NAME
+    ))?;
+
+    let rows = statement.query_map([db_name], |row| {
+        Ok(HistoryEntry {
+            version: row.get::<_, String>(0)?,
+            template: row.get::<_, Option<String>>(1)?,
+            applied_at: row.get::<_, String>(2)?,
+        })
+    })?;
+
+    Ok(rows.collect::<Result<Vec<HistoryEntry>, rusqlite::Error>>()?)
+}
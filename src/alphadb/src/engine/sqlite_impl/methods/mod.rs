@@ -0,0 +1,15 @@
+mod connect;
+mod history;
+mod init;
+mod status;
+mod update;
+mod update_queries;
+mod vacate;
+
+pub use connect::connect;
+pub use history::history;
+pub use init::init;
+pub use status::status;
+pub use update::update;
+pub use update_queries::SQLITE_UPDATE_QUERIES_CONFIG;
+pub use vacate::vacate;
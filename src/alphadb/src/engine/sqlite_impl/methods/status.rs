@@ -0,0 +1,47 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::Status;
+use crate::core::utils::globals::CONFIG_TABLE_NAME;
+use crate::engine::sqlite_impl::utils::check::table_exists;
+use crate::engine::sqlite_impl::utils::errors::AlphaDBSqliteError;
+use rusqlite::{Connection, OptionalExtension};
+
+pub fn status(db_name: &str, connection: &mut Connection) -> Result<Status, AlphaDBSqliteError> {
+    let mut version: Option<String> = None;
+    let mut template: Option<String> = None;
+
+    let init = table_exists(connection, CONFIG_TABLE_NAME)?;
+
+    if init {
+        let fetched = connection
+            .query_row(&format!("SELECT version, template FROM {} where db = ?1", CONFIG_TABLE_NAME), [db_name], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+            })
+            .optional()?;
+
+        if let Some(row) = fetched {
+            version = Some(row.0);
+            template = row.1;
+        }
+    }
+
+    Ok(Status {
+        init,
+        version,
+        name: db_name.to_string(),
+        template,
+    })
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::QueryValue;
+use crate::core::update_queries::update_queries;
+use crate::core::utils::types::ToleratedVerificationIssueLevel;
+use crate::engine::sqlite_impl::methods::SQLITE_UPDATE_QUERIES_CONFIG;
+use crate::engine::sqlite_impl::utils::errors::AlphaDBSqliteError;
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection};
+
+fn query_value_to_sqlite_param(value: &QueryValue) -> Value {
+    match value {
+        QueryValue::String(s) => Value::Text(s.clone()),
+        QueryValue::Integer(i) => Value::Integer(*i),
+        QueryValue::Unsigned(u) => Value::Integer(*u as i64),
+        QueryValue::Float(f) => Value::Real(*f),
+        QueryValue::Bool(b) => Value::Integer(*b as i64),
+        QueryValue::Null => Value::Null,
+    }
+}
+
+pub fn update(
+    db_name: &str,
+    connection: &mut Connection,
+    version_source: String,
+    target_version: Option<&str>,
+    no_data: bool,
+    _tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+) -> Result<(), AlphaDBSqliteError> {
+    let queries = update_queries(&SQLITE_UPDATE_QUERIES_CONFIG, db_name, connection, version_source, target_version, no_data)?;
+
+    for query in queries {
+        if let Some(data) = query.data {
+            connection.execute(query.query.as_str(), params_from_iter(data.iter().map(query_value_to_sqlite_param)))?;
+        } else {
+            // execute() rejects statements that return rows, which some PRAGMA statements do
+            connection.execute_batch(query.query.as_str())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::{query_value_to_sqlite_param, update};
+    use crate::core::method_types::QueryValue;
+    use crate::core::update_queries::update_queries;
+    use crate::core::utils::globals::HISTORY_TABLE_NAME;
+    use crate::core::connect_options::ConnectOptions;
+    use crate::core::utils::types::ToleratedVerificationIssueLevel;
+    use crate::engine::sqlite_impl::methods::{connect, history, init, status, SQLITE_UPDATE_QUERIES_CONFIG};
+    use rusqlite::types::Value;
+    use serde_json::json;
+
+    #[test]
+    fn null_parameter() {
+        assert_eq!(query_value_to_sqlite_param(&QueryValue::from_json(&json!(null))), Value::Null);
+    }
+
+    #[test]
+    fn rebuild_keeps_data() {
+        let (mut connection, db_name) = connect(&ConnectOptions::from_path(":memory:")).unwrap();
+        init(&db_name, &mut connection).unwrap();
+
+        let version_source = json!({"name": "test", "engine": "sqlite", "version": [
+            {"_id": "0.0.1", "createtable": {
+                "accounts": {"primary_key": "id", "id": {"type": "INTEGER", "auto_increment": true}, "name": {"type": "VARCHAR", "length": 50}},
+                "users": {
+                    "primary_key": "id",
+                    "id": {"type": "INTEGER", "auto_increment": true},
+                    "name": {"type": "VARCHAR", "length": 50},
+                    "nickname": {"type": "TEXT", "null": true},
+                    "account_id": {"type": "INTEGER"},
+                    "foreign_key": [{"name": "users_account_fk", "from": "account_id", "references": "accounts", "to": "id"}],
+                    "index": [{"name": "users_name_idx", "columns": ["name"]}]
+                }
+            }},
+            {"_id": "0.0.2", "default_data": {
+                "accounts": [{"name": "main"}],
+                "users": [{"name": "alice", "nickname": "al", "account_id": 1}]
+            }},
+            {"_id": "0.0.3", "altertable": {"users": {
+                "dropcolumn": ["nickname"],
+                "renamecolumn": {"name": "full_name"},
+                "addcolumn": {"active": {"type": "BOOLEAN", "default": true}}
+            }}},
+        ]});
+
+        update(&db_name, &mut connection, version_source.to_string(), None, false, ToleratedVerificationIssueLevel::Critical).unwrap();
+
+        let (full_name, active, account_id): (String, bool, i64) = connection
+            .query_row("SELECT full_name, active, account_id FROM users", [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap();
+        assert_eq!((full_name.as_str(), active, account_id), ("alice", true, 1));
+
+        // The index follows the renamed column and the foreign key is still enforced
+        let index: String = connection
+            .query_row("SELECT sql FROM sqlite_master WHERE name = 'users_name_idx'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(index, "CREATE INDEX \"users_name_idx\" ON \"users\" (\"full_name\")");
+        assert!(connection.execute("INSERT INTO users (full_name, account_id) VALUES ('bob', 2)", []).is_err());
+
+        assert_eq!(status(&db_name, &mut connection).unwrap().version, Some("0.0.3".to_string()));
+        assert_eq!(history(&db_name, &mut connection).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn missing_history_table() {
+        let (mut connection, db_name) = connect(&ConnectOptions::from_path(":memory:")).unwrap();
+        init(&db_name, &mut connection).unwrap();
+
+        // A database initialized before the history table was introduced
+        connection.execute_batch(&format!("DROP TABLE {HISTORY_TABLE_NAME}")).unwrap();
+
+        let version_source = json!({"name": "test", "engine": "sqlite", "version": [
+            {"_id": "0.0.1", "createtable": {"users": {"primary_key": "id", "id": {"type": "INTEGER"}}}},
+        ]});
+
+        let queries = update_queries(&SQLITE_UPDATE_QUERIES_CONFIG, &db_name, &mut connection, version_source.to_string(), None, false).unwrap();
+        assert!(queries[0].query.starts_with(&format!("CREATE TABLE IF NOT EXISTS {HISTORY_TABLE_NAME}")));
+
+        update(&db_name, &mut connection, version_source.to_string(), None, false, ToleratedVerificationIssueLevel::Critical).unwrap();
+        assert_eq!(history(&db_name, &mut connection).unwrap().len(), 1);
+    }
+}
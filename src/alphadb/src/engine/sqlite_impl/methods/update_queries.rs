@@ -0,0 +1,80 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::{Query, QueryValue, Status};
+use crate::core::update_queries::UpdateQueriesConfig;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::globals::{CONFIG_TABLE_NAME, HISTORY_TABLE_NAME};
+use crate::engine::sqlite_impl::methods::status;
+use crate::engine::sqlite_impl::query::default_data::default_data;
+use crate::engine::sqlite_impl::query::table::config::SQLITE_TABLE_CONFIG;
+use crate::engine::sqlite_impl::query::table::rebuild::alter_table;
+use crate::engine::sqlite_impl::query::{createindex, dropindex};
+use crate::engine::sqlite_impl::utils::history::create_history_table_query;
+use crate::engine::AlphaDBEngine;
+use rusqlite::Connection;
+use serde_json::Value;
+
+pub const SQLITE_UPDATE_QUERIES_CONFIG: UpdateQueriesConfig<Connection> = UpdateQueriesConfig {
+    engine: Some(AlphaDBEngine::SQLite),
+    status: status_hook,
+    table_config: &SQLITE_TABLE_CONFIG,
+    create_index: createindex,
+    drop_index,
+    default_data,
+    config_update_query,
+    history_query,
+    history_table_query: Some(history_table_query),
+    version_extras: None,
+    create_table: None,
+    alter_table: Some(alter_table),
+};
+
+fn status_hook(db_name: &str, connection: &mut Connection) -> Result<Status, AlphaDBError> {
+    status(db_name, connection).map_err(|e| e.into())
+}
+
+fn drop_index(index_name: &Value, _table_name: &str) -> Result<String, AlphaDBError> {
+    dropindex(index_name)
+}
+
+fn config_update_query(latest_version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("UPDATE {CONFIG_TABLE_NAME} SET version=?1, template=?2 WHERE db = ?3;"),
+        data: Some(Vec::from([
+            QueryValue::String(latest_version.to_string()),
+            QueryValue::String(template_name.to_string()),
+            QueryValue::String(db_name.to_string()),
+        ])),
+    }
+}
+
+fn history_table_query() -> Query {
+    Query {
+        query: create_history_table_query(),
+        data: None,
+    }
+}
+
+fn history_query(version: &str, template_name: &str, db_name: &str) -> Query {
+    Query {
+        query: format!("INSERT INTO {HISTORY_TABLE_NAME} (db, version, template) VALUES (?1, ?2, ?3);"),
+        data: Some(Vec::from([
+            QueryValue::String(db_name.to_string()),
+            QueryValue::String(version.to_string()),
+            QueryValue::String(template_name.to_string()),
+        ])),
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use rusqlite::Connection;
+
+use crate::engine::sqlite_impl::query::quote;
+use crate::engine::sqlite_impl::utils::errors::AlphaDBSqliteError;
+
+pub fn vacate(connection: &mut Connection) -> Result<(), AlphaDBSqliteError> {
+    let tables = {
+        let mut statement = connection.prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'")?;
+        let rows = statement.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<Result<Vec<String>, rusqlite::Error>>()?
+    };
+
+    // SQLite has no DROP TABLE ... CASCADE, foreign keys are disabled while dropping instead
+    connection.pragma_update(None, "foreign_keys", false)?;
+
+    for table in tables {
+        connection.execute(&format!("DROP TABLE IF EXISTS {}", quote(&table)), [])?;
+    }
+
+    connection.pragma_update(None, "foreign_keys", true)?;
+
+    Ok(())
+}
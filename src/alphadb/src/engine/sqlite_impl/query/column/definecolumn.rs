@@ -0,0 +1,233 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::query::column::definecolumn::DefineColumn;
+use crate::core::utils::error_messages::{incompatible_column_attributes_err, incomplete_version_object_err, simple_err};
+use crate::core::utils::errors::{AlphaDBError, Get};
+use crate::core::utils::json::{get_json_float, get_json_int, get_json_string, get_json_value_as_string, get_object_keys};
+use crate::core::verification::compatibility::{check_column_attributes_compatibility, check_column_type_compatibility};
+use crate::core::verification::issue::VersionTrace;
+use core::f64;
+use serde_json::Value;
+
+use crate::engine::sqlite_impl::query::quote;
+use crate::engine::sqlite_impl::verification::compatibility::{
+    ALLOW_DECIMAL_LENGTH, COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, LENGTH_COLUMN_TYPES, SUPPORTED_COLUMN_TYPES,
+};
+
+/// **Define column**
+///
+/// Generate a SQLite query part that defines a single column
+///
+/// `auto_increment` does not add anything to the definition. An `INTEGER`
+/// column that is the table's only primary key is an alias for the rowid,
+/// which SQLite assigns automatically.
+pub fn definecolumn(column_data: &Value, table_name: &str, column_name: &String, version: &str) -> Result<Option<DefineColumn>, AlphaDBError> {
+    let mut query = DefineColumn::new();
+    let column_keys = get_object_keys(column_data);
+    let version_trace = VersionTrace::from([version.to_string(), table_name.to_string(), column_name.to_string()]);
+
+    // If iteration is not an object, it is not a column, so it should be processed later
+    if let Ok(column_keys) = column_keys {
+        // Foreign keys, as well, have to be handled later
+        if column_name == "foreign_key" {
+            return Ok(None);
+        }
+
+        // Must know the type to create a column
+        if !column_keys.contains(&&"type".to_string()) {
+            return Err(incomplete_version_object_err("type", &version_trace));
+        }
+
+        let column_type = get_json_string(&column_data["type"])?;
+
+        let mut null = false;
+        if column_keys.iter().any(|&i| i == "null") && column_data["null"] == true {
+            null = true;
+        }
+
+        for rule in COLUMN_TYPE_COMPATIBILITY_RULES {
+            if !check_column_type_compatibility(column_type, &rule, &column_keys) {
+                return Err(incompatible_column_attributes_err(
+                    rule.attribute.to_uppercase().as_str(),
+                    format!("type=={column_type}").as_str(),
+                    version_trace,
+                ));
+            }
+        }
+
+        for rule in COLUMN_ATTRIBUTE_COMPATIBILITY_RULES {
+            if let Err(incompatible_keys) = check_column_attributes_compatibility(&rule, &column_keys) {
+                for key in incompatible_keys {
+                    if key == "null" && !null {
+                        continue;
+                    }
+
+                    return Err(incompatible_column_attributes_err(
+                        rule.attribute.to_uppercase().as_str(),
+                        key.to_uppercase().as_str(),
+                        version_trace,
+                    ));
+                }
+            }
+        }
+
+        let mut unique = false;
+        if column_keys.iter().any(|&i| i == "unique") && column_data["unique"] == true {
+            unique = true;
+        }
+
+        let mut length: f64 = -1.0;
+        if LENGTH_COLUMN_TYPES.contains(&column_type) && column_keys.iter().any(|&i| i == "length") {
+            if ALLOW_DECIMAL_LENGTH.contains(&column_type.to_lowercase().as_str()) {
+                length = match get_json_float(&column_data["length"]) {
+                    Ok(l) => l,
+                    Err(e) => return Err(simple_err(&e.message(), version_trace)),
+                };
+            } else {
+                length = match get_json_int(&column_data["length"]) {
+                    Ok(l) => l as f64,
+                    Err(e) => return Err(simple_err(&e.message(), version_trace)),
+                };
+            }
+        }
+
+        let mut default: Option<String> = None;
+        if column_keys.iter().any(|&i| i == "default") {
+            let default_value = get_json_value_as_string(&column_data["default"])?;
+
+            if column_type == "BOOLEAN" {
+                if default_value == "true" {
+                    default = Some("true".to_string());
+                } else {
+                    default = Some("false".to_string());
+                }
+            } else {
+                default = Some(default_value);
+            }
+        }
+
+        if !SUPPORTED_COLUMN_TYPES.contains(&column_type) {
+            return Err(simple_err(format!("Column type '{}' is not (yet) supported", column_type).as_str(), version_trace));
+        }
+
+        query.datatype(column_type);
+        query.name(quote(column_name));
+
+        if length != -1.0 {
+            let length_string = length.to_string();
+            let length_str = length_string.as_str();
+            query.size(length_str);
+        }
+
+        if null {
+            query.constraint("null");
+        } else {
+            query.constraint("not null");
+        }
+
+        if unique {
+            query.constraint("unique");
+        }
+
+        if let Some(d) = default {
+            query.default(&d).default_raw(column_type == "BOOLEAN");
+
+            // SQLite default keywords should not contain quotes, expressions must be parenthesized
+            if d.parse::<f64>().is_err() {
+                let sql_keywords = ["CURRENT_TIMESTAMP", "CURRENT_DATE", "CURRENT_TIME", "NULL"];
+                if sql_keywords.iter().any(|&keyword| d.to_uppercase() == keyword) {
+                    query.default_raw(true);
+                } else if d.contains('(') && d.contains(')') {
+                    query.default(format!("({d})")).default_raw(true);
+                }
+            }
+        }
+    } else {
+        return Ok(None);
+    }
+
+    Ok(Some(query))
+}
+
+#[cfg(test)]
+mod definecolumn_tests {
+    use super::definecolumn;
+    use serde_json::json;
+
+    // AUTO_INCREMENT on incompatible type
+    #[test]
+    fn ai_and_type() {
+        let column = &json!({
+            "type": "VARCHAR",
+            "auto_increment": true
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert!(q.is_err());
+        assert_eq!(q.unwrap_err().message, "Column attributes 'AUTO_INCREMENT' and 'type==VARCHAR' are not compatible.");
+    }
+
+    // AUTO_INCREMENT relies on the rowid alias, nothing is added to the definition
+    #[test]
+    fn auto_increment() {
+        let column = &json!({
+            "type": "INTEGER",
+            "auto_increment": true
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert_eq!(q.unwrap().unwrap().to_string(), "\"col\" INTEGER NOT NULL");
+    }
+
+    #[test]
+    fn default_expression() {
+        let column = &json!({
+            "type": "DATETIME",
+            "default": "datetime('now')",
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert_eq!(q.unwrap().unwrap().to_string(), "\"col\" DATETIME NOT NULL DEFAULT (datetime('now'))");
+    }
+
+    // Length is only kept for types that accept one
+    #[test]
+    fn length() {
+        let column = &json!({
+            "type": "VARCHAR",
+            "length": 30,
+            "unique": true,
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert_eq!(q.unwrap().unwrap().to_string(), "\"col\" VARCHAR(30) NOT NULL UNIQUE");
+
+        let column = &json!({
+            "type": "INTEGER",
+            "length": 11,
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+        assert_eq!(q.unwrap().unwrap().to_string(), "\"col\" INTEGER NOT NULL");
+    }
+
+    // Unsupported column type
+    #[test]
+    fn unsupported_type() {
+        let column = &json!({
+            "type": "LONGTEXT",
+        });
+        let q = definecolumn(column, "table", &"col".to_string(), "0.0.1");
+
+        assert!(q.is_err());
+        assert_eq!(q.unwrap_err().message, "Column type 'LONGTEXT' is not (yet) supported");
+    }
+}
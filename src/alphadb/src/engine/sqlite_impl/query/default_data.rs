@@ -0,0 +1,57 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::{method_types::Query, query::default_data::parse_default_data, utils::errors::AlphaDBError};
+use crate::engine::sqlite_impl::query::{quote, IDENTIFIER_QUOTE};
+use serde_json::Value;
+
+pub fn default_data(table_name: &str, item: &Value) -> Result<Query, AlphaDBError> {
+    let data = parse_default_data(item)?;
+
+    let q = format!(
+        "INSERT INTO {} ({}) VALUES ({});",
+        quote(table_name),
+        IDENTIFIER_QUOTE.quote_list(&data.columns),
+        (1..=data.values.len()).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(",")
+    );
+    Ok(Query {
+        query: q,
+        data: Some(data.values),
+    })
+}
+
+#[cfg(test)]
+mod default_data_tests {
+    use super::default_data;
+    use crate::core::method_types::QueryValue;
+    use serde_json::json;
+
+    #[test]
+    fn data() {
+        let test_item = json!({
+            "col1": "value1",
+            "col2": 1,
+            "col3": null,
+            "col4": true,
+        });
+
+        let q = default_data("test", &test_item).unwrap();
+        assert_eq!(q.query, "INSERT INTO \"test\" (\"col1\", \"col2\", \"col4\") VALUES (?1,?2,?3);");
+        assert_eq!(
+            q.data.unwrap(),
+            Vec::from([QueryValue::String("value1".to_string()), QueryValue::Integer(1), QueryValue::Bool(true)])
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::core::utils::error_messages::incomplete_version_object_err;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::json::{array_iter, get_json_boolean, get_json_string, get_object_keys};
+use crate::core::verification::issue::VersionTrace;
+use crate::engine::sqlite_impl::query::{quote, IDENTIFIER_QUOTE};
+use serde_json::Value;
+
+use crate::core::utils::condition_to_sql;
+
+/// Generate a SQLite CREATE INDEX query
+///
+/// `index` is a JSON value describing the index, with the shape:
+/// ```json
+/// {
+///   "name": "index_name",
+///   "columns": ["col1", "col2"],
+///   "condition": { ... }
+/// }
+/// ```
+/// `condition` is optional. SQLite only has b-tree indexes, so an index
+/// `type` is not accepted.
+pub fn createindex(index: &Value, table_name: &str) -> Result<String, AlphaDBError> {
+    let keys = get_object_keys(index)?;
+
+    if !keys.iter().any(|k| *k == "name") {
+        return Err(incomplete_version_object_err("name", &VersionTrace::new()));
+    }
+
+    if !keys.iter().any(|k| *k == "columns") {
+        return Err(incomplete_version_object_err("columns", &VersionTrace::new()));
+    }
+
+    if keys.iter().any(|k| *k == "type") {
+        return Err(AlphaDBError {
+            message: "SQLite does not support index types.".to_string(),
+            error: "unsupported-feature".to_string(),
+            ..Default::default()
+        });
+    }
+
+    let name = get_json_string(&index["name"])?;
+    let unique = get_json_boolean(&index["unique"]).unwrap_or_default();
+
+    let columns: Vec<&str> = array_iter(&index["columns"])?
+        .iter()
+        .map(|v| get_json_string(v))
+        .collect::<Result<Vec<&str>, AlphaDBError>>()?;
+
+    if columns.is_empty() {
+        return Err(AlphaDBError {
+            message: "Index 'columns' must contain at least one column.".to_string(),
+            error: "incomplete-version-object".to_string(),
+            ..Default::default()
+        });
+    }
+
+    let mut sql = format!(
+        "CREATE {}INDEX {} ON {} ({})",
+        if unique { "UNIQUE " } else { "" },
+        quote(name),
+        quote(table_name),
+        IDENTIFIER_QUOTE.quote_list(&columns)
+    );
+
+    if keys.iter().any(|k| *k == "condition") {
+        let where_clause = condition_to_sql(&index["condition"], IDENTIFIER_QUOTE)?;
+        sql = format!("{sql} WHERE {where_clause}");
+    }
+
+    sql.push(';');
+
+    Ok(sql)
+}
+
+/// Generate a SQLite DROP INDEX query
+///
+/// `IF EXISTS` is included because a table rebuild drops the indexes of the
+/// rebuilt table before the version's `drop_index` entries run.
+pub fn dropindex(index_name: &Value) -> Result<String, AlphaDBError> {
+    let name = get_json_string(index_name)?;
+    Ok(format!("DROP INDEX IF EXISTS {};", quote(name)))
+}
+
+#[cfg(test)]
+mod createindex_tests {
+    use super::createindex;
+    use serde_json::json;
+
+    #[test]
+    fn missing_name() {
+        let index = json!({ "columns": ["col1"] });
+        let result = createindex(&index, "my_table");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().message, "Missing required key 'name'.");
+    }
+
+    #[test]
+    fn index_type() {
+        let index = json!({ "name": "idx_col1", "type": "btree", "columns": ["col1"] });
+        let result = createindex(&index, "my_table");
+        assert_eq!(result.unwrap_err().error, "unsupported-feature");
+    }
+
+    #[test]
+    fn unique_index() {
+        let index = json!({ "name": "idx_multi", "unique": true, "columns": ["col1", "col2"] });
+        let result = createindex(&index, "my_table").unwrap();
+        assert_eq!(result, "CREATE UNIQUE INDEX \"idx_multi\" ON \"my_table\" (\"col1\", \"col2\");");
+    }
+
+    #[test]
+    fn index_with_condition() {
+        let index = json!({
+            "name": "test_index",
+            "columns": ["col3"],
+            "condition": {
+                "type": "comparison",
+                "op": "=",
+                "left": { "type": "column", "name": "status" },
+                "right": { "type": "value", "value": "pending" }
+            }
+        });
+        let result = createindex(&index, "my_table").unwrap();
+        assert_eq!(result, "CREATE INDEX \"test_index\" ON \"my_table\" (\"col3\") WHERE \"status\" = 'pending';");
+    }
+}
+
+#[cfg(test)]
+mod dropindex_tests {
+    use super::dropindex;
+    use serde_json::json;
+
+    #[test]
+    fn basic_drop() {
+        let result = dropindex(&json!("idx_col1")).unwrap();
+        assert_eq!(result, "DROP INDEX IF EXISTS \"idx_col1\";");
+    }
+}
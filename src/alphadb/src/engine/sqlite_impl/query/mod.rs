@@ -0,0 +1,19 @@
+use crate::core::query::identifier::IdentifierQuote;
+
+pub mod column;
+mod constraint;
+pub mod default_data;
+mod index;
+pub mod table;
+
+pub use constraint::check::create_check_constraint;
+pub use constraint::foreign_key::create_foreign_key_constraint;
+pub use index::index::{createindex, dropindex};
+
+/// How SQLite quotes identifiers in generated queries
+pub(crate) const IDENTIFIER_QUOTE: IdentifierQuote = IdentifierQuote::DoubleQuote;
+
+/// Quote a table, column, index or constraint name
+pub(crate) fn quote(identifier: &str) -> String {
+    IDENTIFIER_QUOTE.quote(identifier)
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::query::column::DefineColumn;
+use crate::core::query::table::TableQueryConfig;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::verification::issue::VersionTrace;
+use serde_json::Value;
+
+use crate::engine::sqlite_impl::query::column::definecolumn::definecolumn;
+use crate::engine::sqlite_impl::query::{create_check_constraint, create_foreign_key_constraint, IDENTIFIER_QUOTE};
+
+/// SQLite can not modify columns, primary keys or foreign keys with `ALTER TABLE`.
+/// Those changes are applied by rebuilding the table (see [`rebuild`](super::rebuild)),
+/// which only uses the `CREATE TABLE` hooks of this config.
+pub const SQLITE_TABLE_CONFIG: TableQueryConfig = TableQueryConfig {
+    name: "sqlite",
+    identifier_quote: IDENTIFIER_QUOTE,
+    define_column: definecolumn,
+    foreign_key_constraint: create_foreign_key_constraint,
+    check_constraint: create_check_constraint,
+    table_options: None,
+    modify_column,
+    drop_primary_key,
+    add_primary_key,
+    drop_foreign_key,
+    preprocess: None,
+    rename_column: None,
+    column_if_exists: false,
+    create_options: None,
+    alter_options: None,
+};
+
+fn modify_column(_version_list: &Vec<Value>, _modify_entry: &mut Value, table_name: &str, column: &str, version: &str) -> Result<Vec<DefineColumn>, AlphaDBError> {
+    Err(AlphaDBError {
+        message: "SQLite can not modify a column in place, the table must be rebuilt".to_string(),
+        error: "unsupported-feature".to_string(),
+        version_trace: VersionTrace::from([version.to_string(), "altertable".to_string(), format!("table:{table_name}"), format!("column:{column}")]),
+        location: None,
+    })
+}
+
+fn drop_primary_key(_table_name: &str) -> Vec<DefineColumn> {
+    Vec::new()
+}
+
+fn add_primary_key(_table_name: &str, _columns: &str) -> Vec<DefineColumn> {
+    Vec::new()
+}
+
+fn drop_foreign_key(_foreign_key_name: &str) -> DefineColumn {
+    DefineColumn::new()
+}
+
+#[cfg(test)]
+mod createtable_tests {
+    use super::SQLITE_TABLE_CONFIG;
+    use crate::core::query::table::create_table;
+    use serde_json::json;
+
+    #[test]
+    fn test_query() {
+        let json = &json!({
+            "createtable": {
+                "table": {
+                    "primary_key": "id",
+                    "id": {
+                        "type": "INTEGER",
+                        "auto_increment": true,
+                    },
+                    "col1": {"type": "VARCHAR", "length": 30, "unique": true},
+                    "foreign_key": [
+                        {
+                            "name": "table_key_fk",
+                            "references": "other_table",
+                            "from": "key",
+                            "to": "key",
+                            "on_delete": "cascade",
+                        }
+                    ],
+                }
+            }
+        });
+
+        assert_eq!(
+            create_table(&SQLITE_TABLE_CONFIG, json, "table", "0.0.1").unwrap(),
+            "CREATE TABLE \"table\" (\"id\" INTEGER NOT NULL, \"col1\" VARCHAR(30) NOT NULL UNIQUE, PRIMARY KEY (\"id\"), CONSTRAINT \"table_key_fk\" FOREIGN KEY (\"key\") REFERENCES \"other_table\" (\"key\") ON DELETE CASCADE);"
+        );
+    }
+}
@@ -0,0 +1,494 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! SQLite `ALTER TABLE` builder.
+//!
+//! SQLite's `ALTER TABLE` can add and rename columns, but can not modify or
+//! drop them, nor change the primary key or foreign keys. For those changes the
+//! table is rebuilt: a new table is created from the consolidated definition,
+//! the data is copied over, the old table is dropped and the new one renamed.
+//! See <https://www.sqlite.org/lang_altertable.html#otheralter>.
+
+use crate::core::method_types::Query;
+use crate::core::query::table::{create_table, TableQueryConfig};
+use crate::core::utils::consolidate::table::consolidate_table;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::json::{array_iter, get_json_object, get_json_string, get_object_keys, object_iter};
+use crate::core::utils::version_source::get_version_array;
+use crate::core::verification::issue::VersionTrace;
+use crate::engine::sqlite_impl::query::{createindex, quote, IDENTIFIER_QUOTE};
+use serde_json::{json, Value};
+
+/// Prefix of the temporary table a table is rebuilt into
+const REBUILD_TABLE_PREFIX: &str = "_adb_rebuild_";
+
+/// `altertable` keys that can only be applied by rebuilding the table
+const REBUILD_KEYS: [&str; 6] = ["dropcolumn", "modifycolumn", "primary_key", "add_foreign_key", "drop_foreign_key", "modify_foreign_key"];
+
+/// Table level definitions of a table at a version, with column renames applied
+#[derive(Debug, Default)]
+struct TableState {
+    primary_key: Option<Value>,
+    foreign_keys: Vec<Value>,
+    checks: Vec<Value>,
+    indexes: Vec<Value>,
+}
+
+/// Rename `old` to `new` in a column name or list of column names
+fn rename_column_reference(value: &mut Value, old: &str, new: &str) {
+    match value {
+        Value::String(column) if column == old => *column = new.to_string(),
+        Value::Array(columns) => {
+            for column in columns {
+                rename_column_reference(column, old, new);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn references_column(value: &Value, column: &str) -> bool {
+    match value {
+        Value::String(c) => c == column,
+        Value::Array(columns) => columns.iter().any(|c| c == column),
+        _ => false,
+    }
+}
+
+/// Whether a check or index condition compares `column`
+fn condition_references_column(condition: &Value, column: &str) -> bool {
+    match condition {
+        Value::Object(node) => (node.get("type").is_some_and(|t| t == "column") && node.get("name").is_some_and(|n| n == column)) || node.values().any(|v| condition_references_column(v, column)),
+        Value::Array(nodes) => nodes.iter().any(|v| condition_references_column(v, column)),
+        _ => false,
+    }
+}
+
+/// Rename `old` to `new` in every column of a check or index condition
+fn rename_condition_column(condition: &mut Value, old: &str, new: &str) {
+    match condition {
+        Value::Object(node) => {
+            if node.get("type").is_some_and(|t| t == "column") && node.get("name").is_some_and(|n| n == old) {
+                node.insert("name".to_string(), Value::from(new));
+            }
+            node.values_mut().for_each(|v| rename_condition_column(v, old, new));
+        }
+        Value::Array(nodes) => nodes.iter_mut().for_each(|v| rename_condition_column(v, old, new)),
+        _ => {}
+    }
+}
+
+/// Replace the item with the same name, or add it
+fn upsert_by_name(items: &mut Vec<Value>, item: &Value) {
+    items.retain(|i| i["name"] != item["name"]);
+    items.push(item.clone());
+}
+
+/// Walk `version_list` and collect the primary key, foreign keys, checks and
+/// indexes of `table_name` after the last version in the list
+fn table_state(version_list: &[Value], table_name: &str) -> Result<TableState, AlphaDBError> {
+    let mut state = TableState::default();
+
+    for version in version_list {
+        let created = &version["createtable"][table_name];
+        if created.is_object() {
+            state = TableState {
+                primary_key: created.get("primary_key").cloned(),
+                ..Default::default()
+            };
+
+            if let Some(foreign_keys) = created.get("foreign_key") {
+                state.foreign_keys = array_iter(foreign_keys)?.clone();
+            }
+            if let Some(checks) = created.get("check") {
+                state.checks = array_iter(checks)?.clone();
+            }
+            if let Some(indexes) = created.get("index") {
+                state.indexes = array_iter(indexes)?.clone();
+            }
+        }
+
+        let altered = &version["altertable"][table_name];
+        if !altered.is_object() {
+            continue;
+        }
+
+        // Definitions on a dropped column are dropped with it, SQLite can not recreate them
+        if let Some(dropped) = altered.get("dropcolumn") {
+            for column in array_iter(dropped)? {
+                let column = get_json_string(column).unwrap_or_default();
+
+                if state.primary_key.as_ref().is_some_and(|pk| references_column(pk, column)) {
+                    state.primary_key = None;
+                }
+                state.foreign_keys.retain(|fk| !references_column(&fk["from"], column));
+                state.checks.retain(|check| !condition_references_column(&check["condition"], column));
+                state
+                    .indexes
+                    .retain(|index| !references_column(&index["columns"], column) && !condition_references_column(&index["condition"], column));
+            }
+        }
+
+        if let Some(renames) = altered.get("renamecolumn") {
+            for (old, new) in get_json_object(renames)? {
+                let new = get_json_string(new)?;
+
+                if let Some(primary_key) = state.primary_key.as_mut() {
+                    rename_column_reference(primary_key, old, new);
+                }
+                for foreign_key in state.foreign_keys.iter_mut() {
+                    rename_column_reference(&mut foreign_key["from"], old, new);
+                }
+                for check in state.checks.iter_mut() {
+                    rename_condition_column(&mut check["condition"], old, new);
+                }
+                for index in state.indexes.iter_mut() {
+                    rename_column_reference(&mut index["columns"], old, new);
+                    if index.get("condition").is_some() {
+                        rename_condition_column(&mut index["condition"], old, new);
+                    }
+                }
+            }
+        }
+
+        if let Some(primary_key) = altered.get("primary_key") {
+            state.primary_key = if primary_key.is_null() { None } else { Some(primary_key.clone()) };
+        }
+
+        if let Some(dropped) = altered.get("drop_foreign_key") {
+            for name in array_iter(dropped)? {
+                state.foreign_keys.retain(|fk| &fk["name"] != name);
+            }
+        }
+        for key in ["modify_foreign_key", "add_foreign_key"] {
+            if let Some(foreign_keys) = altered.get(key) {
+                for foreign_key in array_iter(foreign_keys)? {
+                    upsert_by_name(&mut state.foreign_keys, foreign_key);
+                }
+            }
+        }
+
+        if let Some(dropped) = altered.get("drop_index") {
+            for name in array_iter(dropped)? {
+                state.indexes.retain(|index| &index["name"] != name);
+            }
+        }
+        for key in ["modify_index", "add_index"] {
+            if let Some(indexes) = altered.get(key) {
+                for index in array_iter(indexes)? {
+                    upsert_by_name(&mut state.indexes, index);
+                }
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+/// Whether `ALTER TABLE ... ADD COLUMN` accepts the column. SQLite refuses
+/// unique columns, and NOT NULL columns without a constant default.
+fn can_add_column(column: &Value) -> bool {
+    if column["unique"] == true {
+        return false;
+    }
+
+    if column["null"] == true {
+        return true;
+    }
+
+    match &column["default"] {
+        Value::Null => false,
+        Value::String(default) => !(default.to_uppercase().starts_with("CURRENT_") || default.contains('(')),
+        _ => true,
+    }
+}
+
+/// Build the queries for one table's `altertable` block in `version`
+///
+/// Added and renamed columns use `ALTER TABLE` directly. Any other change, or
+/// a column `ADD COLUMN` does not accept, rebuilds the table as it is after
+/// `version`. Indexes of the rebuilt table are recreated, except the ones this
+/// version adds or modifies, which the update queries create afterwards.
+///
+/// # Arguments
+/// * `config` - SQLite table query configuration
+/// * `version_source` - Complete JSON version source, sorted by version
+/// * `table_name` - Name of the table to be altered
+/// * `version` - Current version number to process
+pub fn alter_table(config: &TableQueryConfig, version_source: &Value, table_name: &str, version: &str) -> Result<Vec<Query>, AlphaDBError> {
+    let version_list = get_version_array(version_source)?;
+
+    let version_index = version_list.iter().position(|v| v["_id"] == version).ok_or_else(|| AlphaDBError {
+        message: "An unexpected error occured. No table data seems to be returned".to_string(),
+        version_trace: VersionTrace::from([version, "altertable", table_name]),
+        ..Default::default()
+    })?;
+
+    let table_data = &version_list[version_index]["altertable"][table_name];
+    let table_keys = get_object_keys(table_data)?;
+
+    let mut rebuild = table_keys.iter().any(|k| REBUILD_KEYS.contains(&k.as_str()));
+    if let Some(added) = table_data.get("addcolumn") {
+        rebuild = rebuild || get_json_object(added)?.values().any(|column| !can_add_column(column));
+    }
+
+    if rebuild {
+        return rebuild_table(config, &version_list[..=version_index], table_name, version);
+    }
+
+    let mut queries = Vec::new();
+
+    if let Some(added) = table_data.get("addcolumn") {
+        for column in object_iter(added)? {
+            if let Some(mut definition) = (config.define_column)(&added[column], table_name, column, version)? {
+                definition.method("ADD COLUMN");
+                queries.push(Query {
+                    query: format!("ALTER TABLE {} {};", quote(table_name), definition.to_sql()),
+                    data: None,
+                });
+            }
+        }
+    }
+
+    if let Some(renames) = table_data.get("renamecolumn") {
+        for (old, new) in get_json_object(renames)? {
+            queries.push(Query {
+                query: format!("ALTER TABLE {} RENAME COLUMN {} TO {};", quote(table_name), quote(old), quote(get_json_string(new)?)),
+                data: None,
+            });
+        }
+    }
+
+    Ok(queries)
+}
+
+/// Rebuild `table_name` as it is after the last version in `version_list`
+fn rebuild_table(config: &TableQueryConfig, version_list: &[Value], table_name: &str, version: &str) -> Result<Vec<Query>, AlphaDBError> {
+    let table_data = &version_list[version_list.len() - 1]["altertable"][table_name];
+    let rebuild_table_name = format!("{REBUILD_TABLE_PREFIX}{table_name}");
+
+    let previous_columns = consolidate_table(&version_list[..version_list.len() - 1].to_vec(), table_name, None)?;
+
+    // Primary keys are taken from the table state, which also handles composite keys
+    let mut definition = json!({});
+    for (column, data) in get_json_object(&consolidate_table(&version_list.to_vec(), table_name, None)?)? {
+        if data.is_object() {
+            definition[column] = data.clone();
+        }
+    }
+    let columns: Vec<String> = get_object_keys(&definition)?.into_iter().cloned().collect();
+
+    let state = table_state(version_list, table_name)?;
+    if let Some(primary_key) = state.primary_key {
+        definition["primary_key"] = primary_key;
+    }
+    if !state.foreign_keys.is_empty() {
+        definition["foreign_key"] = Value::from(state.foreign_keys);
+    }
+    if !state.checks.is_empty() {
+        definition["check"] = Value::from(state.checks);
+    }
+
+    // Copy every column that existed before this version, under its previous name
+    let mut insert_columns: Vec<&str> = Vec::new();
+    let mut select_columns: Vec<&str> = Vec::new();
+    for column in columns.iter() {
+        let mut previous_name = column.as_str();
+        if let Some(renames) = table_data.get("renamecolumn") {
+            if let Some((old, _)) = get_json_object(renames)?.iter().find(|(_, new)| *new == column) {
+                previous_name = old;
+            }
+        }
+
+        if previous_columns.get(previous_name).is_some_and(|c| c.is_object()) {
+            insert_columns.push(column);
+            select_columns.push(previous_name);
+        }
+    }
+
+    let mut statements = vec![
+        "PRAGMA foreign_keys = OFF;".to_string(),
+        create_table(config, &json!({ "createtable": { &rebuild_table_name: definition } }), &rebuild_table_name, version)?,
+    ];
+
+    if !insert_columns.is_empty() {
+        statements.push(format!(
+            "INSERT INTO {} ({}) SELECT {} FROM {};",
+            quote(&rebuild_table_name),
+            IDENTIFIER_QUOTE.quote_list(&insert_columns),
+            IDENTIFIER_QUOTE.quote_list(&select_columns),
+            quote(table_name)
+        ));
+    }
+
+    statements.push(format!("DROP TABLE {};", quote(table_name)));
+    statements.push(format!("ALTER TABLE {} RENAME TO {};", quote(&rebuild_table_name), quote(table_name)));
+
+    let mut created_later: Vec<&Value> = Vec::new();
+    for key in ["modify_index", "add_index"] {
+        if let Some(indexes) = table_data.get(key) {
+            created_later.extend(array_iter(indexes)?.iter().map(|index| &index["name"]));
+        }
+    }
+
+    for index in state.indexes.iter() {
+        if !created_later.contains(&&index["name"]) {
+            statements.push(createindex(index, table_name)?);
+        }
+    }
+
+    statements.push("PRAGMA foreign_keys = ON;".to_string());
+
+    Ok(statements.into_iter().map(|query| Query { query, data: None }).collect())
+}
+
+#[cfg(test)]
+mod altertable_tests {
+    use super::alter_table;
+    use crate::engine::sqlite_impl::query::table::config::SQLITE_TABLE_CONFIG;
+    use serde_json::{json, Value};
+
+    fn queries(version_source: &Value, version: &str) -> Vec<String> {
+        alter_table(&SQLITE_TABLE_CONFIG, version_source, "table", version)
+            .unwrap()
+            .into_iter()
+            .map(|q| q.query)
+            .collect()
+    }
+
+    #[test]
+    fn addcolumn_and_renamecolumn() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {"col1": {"type": "INTEGER"}}}},
+            {"_id": "0.0.2", "altertable": {"table": {
+                "addcolumn": {"col2": {"type": "TEXT", "null": true}, "col3": {"type": "INTEGER", "default": 0}},
+                "renamecolumn": {"col1": "renamed"}
+            }}},
+        ]});
+
+        assert_eq!(
+            queries(&version_source, "0.0.2"),
+            [
+                "ALTER TABLE \"table\" ADD COLUMN \"col2\" TEXT NULL;",
+                "ALTER TABLE \"table\" ADD COLUMN \"col3\" INTEGER NOT NULL DEFAULT '0';",
+                "ALTER TABLE \"table\" RENAME COLUMN \"col1\" TO \"renamed\";"
+            ]
+        );
+    }
+
+    #[test]
+    fn dropcolumn() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {
+                "primary_key": "id",
+                "id": {"type": "INTEGER", "auto_increment": true},
+                "col1": {"type": "TEXT"},
+                "col2": {"type": "TEXT"},
+                "index": [{"name": "table_col2_idx", "columns": ["col2"]}]
+            }}},
+            {"_id": "0.0.2", "altertable": {"table": {"dropcolumn": ["col1"], "renamecolumn": {"col2": "renamed"}}}},
+        ]});
+
+        assert_eq!(
+            queries(&version_source, "0.0.2"),
+            [
+                "PRAGMA foreign_keys = OFF;",
+                "CREATE TABLE \"_adb_rebuild_table\" (\"id\" INTEGER NOT NULL, \"renamed\" TEXT NOT NULL, PRIMARY KEY (\"id\"));",
+                "INSERT INTO \"_adb_rebuild_table\" (\"id\", \"renamed\") SELECT \"id\", \"col2\" FROM \"table\";",
+                "DROP TABLE \"table\";",
+                "ALTER TABLE \"_adb_rebuild_table\" RENAME TO \"table\";",
+                "CREATE INDEX \"table_col2_idx\" ON \"table\" (\"renamed\");",
+                "PRAGMA foreign_keys = ON;"
+            ]
+        );
+    }
+
+    #[test]
+    fn modifycolumn_and_foreign_key() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {
+                "col1": {"type": "VARCHAR", "length": 20},
+                "account_id": {"type": "INTEGER"},
+                "foreign_key": [{"name": "table_account_fk", "from": "account_id", "references": "accounts", "to": "id"}],
+                "index": [{"name": "table_col1_idx", "columns": ["col1"]}]
+            }}},
+            {"_id": "0.0.2", "altertable": {"table": {
+                "modifycolumn": {"col1": {"recreate": false, "length": 50, "null": true}},
+                "addcolumn": {"col2": {"type": "TEXT"}},
+                "modify_foreign_key": [{"name": "table_account_fk", "from": "account_id", "references": "accounts", "to": "id", "on_delete": "cascade"}],
+                "modify_index": [{"name": "table_col1_idx", "unique": true, "columns": ["col1"]}]
+            }}},
+        ]});
+
+        assert_eq!(
+            queries(&version_source, "0.0.2"),
+            [
+                "PRAGMA foreign_keys = OFF;",
+                "CREATE TABLE \"_adb_rebuild_table\" (\"col1\" VARCHAR(50) NULL, \"account_id\" INTEGER NOT NULL, \"col2\" TEXT NOT NULL, CONSTRAINT \"table_account_fk\" FOREIGN KEY (\"account_id\") REFERENCES \"accounts\" (\"id\") ON DELETE CASCADE);",
+                "INSERT INTO \"_adb_rebuild_table\" (\"col1\", \"account_id\") SELECT \"col1\", \"account_id\" FROM \"table\";",
+                "DROP TABLE \"table\";",
+                "ALTER TABLE \"_adb_rebuild_table\" RENAME TO \"table\";",
+                "PRAGMA foreign_keys = ON;"
+            ]
+        );
+    }
+
+    #[test]
+    fn dropcolumn_drops_its_definitions() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {
+                "col1": {"type": "TEXT"},
+                "col2": {"type": "INTEGER"},
+                "account_id": {"type": "INTEGER"},
+                "foreign_key": [{"name": "table_account_fk", "from": "account_id", "references": "accounts", "to": "id"}],
+                "check": [
+                    {"name": "table_col1_check", "condition": {"type": "comparison", "op": "!=", "left": {"type": "column", "name": "col1"}, "right": {"type": "value", "value": ""}}},
+                    {"name": "table_col2_check", "condition": {"type": "comparison", "op": ">", "left": {"type": "column", "name": "col2"}, "right": {"type": "value", "value": 0}}}
+                ],
+                "index": [
+                    {"name": "table_col1_idx", "columns": ["col1", "col2"]},
+                    {"name": "table_col2_idx", "columns": ["col2"]}
+                ]
+            }}},
+            {"_id": "0.0.2", "altertable": {"table": {"dropcolumn": ["col1", "account_id"], "renamecolumn": {"col2": "renamed"}}}},
+        ]});
+
+        assert_eq!(
+            queries(&version_source, "0.0.2"),
+            [
+                "PRAGMA foreign_keys = OFF;",
+                "CREATE TABLE \"_adb_rebuild_table\" (\"renamed\" INTEGER NOT NULL, CONSTRAINT \"table_col2_check\" CHECK (\"renamed\" > 0));",
+                "INSERT INTO \"_adb_rebuild_table\" (\"renamed\") SELECT \"col2\" FROM \"table\";",
+                "DROP TABLE \"table\";",
+                "ALTER TABLE \"_adb_rebuild_table\" RENAME TO \"table\";",
+                "CREATE INDEX \"table_col2_idx\" ON \"table\" (\"renamed\");",
+                "PRAGMA foreign_keys = ON;"
+            ]
+        );
+    }
+
+    #[test]
+    fn composite_primary_key() {
+        let version_source = json!({"name": "test", "version": [
+            {"_id": "0.0.1", "createtable": {"table": {"col1": {"type": "INTEGER"}, "col2": {"type": "INTEGER"}}}},
+            {"_id": "0.0.2", "altertable": {"table": {"primary_key": ["col1", "col2"]}}},
+        ]});
+
+        assert_eq!(
+            queries(&version_source, "0.0.2")[1],
+            "CREATE TABLE \"_adb_rebuild_table\" (\"col1\" INTEGER NOT NULL, \"col2\" INTEGER NOT NULL, PRIMARY KEY (\"col1\", \"col2\"));"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::method_types::Check;
+use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::globals::CONFIG_TABLE_NAME;
+use rusqlite::{Connection, OptionalExtension};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CheckError {
+    #[error(transparent)]
+    AlphaDbError(#[from] AlphaDBError),
+
+    #[error(transparent)]
+    SqliteError(#[from] rusqlite::Error),
+}
+
+/// SQLite has no `information_schema`, tables are listed in `sqlite_master`
+pub fn table_exists(connection: &Connection, table_name: &str) -> Result<bool, rusqlite::Error> {
+    let table = connection
+        .query_row("SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?1", [table_name], |row| row.get::<_, String>(0))
+        .optional()?;
+
+    Ok(table.is_some())
+}
+
+pub fn check(db_name: &str, connection: &mut Connection) -> Result<Check, CheckError> {
+    let mut version: Option<String> = None;
+
+    let table_check = table_exists(connection, CONFIG_TABLE_NAME)?;
+
+    if table_check {
+        version = connection
+            .query_row(&format!("SELECT version FROM {} where db = ?1", CONFIG_TABLE_NAME), [db_name], |row| row.get::<_, String>(0))
+            .optional()?;
+    }
+
+    Ok(Check {
+        check: table_check && version.is_some(),
+        version,
+    })
+}
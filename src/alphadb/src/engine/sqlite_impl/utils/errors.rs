@@ -0,0 +1,78 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::{
+    utils::errors::{AlphaDBError, Get},
+    verification::issue::VersionTrace,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AlphaDBSqliteError {
+    #[error(transparent)]
+    AlphaDBError(#[from] AlphaDBError),
+
+    #[error("SQLite Error: {error}")]
+    SqliteError { error: rusqlite::Error, version_trace: VersionTrace },
+}
+
+impl From<rusqlite::Error> for AlphaDBSqliteError {
+    fn from(error: rusqlite::Error) -> Self {
+        AlphaDBSqliteError::SqliteError {
+            error,
+            version_trace: VersionTrace::new(),
+        }
+    }
+}
+
+impl From<AlphaDBSqliteError> for AlphaDBError {
+    fn from(err: AlphaDBSqliteError) -> Self {
+        AlphaDBError {
+            message: err.message(),
+            error: err.error(),
+            version_trace: err.version_trace().clone(),
+            location: None,
+        }
+    }
+}
+
+impl Get for AlphaDBSqliteError {
+    fn message(&self) -> String {
+        match self {
+            AlphaDBSqliteError::AlphaDBError(e) => e.message(),
+            AlphaDBSqliteError::SqliteError { error, .. } => format!("SQLite Error: {}", error),
+        }
+    }
+    fn error(&self) -> String {
+        match self {
+            AlphaDBSqliteError::AlphaDBError(e) => e.error(),
+            AlphaDBSqliteError::SqliteError { .. } => String::new(),
+        }
+    }
+    fn version_trace(&self) -> &VersionTrace {
+        match self {
+            AlphaDBSqliteError::AlphaDBError(e) => &e.version_trace,
+            AlphaDBSqliteError::SqliteError { version_trace, .. } => version_trace,
+        }
+    }
+    fn set_version_trace(&mut self, new_version_trace: &VersionTrace) {
+        match self {
+            AlphaDBSqliteError::AlphaDBError(e) => e.set_version_trace(new_version_trace),
+            AlphaDBSqliteError::SqliteError { version_trace, .. } => {
+                *version_trace = new_version_trace.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod check;
+pub mod connection;
+pub mod errors;
+pub mod history;
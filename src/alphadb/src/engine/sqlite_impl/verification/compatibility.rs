@@ -0,0 +1,47 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::verification::compatibility::ColumnCompatibilityRule;
+
+/// All columns supported by AlphaDB for SQLite
+///
+/// SQLite accepts any type name and maps it to a storage class through type
+/// affinity. This list keeps version sources readable for the other engines.
+pub const SUPPORTED_COLUMN_TYPES: [&str; 18] = [
+    "INTEGER", "INT", "BIGINT", "SMALLINT", "TINYINT", "REAL", "DOUBLE", "FLOAT", "NUMERIC", "DECIMAL", "BOOLEAN", "TEXT", "VARCHAR", "CHAR", "BLOB", "DATE",
+    "DATETIME", "JSON",
+];
+
+/// All SQLite column types that accept a length, SQLite itself does not enforce it
+pub const LENGTH_COLUMN_TYPES: [&str; 4] = ["VARCHAR", "CHAR", "NUMERIC", "DECIMAL"];
+
+/// All the SQLite column types that allow a decimal length value
+pub const ALLOW_DECIMAL_LENGTH: [&str; 2] = ["numeric", "decimal"];
+
+/// All type compatibility rules
+///
+/// `auto_increment` relies on the column being an alias for the rowid, which
+/// only an `INTEGER PRIMARY KEY` column is.
+pub const COLUMN_TYPE_COMPATIBILITY_RULES: [ColumnCompatibilityRule; 1] = [ColumnCompatibilityRule {
+    incompatible: &[
+        "int", "bigint", "smallint", "tinyint", "real", "double", "float", "numeric", "decimal", "boolean", "text", "varchar", "char", "blob", "date", "datetime", "json",
+    ],
+    attribute: "auto_increment",
+}];
+
+pub const COLUMN_ATTRIBUTE_COMPATIBILITY_RULES: [ColumnCompatibilityRule; 1] = [ColumnCompatibilityRule {
+    incompatible: &["null"],
+    attribute: "auto_increment",
+}];
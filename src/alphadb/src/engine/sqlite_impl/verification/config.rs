@@ -0,0 +1,106 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::engine_config::{EngineConfig, IndexNameScope, VerificationHooks};
+use crate::engine::sqlite_impl::verification::compatibility::{COLUMN_ATTRIBUTE_COMPATIBILITY_RULES, COLUMN_TYPE_COMPATIBILITY_RULES, SUPPORTED_COLUMN_TYPES};
+
+/// SQLite keywords that can not be used as a name without quotes, see <https://www.sqlite.org/lang_keywords.html>
+pub const RESERVED_WORDS: [&str; 56] = [
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "AUTOINCREMENT",
+    "BETWEEN",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COMMIT",
+    "CONSTRAINT",
+    "CREATE",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DELETE",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "ESCAPE",
+    "EXCEPT",
+    "EXISTS",
+    "FOREIGN",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INDEX",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LIMIT",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "ON",
+    "OR",
+    "ORDER",
+    "PRIMARY",
+    "REFERENCES",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+];
+
+/// SQLite engine configuration for verification
+pub const SQLITE_CONFIG: EngineConfig = EngineConfig {
+    name: "sqlite",
+    non_column_table_keys: &["primary_key", "foreign_key"],
+    string_columns: &["TEXT", "VARCHAR", "CHAR", "JSON"],
+    int_columns: &["INTEGER", "INT", "BIGINT", "SMALLINT", "TINYINT"],
+    float_columns: &["REAL", "DOUBLE", "FLOAT", "NUMERIC", "DECIMAL"],
+
+    supported_column_types: &SUPPORTED_COLUMN_TYPES,
+    version_methods: &[],
+
+    index_types: &[],
+    partial_indexes: true,
+    index_name_scope: IndexNameScope::Schema,
+    reserved_words: &RESERVED_WORDS,
+    max_identifier_length: None,
+
+    type_compatibility_rules: &COLUMN_TYPE_COMPATIBILITY_RULES,
+    attribute_compatibility_rules: &COLUMN_ATTRIBUTE_COMPATIBILITY_RULES,
+
+    verification_hooks: VerificationHooks {
+        verify: &[],
+        createtable: &[],
+        altertable: &[],
+        default_data: &[],
+        column_compatibility: &[],
+    },
+};
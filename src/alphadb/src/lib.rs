@@ -20,7 +20,8 @@ pub mod verification;
 pub mod version_source;
 
 use crate::core::{
-    method_types::{Init, Query, Status},
+    connect_options::ConnectOptions,
+    method_types::{HistoryEntry, Init, Query, Status},
     runtime_config::RuntimeConfig,
     utils::{errors::AlphaDBError, types::ToleratedVerificationIssueLevel},
 };
@@ -52,15 +53,30 @@ impl<C> AlphaDB<C> {
         }
     }
 
-    pub fn connect(&mut self, host: &str, user: &str, password: &str, database: &str, port: u16) -> Result<(), AlphaDBError> {
-        let conn = (self.config.hooks.connect)(host, user, password, database, port)?;
+    pub fn connect(&mut self, options: &ConnectOptions) -> Result<(), AlphaDBError> {
+        let (conn, db_name) = (self.config.hooks.connect)(options)?;
         self.connection = Some(conn);
-        self.db_name = Some(database.to_string());
+        self.db_name = Some(db_name);
         self.is_connected = true;
         Ok(())
     }
 
-    /// Get a mutable reference to the connection, or return an error if not connected
+    /// Use a connection the application already holds instead of opening a new one.
+    /// The connection keeps its own session settings, like TLS and the search path.
+    pub fn from_connection(config: RuntimeConfig<C>, conn: C, db_name: &str) -> AlphaDB<C> {
+        AlphaDB {
+            db_name: Some(db_name.to_string()),
+            is_connected: true,
+            connection: Some(conn),
+            config,
+        }
+    }
+
+    /// Give the connection back to the application
+    pub fn into_connection(self) -> Option<C> {
+        self.connection
+    }
+
     /// Get a mutable reference to the connection, or return an error if not connected
     fn get_connection(&mut self) -> Result<(&str, &mut C), AlphaDBError> {
         let db_name = self.db_name.as_deref().ok_or_else(|| AlphaDBError {
@@ -87,6 +103,13 @@ impl<C> AlphaDB<C> {
         hook(db_name, connection)
     }
 
+    /// Get every version applied to the database, oldest first
+    pub fn history(&mut self) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+        let hook = self.config.hooks.history;
+        let (db_name, connection) = self.get_connection()?;
+        hook(db_name, connection)
+    }
+
     pub fn update_queries(&mut self, version_source: String, target_version: Option<&str>, no_data: bool) -> Result<Vec<Query>, AlphaDBError> {
         let hook = self.config.hooks.update_queries;
         let (db_name, connection) = self.get_connection()?;
@@ -112,3 +135,59 @@ impl<C> AlphaDB<C> {
         hook(connection)
     }
 }
+
+#[cfg(feature = "mysql")]
+impl AlphaDB<mysql::PooledConn> {
+    /// Take a connection from the application's MySQL pool
+    pub fn from_mysql_pool(pool: &mysql::Pool, db_name: &str) -> Result<Self, AlphaDBError> {
+        let conn = engine::mysql_impl::methods::connect_from_pool(pool)?;
+        Ok(AlphaDB::from_connection(engine::mysql(), conn, db_name))
+    }
+
+    /// Take a connection from the application's MariaDB pool
+    pub fn from_mariadb_pool(pool: &mysql::Pool, db_name: &str) -> Result<Self, AlphaDBError> {
+        let mut conn = engine::mysql_impl::methods::connect_from_pool(pool)?;
+        engine::mariadb_impl::utils::server_version::ServerVersion::detect(&mut conn)?;
+        Ok(AlphaDB::from_connection(engine::mariadb(), conn, db_name))
+    }
+}
+
+#[cfg(test)]
+mod alphadb_tests {
+    use super::*;
+    use crate::core::runtime_config::RuntimeHooks;
+
+    /// Runtime config without a database, the connection counts the hook calls
+    fn counting_config() -> RuntimeConfig<u32> {
+        RuntimeConfig {
+            name: "counting",
+            hooks: RuntimeHooks {
+                connect: |_| Ok((0, String::new())),
+                init: |_, _| Ok(Init::Success),
+                status: |db_name, connection| {
+                    *connection += 1;
+                    Ok(Status {
+                        init: true,
+                        version: None,
+                        name: db_name.to_string(),
+                        template: None,
+                    })
+                },
+                history: |_, _| Ok(Vec::new()),
+                update_queries: |_, _, _, _, _| Ok(Vec::new()),
+                update: |_, _, _, _, _, _| Ok(()),
+                vacate: |_| Ok(()),
+            },
+        }
+    }
+
+    #[test]
+    fn from_connection() {
+        let mut db = AlphaDB::from_connection(counting_config(), 5, "adb_test1");
+        assert!(db.is_connected);
+
+        let status = db.status().unwrap();
+        assert_eq!(status.name, "adb_test1");
+        assert_eq!(db.into_connection(), Some(6));
+    }
+}
@@ -1,3 +1,4 @@
+pub use crate::core::connect_options::{ClientCertificate, ConnectOptions, TlsMode, TlsOptions};
 pub use crate::core::utils::{
     errors::{AlphaDBError, Get},
     types::ToleratedVerificationIssueLevel,
@@ -13,33 +13,44 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::core::verification::foreign_key::verify_foreign_key;
-use crate::core::verification::index::verify_index;
+use crate::core::verification::default_data::{verify_table_default_data, version_items};
+use crate::core::verification::foreign_key::{verify_foreign_key, verify_version_foreign_keys};
+use crate::core::verification::identifier::verify_version_identifiers;
+use crate::core::verification::index::{verify_index, verify_version_indexes};
 use crate::core::verification::issue::VerificationIssueAccess;
 pub use crate::core::verification::issue::{IssueCollection, VerificationIssue, VerificationIssueLevel, VersionTrace};
+use crate::core::verification::lint::lint_version;
+pub use crate::core::verification::rules::{find_rule, Rule, RULES};
+use crate::core::verification::rules::{
+    DROPPED_PRIMARY_KEY, EMPTY_BLOCK, INCOMPATIBLE_ATTRIBUTES, INVALID_BOOLEAN_DEFAULT, INVALID_STRUCTURE, MISSING_COLUMN_TYPE, MISSING_NAME, MISSING_VERSION_NUMBER, NO_VERSIONS,
+    UNKNOWN_METHOD, UNSUPPORTED_COLUMN_TYPE,
+};
 
 use crate::core::engine_config::{AltertableHookParams, ColumnCompatibilityHookParams, CreatetableHookParams, DefaultDataHookParams, VerifyHookParams};
+pub use crate::core::utils::source_map::{SourceLocation, SourceMap};
 use crate::core::verification::compatibility::{check_column_attributes_compatibility, verify_column_type_compatibility};
+use crate::core::verification::config::ignored_rules;
+pub use crate::core::verification::config::VerificationConfig;
+pub use crate::core::verification::custom::{ColumnContext, CustomRule, Ruleset, TableContext, VerificationRule, VersionContext};
 use crate::core::verification::primary_key::verify_primary_key;
+pub use crate::core::verification::report::{junit_report, sarif_report, verification_report, ReportFormat};
 use crate::core::{
     engine_config::EngineConfig,
     utils::{
-        consolidate::{default_data::consolidate_default_data, primary_key::get_primary_key, table::consolidate_table},
+        column_types::resolve_portable_column_types,
+        consolidate::{default_data::consolidate_default_data, primary_key::get_primary_key},
         errors::{AlphaDBError, Get, ToVerificationIssue},
-        json::{
-            exists_in_object as adb_exists_in_object, get_json_float as adb_get_json_float, get_json_int as adb_get_json_int, get_json_object as adb_get_json_object,
-            get_json_string as adb_get_json_string,
-        },
-        version_number::parse_version_number as adb_parse_version_number,
+        json::{exists_in_object as adb_exists_in_object, get_json_object as adb_get_json_object, get_json_string as adb_get_json_string},
         version_source::get_version_array,
     },
     verification::json::{
         array_iter, exists_in_object, get_json_boolean, get_json_object, get_json_string, get_json_value_as_string, get_object_keys, object_iter, parse_version_number,
     },
 };
+use crate::engine::AlphaDBEngine;
 use serde_json::Value;
 
-const SUPPORTED_ENGINES: [&str; 2] = ["mysql", "postgres"];
+const SUPPORTED_ENGINES: [&str; 4] = ["mysql", "mariadb", "postgres", "sqlite"];
 
 fn get_engine_config(name: &str) -> Option<&'static EngineConfig> {
     #[cfg(feature = "mysql")]
@@ -47,28 +58,63 @@ fn get_engine_config(name: &str) -> Option<&'static EngineConfig> {
         return Some(&crate::engine::mysql_impl::verification::MYSQL_CONFIG);
     }
 
+    #[cfg(feature = "mysql")]
+    if name == "mariadb" {
+        return Some(&crate::engine::mariadb_impl::verification::MARIADB_CONFIG);
+    }
+
     #[cfg(feature = "postgres")]
     if name == "postgres" {
         return Some(&crate::engine::postgres_impl::verification::POSTGRES_CONFIG);
     }
 
+    #[cfg(feature = "sqlite")]
+    if name == "sqlite" {
+        return Some(&crate::engine::sqlite_impl::verification::SQLITE_CONFIG);
+    }
+
     None
 }
 
+/// Configurations of all supported engines that are enabled
+fn engine_configs() -> impl Iterator<Item = &'static EngineConfig> {
+    SUPPORTED_ENGINES.iter().filter_map(|engine| get_engine_config(engine))
+}
+
+/// Get the configuration of a supported engine, or an error naming the supported engines
+fn get_supported_engine_config(name: &str) -> Result<&'static EngineConfig, AlphaDBError> {
+    get_engine_config(name).ok_or_else(|| AlphaDBError {
+        message: format!("Engine '{}' is not supported. Supported engines: {:?}", name, SUPPORTED_ENGINES),
+        ..Default::default()
+    })
+}
+
+/// The issues found when verifying a version source for one engine, see `AlphaDBVerification::verify_engines`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineIssues {
+    pub engine: &'static str,
+    pub issues: Vec<VerificationIssue>,
+}
+
 pub struct AlphaDBVerification {
     version_source: Value,
     issues: Vec<VerificationIssue>,
     version_list: Vec<Value>,
     config: &'static EngineConfig,
+    verification_config: VerificationConfig,
+    source_map: SourceMap,
+    rules: Ruleset,
 }
 
 impl AlphaDBVerification {
     pub fn new(version_source: String) -> Result<AlphaDBVerification, AlphaDBError> {
+        let source_map = SourceMap::from_json(&version_source, None);
         let version_source: Value = match serde_json::from_str(&version_source) {
             Ok(vs) => vs,
-            Err(_) => {
+            Err(e) => {
                 return Err(AlphaDBError {
                     message: "The provided version source can not be deserialized. Not valid JSON.".to_string(),
+                    location: AlphaDBError::from(e).location,
                     ..Default::default()
                 })
             }
@@ -78,6 +124,7 @@ impl AlphaDBVerification {
             error: "no-engine".to_string(),
             message: "No engine specified. While not required for AlphaDB, this version source is incompatible with the command-line interface.".to_string(),
             version_trace: VersionTrace::new(),
+            location: None,
         };
         if !adb_exists_in_object(&version_source, "engine")? {
             return Err(no_engine_error);
@@ -88,24 +135,64 @@ impl AlphaDBVerification {
             return Err(no_engine_error);
         }
 
-        let config = get_engine_config(engine).ok_or_else(|| AlphaDBError {
-            message: format!("Engine '{}' is not supported. Supported engines: {:?}", engine, SUPPORTED_ENGINES),
-            ..Default::default()
-        })?;
+        let config = get_supported_engine_config(engine)?;
 
         Ok(AlphaDBVerification {
             version_list: get_version_array(&version_source)?.clone(),
             version_source,
             issues: Vec::new(),
             config,
+            verification_config: VerificationConfig::default(),
+            source_map,
+            rules: Ruleset::default(),
         })
     }
 
+    /// Disable rules or override their levels, see `VerificationConfig`
+    pub fn with_config(mut self, verification_config: VerificationConfig) -> Self {
+        self.verification_config = verification_config;
+        self
+    }
+
+    /// Check the version source with a custom rule next to the built-in rules
+    pub fn with_rule(mut self, rule: impl VerificationRule + 'static) -> Self {
+        self.rules = self.rules.with_rule(rule);
+        self
+    }
+
+    /// Check the version source with every rule of a ruleset, see `Ruleset`
+    pub fn with_ruleset(mut self, ruleset: Ruleset) -> Self {
+        self.rules = self.rules.with_ruleset(ruleset);
+        self
+    }
+
+    /// The custom rules added with `with_rule` and `with_ruleset`, to describe their
+    /// issues in a report, see `verification_report`
+    pub fn custom_rules(&self) -> Vec<Rule> {
+        self.rules.rules()
+    }
+
+    /// Locate issues with a source map of the files the version source was read from,
+    /// e.g. from `build_version_source_from_dir_with_source_map`. By default issues are
+    /// located in the version source string passed to `new`.
+    pub fn with_source_map(mut self, source_map: SourceMap) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
     /// Loop over entire version source and verify if it will
     /// convert to MySQL queries without errors.
     /// Will Return true if no issues are found, else it will return a
     /// list with all issues and their levels.
     pub fn verify(&mut self) -> Result<(), Vec<VerificationIssue>> {
+        // Verify the column types the portable types become on this engine
+        if let Ok(engine) = self.config.name.parse::<AlphaDBEngine>() {
+            resolve_portable_column_types(&mut self.version_source, engine);
+            if let Ok(versions) = get_version_array(&self.version_source) {
+                self.version_list = versions.clone();
+            }
+        }
+
         // Run verify hooks at start
         for hook in self.config.verification_hooks.verify {
             let params = VerifyHookParams {
@@ -116,24 +203,25 @@ impl AlphaDBVerification {
                     level: draft.level,
                     message: draft.message,
                     version_trace: VersionTrace::new(),
+                    rule: Some(draft.rule),
+                    location: None,
                 });
             }
         }
 
+        let custom_rules = self.rules.rules();
+        self.verification_config.verify(&custom_rules, &mut self.issues);
+
+        // Rules ignored per version, by the first element of the version trace
+        let mut ignored: Vec<(String, Vec<&'static str>)> = Vec::new();
+
         if !exists_in_object(&self.version_source, "name", &mut self.issues, &VersionTrace::new()) {
-            self.issues.add(VerificationIssue {
-                level: VerificationIssueLevel::Critical,
-                message: String::from("No rootlevel name specified."),
-                version_trace: VersionTrace::new(),
-            });
+            self.issues.add(MISSING_NAME.issue(String::from("No rootlevel name specified."), VersionTrace::new()));
         }
 
         if !exists_in_object(&self.version_source, "version", &mut self.issues, &VersionTrace::new()) {
-            self.issues.add(VerificationIssue {
-                level: VerificationIssueLevel::Low,
-                message: String::from("This version source does not contain any versions."),
-                version_trace: VersionTrace::new(),
-            });
+            self.issues
+                .add(NO_VERSIONS.issue(String::from("This version source does not contain any versions."), VersionTrace::new()));
         } else {
             for (i, version) in array_iter(&self.version_source["version"], &mut self.issues, &VersionTrace::from(["versions".to_string()]))
                 .iter()
@@ -144,11 +232,7 @@ impl AlphaDBVerification {
                 let mut version_trace = VersionTrace::from([version_output.clone()]);
 
                 if !exists_in_object(version, "_id", &mut self.issues, &version_trace) {
-                    self.issues.add(VerificationIssue {
-                        level: VerificationIssueLevel::Critical,
-                        message: "Missing a version number".to_string(),
-                        version_trace: VersionTrace::new(),
-                    });
+                    self.issues.add(MISSING_VERSION_NUMBER.issue("Missing a version number".to_string(), VersionTrace::new()));
                 } else {
                     match adb_get_json_string(&version["_id"]) {
                         Ok(v) => {
@@ -170,16 +254,23 @@ impl AlphaDBVerification {
                     }
                 }
 
+                ignored.push((version_output.clone(), ignored_rules(version, &version_trace, &custom_rules, &mut self.issues)));
+
                 // for method in version.as_object().unwrap().keys() {
                 for method in object_iter(version, &mut self.issues, &version_trace) {
                     match method.as_str() {
                         "_id" => continue,
+                        // Read by the safety lints
+                        "_acknowledged" => continue,
+                        "_ignore" => continue,
                         "createtable" => match self.createtable(&version["createtable"], &version_output) {
                             Ok(v) => v,
                             Err(e) => self.issues.add(VerificationIssue {
                                 message: e.message(),
                                 level: VerificationIssueLevel::Critical,
                                 version_trace: e.version_trace().clone(),
+                                rule: Some(INVALID_STRUCTURE.id),
+                                location: e.location.clone(),
                             }),
                         },
                         "altertable" => match self.altertable(&version["altertable"], &version_output, version_number) {
@@ -188,6 +279,8 @@ impl AlphaDBVerification {
                                 message: e.message(),
                                 level: VerificationIssueLevel::Critical,
                                 version_trace: e.version_trace().clone(),
+                                rule: Some(INVALID_STRUCTURE.id),
+                                location: e.location.clone(),
                             }),
                         },
                         "default_data" => match self.default_data(&version_output, version_number) {
@@ -196,20 +289,46 @@ impl AlphaDBVerification {
                                 message: e.message(),
                                 level: VerificationIssueLevel::Critical,
                                 version_trace: e.version_trace().clone(),
+                                rule: Some(INVALID_STRUCTURE.id),
+                                location: e.location.clone(),
                             }),
                         },
+                        // Verified by the engine's own hooks
+                        m if self.config.version_methods.contains(&m) => continue,
                         _ => {
-                            self.issues.add(VerificationIssue {
-                                level: VerificationIssueLevel::High,
-                                message: format!("Method '{method}' does not exist"),
-                                version_trace: VersionTrace::from([version_output.clone()]),
-                            });
+                            let message = match engine_configs().find(|c| c.version_methods.contains(&method.as_str())) {
+                                Some(c) => format!("Method '{method}' is specific to {} and not supported on {}", c.name, self.config.name),
+                                None => format!("Method '{method}' does not exist"),
+                            };
+                            self.issues.add(UNKNOWN_METHOD.issue(message, VersionTrace::from([version_output.clone()])));
                         }
                     }
                 }
+
+                verify_version_identifiers(version, &version_output, self.config, &mut self.issues);
+
+                // Indexes, foreign keys and column changes are checked against the schema and their history at this version
+                if version_number.is_some() {
+                    verify_version_indexes(&self.version_list, version, &version_output, self.config, &mut self.issues);
+                    verify_version_foreign_keys(&self.version_list, version, &version_output, &mut self.issues);
+                    lint_version(&self.version_list, version, &version_output, &mut self.issues);
+                    self.rules.verify_version(&self.version_list, version, &version_output, self.config.name, &mut self.issues);
+                }
             }
         }
 
+        let issues = std::mem::take(&mut self.issues).into_iter().filter(|issue| {
+            let (Some(rule), Some(version)) = (issue.rule, issue.version_trace.as_slice().first()) else {
+                return true;
+            };
+            !ignored.iter().any(|(v, rules)| v == version && rules.contains(&rule))
+        });
+        self.issues = self.verification_config.apply(&custom_rules, issues.collect());
+
+        for issue in self.issues.iter_mut().filter(|i| i.location.is_none()) {
+            issue.location = self.source_map.locate(&self.version_source, &issue.version_trace);
+        }
+
         if self.issues.is_empty() {
             Ok(())
         } else {
@@ -217,6 +336,55 @@ impl AlphaDBVerification {
         }
     }
 
+    /// Verify the version source for each of `engines` instead of its own engine, to check
+    /// that one version source can be applied to all of them. Issues are reported per engine,
+    /// in the order of `engines`. An issue found on every engine is listed under each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alphadb::verification::AlphaDBVerification;
+    ///
+    /// let version_source = r#"{"name": "shop", "engine": "postgres", "version": [
+    ///     {"_id": "0.0.1", "createextension": {"pgcrypto": {}}}
+    /// ]}"#;
+    ///
+    /// let mut verification = AlphaDBVerification::new(version_source.to_string()).unwrap();
+    /// let engine_issues = verification.verify_engines(&["postgres", "mysql"]).unwrap();
+    ///
+    /// assert!(engine_issues[0].issues.is_empty());
+    /// assert_eq!(engine_issues[1].issues[0].message, "Method 'createextension' is specific to postgres and not supported on mysql");
+    /// ```
+    pub fn verify_engines(&mut self, engines: &[&str]) -> Result<Vec<EngineIssues>, AlphaDBError> {
+        let configs = engines
+            .iter()
+            .map(|engine| get_supported_engine_config(engine))
+            .collect::<Result<Vec<&'static EngineConfig>, AlphaDBError>>()?;
+        let source_config = self.config;
+        // Every engine resolves the portable column types of the untranslated version source
+        let version_source = self.version_source.clone();
+        let version_list = self.version_list.clone();
+
+        let mut engine_issues = Vec::new();
+        for config in configs {
+            self.config = config;
+            self.version_source = version_source.clone();
+            self.version_list = version_list.clone();
+            self.issues.clear();
+            engine_issues.push(EngineIssues {
+                engine: config.name,
+                issues: self.verify().err().unwrap_or_default(),
+            });
+        }
+
+        self.config = source_config;
+        self.version_source = version_source;
+        self.version_list = version_list;
+        self.issues.clear();
+
+        Ok(engine_issues)
+    }
+
     fn createtable(&mut self, createtable: &Value, version_output: &str) -> Result<(), AlphaDBError> {
         let mut version_trace = VersionTrace::new();
         version_trace.push(version_output.to_string());
@@ -225,11 +393,7 @@ impl AlphaDBVerification {
         match adb_get_json_object(createtable) {
             Ok(ct) => {
                 if ct.is_empty() {
-                    self.issues.push(VerificationIssue {
-                        level: VerificationIssueLevel::Low,
-                        message: "Does not contain any data".to_string(),
-                        version_trace: version_trace.clone(),
-                    });
+                    self.issues.push(EMPTY_BLOCK.issue("Does not contain any data".to_string(), version_trace.clone()));
 
                     return Ok(());
                 }
@@ -248,6 +412,8 @@ impl AlphaDBVerification {
                                 level: draft.level,
                                 message: draft.message,
                                 version_trace: version_trace.clone(),
+                                rule: Some(draft.rule),
+                                location: None,
                             });
                         }
                     }
@@ -288,6 +454,11 @@ impl AlphaDBVerification {
                             continue;
                         }
 
+                        if self.config.non_column_table_keys.contains(&column.as_str()) {
+                            version_trace.pop();
+                            continue;
+                        }
+
                         self.verify_column_compatibility(table.as_str(), column, &createtable[table][column].clone(), "createtable", version_output)?;
                         version_trace.pop();
                     }
@@ -307,11 +478,7 @@ impl AlphaDBVerification {
         let mut version_trace = VersionTrace::from([version_output, "altertable"]);
 
         if altertable.as_object().unwrap().is_empty() {
-            self.issues.push(VerificationIssue {
-                level: VerificationIssueLevel::Low,
-                message: "Does not contain any data".to_string(),
-                version_trace,
-            });
+            self.issues.push(EMPTY_BLOCK.issue("Does not contain any data".to_string(), version_trace));
 
             return Ok(());
         }
@@ -330,6 +497,8 @@ impl AlphaDBVerification {
                         level: draft.level,
                         message: draft.message,
                         version_trace: version_trace.clone(),
+                        rule: Some(draft.rule),
+                        location: None,
                     });
                 }
             }
@@ -356,6 +525,8 @@ impl AlphaDBVerification {
                                     level: VerificationIssueLevel::High,
                                     message: e.message(),
                                     version_trace: version_trace.clone(),
+                                    rule: Some(INVALID_STRUCTURE.id),
+                                    location: None,
                                 });
                             }
 
@@ -367,16 +538,10 @@ impl AlphaDBVerification {
                         if let Some(dropcol) = dropcol.as_str() {
                             if let Some(primary_key) = primary_key {
                                 if dropcol == primary_key {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Low,
-                                        message: format!("Column {dropcol} is the tables current primary key"),
-                                        version_trace: VersionTrace::from([
-                                            version_output.to_string(),
-                                            "altertable".to_string(),
-                                            format!("table:{table}"),
-                                            "dropcolumn".to_string(),
-                                        ]),
-                                    });
+                                    self.issues.push(DROPPED_PRIMARY_KEY.issue(
+                                        format!("Column {dropcol} is the tables current primary key"),
+                                        VersionTrace::from([version_output.to_string(), "altertable".to_string(), format!("table:{table}"), "dropcolumn".to_string()]),
+                                    ));
                                 }
                             }
                         }
@@ -397,196 +562,71 @@ impl AlphaDBVerification {
 
     pub fn default_data(&mut self, version_output: &str, version_number: Option<&str>) -> Result<(), AlphaDBError> {
         let mut version_trace = VersionTrace::from([version_output, "default_data"]);
-        let mut table_version_trace = VersionTrace::from([version_output.to_string()]);
 
-        for version in &self.version_list {
-            let parsed_version_number = match version_number {
-                Some(v) => parse_version_number(v, &mut self.issues, &VersionTrace::from([v.to_string()])),
-                None => 0,
-            };
-
-            // Should only process the versions up to the current version
-            let loop_version_number = get_json_string(&version["_id"], &mut self.issues, &version_trace);
-            match adb_parse_version_number(loop_version_number) {
-                Ok(vn) => {
-                    if vn > parsed_version_number {
-                        break;
-                    }
-                }
+        // Without a valid version number it's not possible to determine the table schema
+        let Some(version_number) = version_number else {
+            return Ok(());
+        };
 
-                // When the version number cannot be parsed, proccessing the version would be
-                // useless as we cannot reliable determine if the version is preceding or following
-                // the current version
-                Err(_) => {
-                    break;
-                }
+        let consolidated_default_data = match consolidate_default_data(&self.version_list, Some(version_number)) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(AlphaDBError {
+                    message: e.message(),
+                    error: e.error(),
+                    version_trace,
+                    location: None,
+                });
             }
-            let consolidated_default_data = match consolidate_default_data(&self.version_list, version_number) {
-                Ok(c) => c,
-                Err(e) => {
-                    return Err(AlphaDBError {
-                        message: e.message(),
-                        error: e.error(),
-                        version_trace,
-                    });
-                }
-            };
+        };
 
-            for table in object_iter(&consolidated_default_data, &mut self.issues, &version_trace) {
-                version_trace.push(format!("table:{table}"));
-                table_version_trace.push(format!("table:{table}"));
+        // Only the tables seeded in this version are verified, earlier rows were verified
+        // with their own version
+        let Some(version) = self.version_list.iter().find(|v| v["_id"] == version_number) else {
+            return Ok(());
+        };
+        let tables: Vec<String> = get_object_keys(&version["default_data"], &mut self.issues, &version_trace).into_iter().cloned().collect();
 
-                for hook in self.config.verification_hooks.default_data {
-                    let params = DefaultDataHookParams {
-                        table_name: table,
-                        default_data: &consolidated_default_data[table],
-                        version: version_output,
-                    };
-                    if let Err(draft) = hook(&params) {
-                        self.issues.push(VerificationIssue {
-                            level: draft.level,
-                            message: draft.message,
-                            version_trace: version_trace.clone(),
-                        });
-                    }
-                }
+        for table in &tables {
+            version_trace.push(format!("table:{table}"));
 
-                let consolidated_table = match consolidate_table(&self.version_list, table, Some(loop_version_number)) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        println!("{}", e.error());
-                        return Err(AlphaDBError {
-                            message: e.message(),
-                            error: e.error(),
-                            version_trace,
-                        });
-                    }
+            for hook in self.config.verification_hooks.default_data {
+                let params = DefaultDataHookParams {
+                    table_name: table,
+                    default_data: &consolidated_default_data[table],
+                    version: version_output,
                 };
-
-                if let Some(version_number) = version_number {
-                    if loop_version_number == version_number {
-                        for (i, dataset) in array_iter(&consolidated_default_data[table], &mut self.issues, &version_trace).iter().enumerate() {
-                            version_trace.push(format!("item:{i}"));
-                            let columns = get_object_keys(&consolidated_table, &mut self.issues, &table_version_trace);
-
-                            for column in object_iter(dataset, &mut self.issues, &version_trace) {
-                                if !columns.contains(&column) {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Critical,
-                                        message: format!("Default data for column {column} is specified, but the column does not exist in the table."),
-                                        version_trace: version_trace.clone(),
-                                    });
-                                }
-                            }
-                            version_trace.pop();
-                        }
-                    }
+                if let Err(draft) = hook(&params) {
+                    self.issues.push(VerificationIssue {
+                        level: draft.level,
+                        message: draft.message,
+                        version_trace: version_trace.clone(),
+                        rule: Some(draft.rule),
+                        location: None,
+                    });
                 }
+            }
 
-                for column in object_iter(&consolidated_table, &mut self.issues, &table_version_trace) {
-                    version_trace.push(format!("column:{column}"));
-                    table_version_trace.push(format!("column:{column}"));
-
-                    if !self.config.non_column_table_keys.contains(&column.as_str()) {
-                        let null_not_allowed = !exists_in_object(&consolidated_table[column], "null", &mut self.issues, &table_version_trace)
-                            || !get_json_boolean(&consolidated_table[column]["null"], &mut self.issues, &table_version_trace);
-                        let has_no_default = !exists_in_object(&consolidated_table[column], "default", &mut self.issues, &table_version_trace)
-                            || get_json_string(&consolidated_table[column]["default"], &mut self.issues, &table_version_trace).is_empty();
-
-                        let mut needs_default_data = true;
-                        if !null_not_allowed {
-                            needs_default_data = false;
-                        }
-
-                        if !has_no_default {
-                            needs_default_data = false;
-                        }
-
-                        // If the column is not allowed to have a NULL value, default data is
-                        // required to be present
-                        if needs_default_data {
-                            for (i, dataset) in array_iter(&consolidated_default_data[table], &mut self.issues, &table_version_trace).iter().enumerate() {
-                                version_trace.push(format!("item:{i}"));
-                                let col_type = get_json_string(&consolidated_table[column]["type"], &mut self.issues, &table_version_trace);
-
-                                if !exists_in_object(dataset, column, &mut self.issues, &version_trace) {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Critical,
-                                        message: format!("Column {column} is not allowed to be NULL, so default data is required to be specified."),
-                                        version_trace: version_trace.clone(),
-                                    });
-
-                                    version_trace.pop();
-                                    continue;
-                                }
-
-                                if self.config.string_columns.contains(&col_type) && adb_get_json_string(&dataset[column]).is_err() {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Critical,
-                                        message: format!("Default data for column type `{col_type}` is required to be of type string"),
-                                        version_trace: version_trace.clone(),
-                                    });
-                                }
-
-                                if self.config.int_columns.contains(&col_type) && adb_get_json_int(&dataset[column]).is_err() {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Critical,
-                                        message: format!("Default data for column type `{col_type}` is required to be of type int"),
-                                        version_trace: version_trace.clone(),
-                                    });
-                                }
-
-                                if self.config.float_columns.contains(&col_type) && adb_get_json_float(&dataset[column]).is_err() {
-                                    self.issues.push(VerificationIssue {
-                                        level: VerificationIssueLevel::Critical,
-                                        message: format!("Default data for column type `{col_type}` is required to be of type float"),
-                                        version_trace: version_trace.clone(),
-                                    });
-                                }
-
-                                version_trace.pop();
-                            }
-
-                            // TODO right now the issue is generated for every following version as well. Find
-                            // a way to only add the issue once
-                            let primary_key = get_primary_key(&self.version_list, table, version_number)?.unwrap_or_default();
-
-                            if primary_key == column
-                                || (exists_in_object(&consolidated_table[column], "unique", &mut self.issues, &table_version_trace)
-                                    && get_json_boolean(&consolidated_table[column]["unique"], &mut self.issues, &table_version_trace))
-                            {
-                                let mut column_values: Vec<String> = Vec::new();
-                                for (i, dataset) in array_iter(&consolidated_default_data[table], &mut self.issues, &table_version_trace).iter().enumerate() {
-                                    version_trace.push(format!("item:{i}"));
-                                    let value = dataset[column].to_string();
-
-                                    if column_values.contains(&value) {
-                                        let message = match  primary_key == column {
-                                            true => format!("Column `{column}` is the table's primary key so it's value should be unique, but the value `{value}` is previously specified as default data"),
-                                            false => format!("Column `{column}` has the UNIQUE key, but the value `{value}` is previously specified as default data")
-
-                                        };
-
-                                        self.issues.push(VerificationIssue {
-                                            level: VerificationIssueLevel::Critical,
-                                            message,
-                                            version_trace: version_trace.clone(),
-                                        });
-                                    }
-
-                                    column_values.push(value);
-                                    version_trace.pop();
-                                }
-                            }
-                        }
-                    }
-
-                    version_trace.pop();
-                    table_version_trace.pop();
-                }
-                version_trace.pop();
-                table_version_trace.pop();
+            let items = version_items(version, table, &consolidated_default_data);
+            if let Err(e) = verify_table_default_data(
+                &self.version_list,
+                table,
+                version_number,
+                &consolidated_default_data,
+                &items,
+                self.config,
+                &mut self.issues,
+                &version_trace,
+            ) {
+                return Err(AlphaDBError {
+                    message: e.message(),
+                    error: e.error(),
+                    version_trace,
+                    location: None,
+                });
             }
+
+            version_trace.pop();
         }
 
         Ok(())
@@ -602,15 +642,25 @@ impl AlphaDBVerification {
         for rule in self.config.attribute_compatibility_rules {
             if let Err(incompatible_keys) = check_column_attributes_compatibility(rule, &data_keys) {
                 for key in incompatible_keys {
-                    self.issues.push(VerificationIssue {
-                        level: VerificationIssueLevel::Critical,
-                        message: format!("Column attributes {} and {} are incompatible", rule.attribute.to_uppercase(), key.to_uppercase()),
-                        version_trace: version_trace.clone(),
-                    });
+                    self.issues.push(INCOMPATIBLE_ATTRIBUTES.issue(
+                        format!("Column attributes {} and {} are incompatible", rule.attribute.to_uppercase(), key.to_uppercase()),
+                        version_trace.clone(),
+                    ));
                 }
             }
         }
 
+        // Only a type set in this block, an unchanged type was reported in the version that set it
+        let supports = |config: &EngineConfig, column_type: &str| config.supported_column_types.iter().any(|t| t.eq_ignore_ascii_case(column_type));
+        if let Some(column_type) = data["type"].as_str().filter(|t| !supports(self.config, t)) {
+            let supported_on: Vec<&str> = engine_configs().filter(|c| supports(c, column_type)).map(|c| c.name).collect();
+            let message = match supported_on.is_empty() {
+                true => format!("Column type {column_type} is not supported on {}", self.config.name),
+                false => format!("Column type {column_type} is not supported on {}, but is on {}", self.config.name, supported_on.join(", ")),
+            };
+            self.issues.push(UNSUPPORTED_COLUMN_TYPE.issue(message, version_trace.clone()));
+        }
+
         // If a column type is not defined, we can not check the types compatibility
         let column_type = match get_column_type(&self.version_list, column, table, adb_parse_version_number(version)?) {
             Ok(ct) => ct,
@@ -650,20 +700,16 @@ impl AlphaDBVerification {
                     const VALID_VALUES: [&str; 4] = ["true", "false", "TRUE", "FALSE"];
 
                     if !default_value.is_empty() && !VALID_VALUES.contains(&default_value.as_str()) {
-                        self.issues.push(VerificationIssue {
-                            level: VerificationIssueLevel::High,
-                            message: "The default value for a boolean field must be either 'true' or 'false'. Any other defined value will be treated as false.".to_string(),
-                            version_trace: version_trace.clone(),
-                        });
+                        self.issues.push(INVALID_BOOLEAN_DEFAULT.issue(
+                            "The default value for a boolean field must be either 'true' or 'false'. Any other defined value will be treated as false.".to_string(),
+                            version_trace.clone(),
+                        ));
                     }
                 }
             }
         } else {
-            self.issues.push(VerificationIssue {
-                level: VerificationIssueLevel::Critical,
-                message: "Does not contain a column type".to_string(),
-                version_trace: version_trace.clone(),
-            });
+            self.issues
+                .push(MISSING_COLUMN_TYPE.issue("Does not contain a column type".to_string(), version_trace.clone()));
         }
 
         for hook in self.config.verification_hooks.column_compatibility {
@@ -679,6 +725,8 @@ impl AlphaDBVerification {
                     level: draft.level,
                     message: draft.message,
                     version_trace: version_trace.clone(),
+                    rule: Some(draft.rule),
+                    location: None,
                 });
             }
         }
@@ -686,3 +734,95 @@ impl AlphaDBVerification {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "mysql", feature = "postgres", feature = "sqlite"))]
+mod verification_tests {
+    use super::AlphaDBVerification;
+    use serde_json::{json, Value};
+
+    fn verification(version: Value) -> AlphaDBVerification {
+        let version_source = json!({"name": "shop", "engine": "mysql", "version": [version]});
+        AlphaDBVerification::new(version_source.to_string()).unwrap()
+    }
+
+    #[test]
+    fn verify_engines() {
+        let mut verification = verification(json!({"_id": "0.0.1",
+            "createtable": {"product": {"primary_key": "id", "id": {"type": "INT"}, "data": {"type": "JSONB"}}},
+            "createextension": {"pgcrypto": {}},
+        }));
+
+        let engine_issues = verification.verify_engines(&["mysql", "postgres"]).unwrap();
+        let issues = |i: usize| -> Vec<(Option<&str>, String)> { engine_issues[i].issues.iter().map(|i| (i.rule, i.message.clone())).collect() };
+
+        assert_eq!(engine_issues[0].engine, "mysql");
+        assert_eq!(
+            issues(0),
+            vec![
+                (Some("ADB0016"), "Column type JSONB is not supported on mysql, but is on postgres".to_string()),
+                (Some("ADB0005"), "Method 'createextension' is specific to postgres and not supported on mysql".to_string()),
+            ]
+        );
+
+        assert_eq!(engine_issues[1].engine, "postgres");
+        assert_eq!(
+            issues(1),
+            vec![(
+                Some("ADB0016"),
+                "Column type INT is not supported on postgres, but is on mysql, mariadb, sqlite".to_string()
+            )]
+        );
+
+        // The version source is still verified for its own engine
+        assert_eq!(verification.verify().unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn verify_portable_engines() {
+        let mut verification = verification(json!({"_id": "0.0.1",
+            "createtable": {"product": {"primary_key": "id", "id": {"type": "BIGINT"}, "name": {"type": "VARCHAR", "length": 50}}},
+        }));
+
+        let engine_issues = verification.verify_engines(&["mysql", "mariadb", "postgres", "sqlite"]).unwrap();
+        assert_eq!(
+            engine_issues.iter().map(|e| e.engine).collect::<Vec<&str>>(),
+            vec!["mysql", "mariadb", "postgres", "sqlite"]
+        );
+        assert!(engine_issues.iter().all(|e| e.issues.is_empty()));
+
+        assert!(verification.verify_engines(&["mysql", "oracle"]).is_err());
+    }
+
+    #[test]
+    fn portable_column_types() {
+        let mut verification = verification(json!({"_id": "0.0.1",
+            "createtable": {"product": {
+                "primary_key": "id",
+                "id": {"type": "INTEGER"},
+                "data": {"type": "JSON"},
+                "created_at": {"type": "datetime"},
+                "stock": {"type": "int"},
+            }},
+        }));
+
+        let engine_issues = verification.verify_engines(&["mysql", "postgres"]).unwrap();
+        let messages = |i: usize| -> Vec<String> { engine_issues[i].issues.iter().map(|i| i.message.clone()).collect() };
+
+        // The case of a column type does not matter
+        assert!(messages(0).is_empty());
+        assert_eq!(
+            messages(1),
+            vec!["Column type int is not supported on postgres, but is on mysql, mariadb, sqlite".to_string()]
+        );
+    }
+
+    #[test]
+    fn portable_boolean_default() {
+        let mut verification = verification(json!({"_id": "0.0.1",
+            "createtable": {"product": {"primary_key": "id", "id": {"type": "INT"}, "active": {"type": "BOOLEAN", "default": "yes"}}},
+        }));
+
+        let issues = verification.verify().unwrap_err();
+        assert!(issues.iter().any(|i| i.rule == Some("ADB0013")));
+    }
+}
@@ -20,6 +20,7 @@ use std::str::FromStr;
 use serde_json::{Map, Value};
 
 use crate::core::utils::errors::AlphaDBError;
+use crate::core::utils::source_map::SourceMap;
 use crate::core::utils::version_number::validate_version_number;
 use crate::engine::AlphaDBEngine;
 use crate::verification::VersionTrace;
@@ -30,15 +31,23 @@ use crate::verification::VersionTrace;
 /// provided. The `name` of the resulting version source is taken from the
 /// first source that defines one.
 pub fn combine_version_source_files(files: &[(String, PathBuf)], name: String, engine: AlphaDBEngine) -> Result<Value, AlphaDBError> {
+    combine_version_source_files_with_source_map(files, name, engine).map(|(version_source, _)| version_source)
+}
+
+/// Combine version source files like `combine_version_source_files`, and map every
+/// version to the file, line and column it was read from.
+pub fn combine_version_source_files_with_source_map(files: &[(String, PathBuf)], name: String, engine: AlphaDBEngine) -> Result<(Value, SourceMap), AlphaDBError> {
     if files.is_empty() {
         return Err(AlphaDBError {
             message: "No version source files were provided. At least one version source is required to build a combined version source.".to_string(),
             error: "no-version-source-files-provided".to_string(),
             version_trace: VersionTrace::new(),
+            location: None,
         });
     }
 
     let mut version: Vec<Value> = Vec::new();
+    let mut source_map = SourceMap::default();
 
     for file in files {
         let file_contents = match fs::read_to_string(&file.1) {
@@ -46,7 +55,7 @@ pub fn combine_version_source_files(files: &[(String, PathBuf)], name: String, e
             Err(_) => panic!("An error occured while opening the version source file!"),
         };
 
-        let mut parsed: serde_json::Value = serde_json::from_str(&file_contents)?;
+        let mut parsed = parse_json_file(&file_contents, &file.1)?;
 
         let parsed_map = match parsed.as_object_mut() {
             Some(map) => map,
@@ -58,11 +67,19 @@ pub fn combine_version_source_files(files: &[(String, PathBuf)], name: String, e
                     ),
                     error: "version-source-not-an-object".to_string(),
                     version_trace: VersionTrace::new(),
+                    location: None,
                 })
             }
         };
 
         parsed_map.insert("_id".to_string(), Value::String(file.0.clone()));
+
+        // The version number comes from the file name, so it points at the start of the file
+        let mut file_map = SourceMap::from_json(&file_contents, Some(&file.1));
+        if let Some(start) = file_map.get(&[]).cloned() {
+            file_map.set(vec!["_id".to_string()], start);
+        }
+        source_map.insert(&["version".to_string(), version.len().to_string()], file_map);
         version.push(parsed);
     }
 
@@ -73,7 +90,18 @@ pub fn combine_version_source_files(files: &[(String, PathBuf)], name: String, e
 
     root.insert("version".to_string(), Value::Array(version));
 
-    Ok(Value::Object(root))
+    Ok((Value::Object(root), source_map))
+}
+
+/// Parse a JSON file, pointing syntax errors at the file
+fn parse_json_file(contents: &str, path: &Path) -> Result<Value, AlphaDBError> {
+    serde_json::from_str(contents).map_err(|e| {
+        let mut error = AlphaDBError::from(e);
+        if let Some(location) = error.location.as_mut() {
+            location.file = Some(path.to_path_buf());
+        }
+        error
+    })
 }
 
 struct VersionSourceConfig {
@@ -87,7 +115,7 @@ fn parse_config_file(path: &PathBuf) -> Result<VersionSourceConfig, AlphaDBError
         Err(_) => panic!("An error occured while opening the version source file!"),
     };
 
-    let contents: serde_json::Value = serde_json::from_str(&file_contents)?;
+    let contents = parse_json_file(&file_contents, path)?;
 
     let name = match contents["name"].as_str() {
         Some(n) => n.to_string(),
@@ -96,6 +124,7 @@ fn parse_config_file(path: &PathBuf) -> Result<VersionSourceConfig, AlphaDBError
                 message: "Name not defined".to_string(),
                 error: "name-not-defined".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             })
         }
     };
@@ -107,6 +136,7 @@ fn parse_config_file(path: &PathBuf) -> Result<VersionSourceConfig, AlphaDBError
                 message: "Name not defined".to_string(),
                 error: "name-not-defined".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             })
         }
     };
@@ -139,6 +169,7 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
                 ),
                 error: "no-config-file-found".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             })
         }
         1 => found_config_files[0],
@@ -151,6 +182,7 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
                 ),
                 error: "multiple-config-files-found".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             })
         }
     };
@@ -164,6 +196,7 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
                 message: format!("Failed to read the specified directory ({}): {}", path.display(), e),
                 error: "directory-read-failed".to_string(),
                 version_trace: VersionTrace::new(),
+                location: None,
             })
         }
     };
@@ -177,6 +210,7 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
                     message: format!("Failed to read a directory entry in {}: {}", path.display(), e),
                     error: "directory-entry-read-failed".to_string(),
                     version_trace: VersionTrace::new(),
+                    location: None,
                 })
             }
         };
@@ -201,6 +235,7 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
                         ),
                         error: "version-number-parse-failed".to_string(),
                         version_trace: VersionTrace::new(),
+                        location: None,
                     })
                 }
             };
@@ -213,8 +248,11 @@ pub fn gather_version_source_files(path: &PathBuf) -> Result<VersionSourceParts,
 }
 
 pub fn build_version_source_from_dir(path: &PathBuf) -> Result<Value, AlphaDBError> {
-    let parts = gather_version_source_files(path)?;
-    let combined = combine_version_source_files(&parts.files, parts.config.name, parts.config.engine)?;
+    build_version_source_from_dir_with_source_map(path).map(|(version_source, _)| version_source)
+}
 
-    Ok(combined)
+/// Build a version source from a directory, with a source map pointing into its version files
+pub fn build_version_source_from_dir_with_source_map(path: &PathBuf) -> Result<(Value, SourceMap), AlphaDBError> {
+    let parts = gather_version_source_files(path)?;
+    combine_version_source_files_with_source_map(&parts.files, parts.config.name, parts.config.engine)
 }
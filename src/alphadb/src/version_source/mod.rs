@@ -1,3 +1,6 @@
 mod combine;
 
-pub use combine::{build_version_source_from_dir, combine_version_source_files, gather_version_source_files};
+pub use combine::{
+    build_version_source_from_dir, build_version_source_from_dir_with_source_map, combine_version_source_files, combine_version_source_files_with_source_map,
+    gather_version_source_files,
+};
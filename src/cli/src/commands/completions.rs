@@ -0,0 +1,73 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::connection::get_session_labels;
+use crate::config::version_source::get_version_sources;
+use crate::error;
+use clap_complete::engine::{CompletionCandidate, PathCompleter, ValueCompleter};
+use clap_complete::env::{Bash, EnvCompleter, Fish, Powershell, Zsh};
+use std::ffi::OsStr;
+use std::io;
+use std::path::PathBuf;
+
+pub const SHELLS: [&str; 4] = ["bash", "zsh", "fish", "powershell"];
+
+/// Environment variable the shell sets when it asks for completions
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Saved session labels, for `connect`
+pub fn complete_session_label() -> Vec<CompletionCandidate> {
+    get_session_labels().into_iter().map(CompletionCandidate::new).collect()
+}
+
+/// Saved version source labels, followed by matching paths
+pub fn complete_version_source(current: &OsStr) -> Vec<CompletionCandidate> {
+    let current_str = current.to_string_lossy();
+
+    let mut candidates: Vec<CompletionCandidate> = get_version_sources()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|label| label.starts_with(current_str.as_ref()))
+        .map(|label| CompletionCandidate::new(label).help(Some("saved version source".into())))
+        .collect();
+
+    candidates.extend(PathCompleter::any().complete(current));
+
+    candidates
+}
+
+/// Print the completion script for `shell`
+///
+/// The script calls back into AlphaDB with `COMPLETE=<shell>` set, so saved
+/// session and version source labels are completed from the current config.
+pub fn completions(shell: &str) {
+    let completer: &dyn EnvCompleter = match shell {
+        "bash" => &Bash,
+        "zsh" => &Zsh,
+        "fish" => &Fish,
+        "powershell" => &Powershell,
+        _ => error!(format!("Unsupported shell '{shell}'")),
+    };
+
+    // Complete the binary under the name it was invoked with
+    let bin = std::env::args()
+        .next()
+        .and_then(|arg| PathBuf::from(arg).file_name().map(|f| f.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "alphadb".to_string());
+
+    if let Err(e) = completer.write_registration(COMPLETE_VAR, &bin.replace('-', "_"), &bin, &bin, &mut io::stdout()) {
+        error!(format!("Unable to write completions: {e}"));
+    }
+}
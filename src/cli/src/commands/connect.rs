@@ -13,27 +13,36 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::config::connection::{get_connections, new_mysql_connection, set_active_connection};
+use crate::config::connection::{get_connections, get_session_labels, new_mysql_connection, set_active_connection};
 use crate::config::setup::Config;
 use crate::error;
 use crate::utils::{abort, title};
 use colored::Colorize;
 use inquire::Select;
 
-pub struct Connection {
-    pub host: String,
-    pub user: String,
-    pub password: String,
-    pub database: String,
-    pub port: u16,
-}
-
 /// Select a connection to activate
 ///
 /// - config: AlphaDB configuration
-pub fn connect(config: &Config) {
+/// - label: Saved connection to activate without prompting
+pub fn connect(config: &Config, label: Option<&String>) {
     title("Connect");
 
+    if let Some(label) = label {
+        if !get_session_labels().contains(label) {
+            error!(format!("No saved connection named '{}'", label));
+        }
+
+        set_active_connection(label);
+
+        println!(
+            "\n{} {} {}\n",
+            "Database connection".green(),
+            label.cyan(),
+            "is now active".green()
+        );
+        return;
+    }
+
     // Get all available connections as a vector of strings
     if let Some(mut connections) = get_connections() {
         connections.push("++ New connection".to_string());
@@ -0,0 +1,197 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::setup::Config;
+use crate::config::version_source::select_version_source;
+use crate::error;
+use crate::utils::{read_version_source, title};
+use alphadb::core::utils::consolidate::consolidate_schema;
+use alphadb::core::utils::diff::{diff_schema, AttributeDiff, ColumnDiff, TableDiff};
+use alphadb::core::utils::version_number::sort_versions;
+use alphadb::core::utils::version_source::parse_version_source_string;
+use alphadb::prelude::{AlphaDBError, Get};
+use colored::Colorize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Consolidate the schema of a version source at `version` (latest when `None`)
+fn schema_at(vs_file: &Path, version: Option<&str>) -> Result<Value, AlphaDBError> {
+    let mut version_source = parse_version_source_string(read_version_source(&vs_file.to_path_buf()))?;
+
+    let versions = match version_source["version"].as_array_mut() {
+        Some(v) => v,
+        None => {
+            return Err(AlphaDBError {
+                message: format!("The version source at '{}' does not contain any versions", vs_file.to_string_lossy()),
+                ..Default::default()
+            })
+        }
+    };
+
+    sort_versions(versions)?;
+    consolidate_schema(versions, version)
+}
+
+/// Format a JSON value for display. Strings are shown without quotes.
+fn format_value(value: &Option<Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(v) => v.to_string(),
+        None => "(unset)".to_string(),
+    }
+}
+
+/// Format a column or table definition on a single line
+fn format_definition(definition: &Value) -> String {
+    match definition.as_object() {
+        Some(o) => o
+            .iter()
+            .filter(|(k, _)| *k != "primary_key")
+            .map(|(k, v)| format!("{}={}", k, format_value(&Some(v.clone()))))
+            .collect::<Vec<String>>()
+            .join(" "),
+        None => definition.to_string(),
+    }
+}
+
+fn print_attribute(attribute: &AttributeDiff, indent: &str) {
+    println!(
+        "{}{}: {} -> {}",
+        indent,
+        attribute.attribute,
+        format_value(&attribute.from).red(),
+        format_value(&attribute.to).green()
+    );
+}
+
+fn print_columns(definition: &Value, prefix: &str) {
+    if let Some(columns) = definition.as_object() {
+        for (column, column_definition) in columns {
+            if column == "primary_key" {
+                continue;
+            }
+
+            println!("    {} {}  {}", prefix, column, format_definition(column_definition).dimmed());
+        }
+    }
+}
+
+fn print_diff(diff: &[TableDiff]) {
+    for table in diff {
+        match table {
+            TableDiff::Added { table, definition } => {
+                println!("{} {}", "+ table".green(), table.green());
+                print_columns(definition, &"+".green());
+            }
+            TableDiff::Removed { table, definition } => {
+                println!("{} {}", "- table".red(), table.red());
+                print_columns(definition, &"-".red());
+            }
+            TableDiff::Modified { table, primary_key, columns } => {
+                println!("{} {}", "~ table".yellow(), table.yellow());
+
+                if let Some(primary_key) = primary_key {
+                    print_attribute(primary_key, "    ");
+                }
+
+                for column in columns {
+                    match column {
+                        ColumnDiff::Added { column, definition } => {
+                            println!("    {} {}  {}", "+".green(), column, format_definition(definition).dimmed())
+                        }
+                        ColumnDiff::Removed { column, definition } => {
+                            println!("    {} {}  {}", "-".red(), column, format_definition(definition).dimmed())
+                        }
+                        ColumnDiff::Modified { column, attributes } => {
+                            println!("    {} {}", "~".yellow(), column);
+                            for attribute in attributes {
+                                print_attribute(attribute, "        ");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Show the schema differences between two version sources,
+/// or between two versions of the same version source
+///
+/// - config: AlphaDB configuration
+/// - sources: Zero, one or two version sources
+/// - from: Version of the first version source to compare (latest when `None`)
+/// - to: Version of the second version source to compare (latest when `None`)
+/// - json: Print the diff as JSON instead of text
+pub fn diff(config: &Config, sources: Vec<PathBuf>, from: Option<String>, to: Option<String>, json: bool) {
+    if !json {
+        title("Diff");
+    }
+
+    let (from_file, to_file) = match sources.len() {
+        2 => (sources[0].clone(), sources[1].clone()),
+        _ => {
+            if from.is_none() {
+                error!("Specify --from when comparing versions of a single version source".to_string());
+            }
+
+            let vs_file = match sources.first() {
+                Some(vs) => vs.clone(),
+                None => match select_version_source(config) {
+                    Some(p) => p,
+                    None => error!("No version source was selected".to_string()),
+                },
+            };
+
+            (vs_file.clone(), vs_file)
+        }
+    };
+
+    let from_schema = match schema_at(&from_file, from.as_deref()) {
+        Ok(s) => s,
+        Err(e) => error!(e.message()),
+    };
+
+    let to_schema = match schema_at(&to_file, to.as_deref()) {
+        Ok(s) => s,
+        Err(e) => error!(e.message()),
+    };
+
+    let diff = match diff_schema(&from_schema, &to_schema) {
+        Ok(d) => d,
+        Err(e) => error!(e.message()),
+    };
+
+    if json {
+        match serde_json::to_string_pretty(&diff) {
+            Ok(s) => println!("{s}"),
+            Err(e) => error!(format!("Unable to serialize the diff: {e}")),
+        }
+        return;
+    }
+
+    let side = |file: &PathBuf, version: &Option<String>| format!("{} ({})", file.to_string_lossy(), version.as_deref().unwrap_or("latest"));
+    println!("{} {} {}\n", side(&from_file, &from).blue(), "->".cyan(), side(&to_file, &to).blue());
+
+    if diff.is_empty() {
+        println!("{}\n", "No schema differences".green());
+        return;
+    }
+
+    print_diff(&diff);
+
+    // Empty line for better readability
+    println!("  ");
+}
@@ -0,0 +1,335 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::setup::Config;
+use crate::config::version_source::select_version_source;
+use crate::error;
+use crate::utils::{abort, read_version_source, title};
+use alphadb::core::utils::consolidate::table::consolidate_table;
+use alphadb::core::utils::version_number::sort_versions;
+use alphadb::core::utils::version_source::parse_version_source_string;
+use alphadb::prelude::Get;
+use alphadb::verification::{AlphaDBVerification, VerificationIssue, VerificationIssueLevel};
+use colored::{ColoredString, Colorize};
+use inquire::error::InquireError;
+use inquire::Select;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const MENU_VERSIONS: &str = "Browse versions";
+const MENU_TABLES: &str = "Browse tables";
+const MENU_QUIT: &str = "Quit";
+
+/// Prompt the user to pick one of `options`, returning its index.
+/// Returns `None` when the prompt is cancelled with escape, so the
+/// caller can go back to the previous menu.
+fn select_index(message: &str, options: Vec<String>, starting_cursor: usize, config: &Config) -> Option<usize> {
+    match Select::new(message, options)
+        .with_vim_mode(config.input.vim_bindings)
+        .with_starting_cursor(starting_cursor)
+        .with_page_size(15)
+        .raw_prompt()
+    {
+        Ok(choice) => Some(choice.index),
+        Err(InquireError::OperationCanceled) => None,
+        Err(InquireError::OperationInterrupted) => {
+            abort();
+            None
+        }
+        Err(_) => error!("An unexpected error occured".to_string()),
+    }
+}
+
+fn issue_label(level: VerificationIssueLevel) -> ColoredString {
+    match level {
+        VerificationIssueLevel::Low => "LOW VULNERABILITY:".on_white().black(),
+        VerificationIssueLevel::High => "HIGH VULNERABILITY:".on_yellow().black(),
+        VerificationIssueLevel::Critical => "CRITICAL:".on_red().black(),
+    }
+}
+
+/// The version trace of an issue without the `table:`, `column:` and `item:`
+/// prefixes some checks add, so it can be compared to a path in the version source.
+fn issue_path(issue: &VerificationIssue) -> Vec<String> {
+    issue
+        .version_trace
+        .iter()
+        .map(|item| match item.split_once(':') {
+            Some(("table" | "column" | "item", rest)) => rest.to_string(),
+            _ => item.clone(),
+        })
+        .collect()
+}
+
+/// Print the issues pointing to exactly `path` and mark them as shown
+fn print_issues_at(path: &[String], issues: &[VerificationIssue], shown: &mut [bool], indent: usize) {
+    for (i, issue) in issues.iter().enumerate() {
+        if !shown[i] && issue_path(issue) == path {
+            println!("{}{} {}", "  ".repeat(indent), issue_label(issue.level), issue.message);
+            shown[i] = true;
+        }
+    }
+}
+
+/// Format a JSON scalar for display. Strings are shown without quotes.
+fn format_scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+/// An object is shown on a single line when all its values are scalars,
+/// like a column definition
+fn is_flat(value: &Value) -> bool {
+    match value {
+        Value::Object(o) => o.values().all(|v| !v.is_object() && !v.is_array()),
+        _ => false,
+    }
+}
+
+fn format_flat(value: &Value) -> String {
+    match value {
+        Value::Object(o) => o.iter().map(|(k, v)| format!("{}={}", k, format_scalar(v))).collect::<Vec<String>>().join(" "),
+        _ => format_scalar(value),
+    }
+}
+
+/// Recursively print a part of the version source as a tree, with the
+/// verification issues inline under the node they point to
+fn print_tree(value: &Value, path: &mut Vec<String>, issues: &[VerificationIssue], shown: &mut [bool], indent: usize) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(o) => o.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Value::Array(a) => a.iter().enumerate().map(|(i, v)| (i.to_string(), v)).collect(),
+        _ => return,
+    };
+
+    for (key, child) in children {
+        let prefix = "  ".repeat(indent);
+        if is_flat(child) || !child.is_object() && !child.is_array() {
+            println!("{}{}  {}", prefix, key.cyan(), format_flat(child).dimmed());
+        } else {
+            println!("{}{}", prefix, key.cyan());
+        }
+
+        path.push(key);
+        print_issues_at(path, issues, shown, indent + 1);
+        if !is_flat(child) {
+            print_tree(child, path, issues, shown, indent + 1);
+        }
+        path.pop();
+    }
+}
+
+/// Print all operations of a single version
+fn print_version(version: &Value, issues: &[VerificationIssue]) {
+    let version_number = format_scalar(&version["_id"]);
+    let mut shown = vec![false; issues.len()];
+
+    println!("\n{} {}", "Version".green(), version_number.green());
+
+    let mut path = Vec::from([version_number]);
+    print_issues_at(&path, issues, &mut shown, 1);
+
+    if let Value::Object(o) = version {
+        for (key, value) in o {
+            if key == "_id" {
+                continue;
+            }
+
+            println!("  {}", key.green());
+            path.push(key.clone());
+            print_issues_at(&path, issues, &mut shown, 2);
+            print_tree(value, &mut path, issues, &mut shown, 2);
+            path.pop();
+        }
+    }
+
+    // Issues pointing inside this version that did not match a printed node
+    for (i, issue) in issues.iter().enumerate() {
+        if !shown[i] && issue_path(issue).first() == path.first() {
+            println!("  {} {}", issue_label(issue.level), issue.message);
+        }
+    }
+
+    println!("  ");
+}
+
+/// Number of issues that point to a version
+fn issue_count(version_number: &str, issues: &[VerificationIssue]) -> usize {
+    issues.iter().filter(|issue| issue.version_trace.iter().next().map(|v| v.as_str()) == Some(version_number)).count()
+}
+
+/// Every table that is created in the version source, in order of creation
+fn table_names(versions: &[Value]) -> Vec<String> {
+    let mut tables: Vec<String> = Vec::new();
+
+    for version in versions {
+        if let Some(createtable) = version["createtable"].as_object() {
+            for table in createtable.keys() {
+                if !tables.contains(table) {
+                    tables.push(table.clone());
+                }
+            }
+        }
+    }
+
+    tables
+}
+
+fn browse_versions(versions: &[Value], version_numbers: &[String], issues: &[VerificationIssue], config: &Config) {
+    let mut cursor = 0;
+
+    loop {
+        let options: Vec<String> = version_numbers
+            .iter()
+            .map(|v| match issue_count(v, issues) {
+                0 => v.clone(),
+                1 => format!("{v}  (1 issue)"),
+                n => format!("{v}  ({n} issues)"),
+            })
+            .collect();
+
+        let index = match select_index("Choose a version (esc to go back)", options, cursor, config) {
+            Some(i) => i,
+            None => return,
+        };
+
+        print_version(&versions[index], issues);
+        cursor = index;
+    }
+}
+
+fn browse_tables(versions: &[Value], version_numbers: &[String], config: &Config) {
+    let tables = table_names(versions);
+
+    if tables.is_empty() {
+        println!("{}\n", "This version source does not create any tables".yellow());
+        return;
+    }
+
+    loop {
+        let table_index = match select_index("Choose a table (esc to go back)", tables.clone(), 0, config) {
+            Some(i) => i,
+            None => return,
+        };
+        let table = &tables[table_index];
+
+        let version_index = match select_index(
+            &format!("Show '{table}' at version (esc to go back)"),
+            version_numbers.to_vec(),
+            version_numbers.len().saturating_sub(1),
+            config,
+        ) {
+            Some(i) => i,
+            None => continue,
+        };
+        let version_number = &version_numbers[version_index];
+
+        // consolidate_table takes the full version list and stops at the target version
+        let consolidated = match consolidate_table(&versions.to_vec(), table, Some(version_number)) {
+            Ok(t) => t,
+            Err(e) => {
+                println!("{} {}\n", "Unable to consolidate table:".red(), e.message());
+                continue;
+            }
+        };
+
+        println!("\n{} {} {} {}", "Table".green(), table.green(), "at version".green(), version_number.green());
+
+        let columns = match consolidated.as_object() {
+            Some(c) => c,
+            None => continue,
+        };
+
+        if columns.keys().all(|k| k == "primary_key") {
+            println!("  {}", "The table has no columns at this version".yellow());
+        }
+
+        for (column, definition) in columns {
+            if column == "primary_key" {
+                continue;
+            }
+
+            println!("  {}  {}", column.cyan(), format_flat(definition).dimmed());
+        }
+
+        if let Some(primary_key) = consolidated["primary_key"].as_str() {
+            println!("  {}  {}", "primary key".cyan(), primary_key);
+        }
+
+        println!("  ");
+    }
+}
+
+/// Interactively browse the versions of a version source and the
+/// consolidated schema of its tables
+pub fn explore(config: &Config, version_source: Option<PathBuf>) {
+    title("Explore Version Source");
+
+    let vs_file = match version_source {
+        Some(vs) => vs.to_path_buf(),
+        None => match select_version_source(config) {
+            Some(p) => p,
+            None => error!("No version source was selected".to_string()),
+        },
+    };
+
+    let vs = read_version_source(&vs_file);
+
+    let issues: Vec<VerificationIssue> = match AlphaDBVerification::new(vs.clone()) {
+        Ok(mut verification) => verification.verify().err().unwrap_or_default(),
+        Err(e) => {
+            println!("{} {}\n", "Verification issues will not be shown:".yellow(), e.message());
+            Vec::new()
+        }
+    };
+
+    let mut parsed = match parse_version_source_string(vs) {
+        Ok(v) => v,
+        Err(e) => error!(e.message()),
+    };
+
+    let mut versions: Vec<Value> = match parsed["version"].as_array_mut() {
+        Some(v) => std::mem::take(v),
+        None => error!("The version source does not contain any versions".to_string()),
+    };
+
+    if let Err(e) = sort_versions(&mut versions) {
+        error!(e.message());
+    }
+
+    let version_numbers: Vec<String> = versions.iter().map(|v| format_scalar(&v["_id"])).collect();
+
+    println!(
+        "{} {} versions, {}\n",
+        vs_file.to_string_lossy().blue(),
+        versions.len(),
+        match issues.len() {
+            0 => "no verification issues".green(),
+            n => format!("{n} verification issues").red(),
+        }
+    );
+
+    loop {
+        let menu = Vec::from([MENU_VERSIONS.to_string(), MENU_TABLES.to_string(), MENU_QUIT.to_string()]);
+
+        match select_index("What do you want to explore?", menu, 0, config) {
+            Some(0) => browse_versions(&versions, &version_numbers, &issues, config),
+            Some(1) => browse_tables(&versions, &version_numbers, config),
+            _ => return,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{dispatch::DbInstance, error, utils::title};
+use alphadb::prelude::Get;
+use colored::Colorize;
+
+/// Print every version applied to the database
+///
+/// - db: AlphaDB instance
+pub fn history(db: &mut DbInstance) {
+    title("History");
+
+    let history = match db.history() {
+        Ok(h) => h,
+        Err(e) => {
+            error!(format!("Unable to retrieve database history: {}", e.message()));
+        }
+    };
+
+    if history.is_empty() {
+        println!("{}", "No versions have been applied to this database yet".yellow());
+    }
+
+    for entry in history {
+        let template = match entry.template {
+            Some(t) => format!(" ({t})"),
+            None => String::new(),
+        };
+
+        println!("{}  {}{}", entry.applied_at.cyan(), entry.version, template);
+    }
+
+    // Empty line for better readability
+    println!("  ");
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error;
+use crate::parse::build_cli;
+use clap::Command;
+use clap_mangen::Man;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Render the man page for `cmd` to `<out_dir>/<name>.1`
+fn write_man_page(cmd: Command, name: &str, out_dir: &Path) {
+    let path = out_dir.join(format!("{name}.1"));
+    let mut buf: Vec<u8> = Vec::new();
+
+    if let Err(e) = Man::new(cmd).title(name).render(&mut buf) {
+        error!(format!("Unable to render man page '{name}': {e}"));
+    }
+
+    if let Err(e) = fs::write(&path, buf) {
+        error!(format!("Unable to write man page to '{}': {e}", path.to_string_lossy().cyan()));
+    }
+
+    println!("Written {}", path.to_string_lossy().cyan());
+}
+
+/// Generate man pages for the command and all visible subcommands
+///
+/// - out_dir: Directory to write the man pages to
+pub fn mangen(out_dir: PathBuf) {
+    if let Err(e) = fs::create_dir_all(&out_dir) {
+        error!(format!("Unable to create '{}': {e}", out_dir.to_string_lossy().cyan()));
+    }
+
+    let cmd = build_cli();
+    let bin = cmd.get_bin_name().unwrap_or("alphadb").to_string();
+
+    for subcommand in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        let name = format!("{}-{}", bin, subcommand.get_name());
+        let subcommand = subcommand.clone().bin_name(format!("{} {}", bin, subcommand.get_name())).display_name(&name);
+        write_man_page(subcommand, &name, &out_dir);
+    }
+
+    write_man_page(cmd.clone().display_name(&bin), &bin, &out_dir);
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::connection::DbSessions;
+use crate::config::credentials::{prompt_answer, write_password_file, Credentials, STORE_COMMAND, STORE_ENV, STORE_FILE};
+use crate::config::setup::{get_config_content, get_home, write_config, Config, ALPHADB_DIR, CONFIG_DIR};
+use crate::utils::title;
+use colored::Colorize;
+use inquire::{required, Select, Text};
+use std::path::PathBuf;
+
+const SKIP: &str = "Skip this session";
+
+/// Directory where password files are written by default
+const SECRETS_DIR: &str = "secrets";
+
+/// Move sessions with an encrypted password over to a password reference
+///
+/// For every session the user picks the new form. The password is only
+/// replaced when the reference resolves to the same password.
+///
+/// - config: AlphaDB configuration
+pub fn migrate_credentials(config: &Config) {
+    title("Migrate Credentials");
+
+    let mut sessions_content = match get_config_content::<DbSessions>() {
+        Some(s) => s,
+        None => {
+            println!("{}\n", "There are no saved sessions".yellow());
+            return;
+        }
+    };
+
+    let mut migrated = 0;
+    let mut remaining = 0;
+
+    for (label, session) in sessions_content.sessions.iter_mut() {
+        if !session.credentials().is_encrypted() {
+            continue;
+        }
+
+        let password = match session.credentials().resolve(config.main.secret.clone()) {
+            Ok(p) => p,
+            Err(e) => {
+                println!("{} {}: {}\n", "Skipping".yellow(), label.cyan(), e);
+                remaining += 1;
+                continue;
+            }
+        };
+
+        let options = Vec::from([STORE_FILE, STORE_COMMAND, STORE_ENV, SKIP]);
+        let choice = prompt_answer(
+            Select::new(&format!("Where should the password of {} be stored?", label), options)
+                .with_vim_mode(config.input.vim_bindings)
+                .prompt(),
+        );
+
+        let credentials = match choice {
+            STORE_FILE => {
+                let default_path = get_home().join(CONFIG_DIR).join(ALPHADB_DIR).join(SECRETS_DIR).join(label.replace(['/', '\\', '@', ':'], "_"));
+                let path = PathBuf::from(prompt_answer(
+                    Text::new("File")
+                        .with_default(&default_path.to_string_lossy())
+                        .with_help_message("The password will be written to this file, only readable by you")
+                        .prompt(),
+                ));
+
+                if let Err(e) = write_password_file(&path, &password) {
+                    println!("{} {}: {}\n", "Skipping".yellow(), label.cyan(), e);
+                    remaining += 1;
+                    continue;
+                }
+
+                Credentials::from_file(path)
+            }
+            STORE_COMMAND => Credentials::from_command(prompt_answer(
+                Text::new("Command")
+                    .with_validator(required!("This field is required"))
+                    .with_help_message("Command printing the password, e.g. pass show db/prod")
+                    .prompt(),
+            )),
+            STORE_ENV => Credentials::from_env(prompt_answer(
+                Text::new("Environment variable")
+                    .with_validator(required!("This field is required"))
+                    .with_help_message("Must be set to the session password before migrating")
+                    .prompt(),
+            )),
+            _ => {
+                remaining += 1;
+                continue;
+            }
+        };
+
+        // Never drop the encrypted password for a reference that does not work
+        match credentials.resolve(None) {
+            Ok(p) if p == password => (),
+            Ok(_) => {
+                println!("{} {}: the reference does not resolve to the saved password\n", "Skipping".yellow(), label.cyan());
+                remaining += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{} {}: {}\n", "Skipping".yellow(), label.cyan(), e);
+                remaining += 1;
+                continue;
+            }
+        }
+
+        *session.credentials_mut() = credentials;
+        migrated += 1;
+
+        println!("{} {}\n", "Migrated".green(), label.cyan());
+    }
+
+    write_config(sessions_content);
+
+    if migrated == 0 && remaining == 0 {
+        println!("{}\n", "No sessions with an encrypted password were found".green());
+        return;
+    }
+
+    println!("{} sessions migrated, {} sessions still use an encrypted password\n", migrated.to_string().green(), remaining.to_string().yellow());
+}
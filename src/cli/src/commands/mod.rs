@@ -1,15 +1,27 @@
+mod completions;
 mod connect;
 mod consolidate;
+mod diff;
+mod explore;
+mod history;
 mod init;
+mod mangen;
+mod migrate_credentials;
 mod status;
 mod update;
 mod vacate;
 mod verify;
 
-pub use connect::{connect, Connection};
+pub use completions::{complete_session_label, complete_version_source, completions, COMPLETE_VAR, SHELLS};
+pub use connect::connect;
 pub use consolidate::consolidate;
+pub use diff::diff;
+pub use explore::explore;
+pub use history::history;
 pub use init::init;
+pub use mangen::mangen;
+pub use migrate_credentials::migrate_credentials;
 pub use status::status;
 pub use update::update;
 pub use vacate::vacate;
-pub use verify::verify;
+pub use verify::{explain_rule, verify};
@@ -13,13 +13,62 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{dispatch::DbInstance, error, utils::title};
+use crate::{
+    dispatch::DbInstance,
+    error,
+    utils::{read_version_source, title},
+};
+use alphadb::core::utils::{
+    version_number::{parse_version_number, sort_versions},
+    version_source::{get_version_array, parse_version_source_string},
+};
+use alphadb::prelude::{AlphaDBError, Get};
 use colored::Colorize;
+use std::path::PathBuf;
+
+/// Get the version numbers in the version source that are newer than `database_version`
+///
+/// Returns the pending versions and the latest version in the version source, if
+/// the database version is ahead of it.
+fn pending_versions(version_source: String, database_version: &str) -> Result<(Vec<String>, Option<String>), AlphaDBError> {
+    let mut version_source = parse_version_source_string(version_source)?;
+
+    if let Some(versions) = version_source["version"].as_array_mut() {
+        sort_versions(versions)?;
+    }
+
+    let database_version_int = parse_version_number(database_version)?;
+    let mut pending: Vec<String> = Vec::new();
+    let mut latest: Option<&str> = None;
+
+    for version in get_version_array(&version_source)? {
+        let version_number = match version["_id"].as_str() {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if parse_version_number(version_number)? > database_version_int {
+            pending.push(version_number.to_string());
+        }
+
+        latest = Some(version_number);
+    }
+
+    let mut behind: Option<String> = None;
+    if let Some(latest) = latest {
+        if parse_version_number(latest)? < database_version_int {
+            behind = Some(latest.to_string());
+        }
+    }
+
+    Ok((pending, behind))
+}
 
 /// Print database status
 ///
 /// - db: AlphaDB instance  
-pub fn status(db: &mut DbInstance) {
+/// - version_source: Optional version source to list pending versions from
+pub fn status(db: &mut DbInstance, version_source: Option<PathBuf>) {
     title("Status");
 
     let status = match db.status() {
@@ -46,7 +95,7 @@ pub fn status(db: &mut DbInstance) {
         println!("Status: {}", "Uninitialized".yellow());
     }
 
-    match status.version {
+    match &status.version {
         Some(version) => {
             println!("Version: {}", version);
         }
@@ -55,6 +104,74 @@ pub fn status(db: &mut DbInstance) {
         }
     };
 
+    if let Some(vs_file) = version_source {
+        let database_version = match status.version {
+            Some(v) => v,
+            None => error!("The database has no version number, initialize it first".to_string()),
+        };
+
+        let (pending, behind) = match pending_versions(read_version_source(&vs_file), &database_version) {
+            Ok(p) => p,
+            Err(e) => error!(e.message()),
+        };
+
+        if let Some(latest) = behind {
+            println!(
+                "{}",
+                format!("Warning: the database version ({database_version}) is ahead of the latest version in the version source ({latest})").yellow()
+            );
+        }
+
+        if pending.is_empty() {
+            println!("Pending: {}", "None, the database is up-to-date".green());
+        } else {
+            println!("Pending: {}", pending.join(", ").cyan());
+        }
+    }
+
     // Empty line for better readability
     println!("  ");
 }
+
+#[cfg(test)]
+mod status_tests {
+    use super::pending_versions;
+    use serde_json::json;
+
+    fn version_source() -> String {
+        json!({"name": "test", "version": [
+            {"_id": "0.0.2", "createtable": {"b": {"id": {"type": "INT"}}}},
+            {"_id": "0.0.1", "createtable": {"a": {"id": {"type": "INT"}}}},
+            {"_id": "0.1.0", "altertable": {"a": {"addcolumn": {"name": {"type": "TEXT"}}}}},
+        ]})
+        .to_string()
+    }
+
+    #[test]
+    fn no_versions_applied() {
+        let (pending, behind) = pending_versions(version_source(), "0.0.0").unwrap();
+        assert_eq!(pending, vec!["0.0.1", "0.0.2", "0.1.0"]);
+        assert_eq!(behind, None);
+    }
+
+    #[test]
+    fn some_versions_applied() {
+        let (pending, behind) = pending_versions(version_source(), "0.0.1").unwrap();
+        assert_eq!(pending, vec!["0.0.2", "0.1.0"]);
+        assert_eq!(behind, None);
+    }
+
+    #[test]
+    fn all_versions_applied() {
+        let (pending, behind) = pending_versions(version_source(), "0.1.0").unwrap();
+        assert!(pending.is_empty());
+        assert_eq!(behind, None);
+    }
+
+    #[test]
+    fn database_ahead_of_version_source() {
+        let (pending, behind) = pending_versions(version_source(), "1.0.0").unwrap();
+        assert!(pending.is_empty());
+        assert_eq!(behind, Some("0.1.0".to_string()));
+    }
+}
@@ -37,7 +37,7 @@ pub fn vacate(db: &mut DbInstance) {
     // is active, so it's safe to unwrap
     let connection = get_active_connection().unwrap();
     let (confirm_messge, success_message) = match connection.connection {
-        SessionType::Mysql(c) => (
+        SessionType::Mysql(c) | SessionType::Mariadb(c) => (
             format!(
                 "{} {} {} {}:{}?\n",
                 "Are you absolutely sure you want to completely emtpy database".yellow(),
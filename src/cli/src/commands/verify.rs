@@ -16,16 +16,21 @@
 use crate::config::setup::Config;
 use crate::config::version_source::select_version_source;
 use crate::error;
-use crate::utils::{read_version_source, title};
+use crate::utils::{print_source_snippet, read_version_source_with_source_map, title};
 use alphadb::core::utils::errors::get_version_trace_string;
 use alphadb::prelude::Get;
-use alphadb::verification::{AlphaDBVerification, VerificationIssueLevel};
+use alphadb::verification::{find_rule, verification_report, AlphaDBVerification, ReportFormat, VerificationConfig, VerificationIssue, VerificationIssueLevel};
 use colored::Colorize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Verify the version source for errors
-pub fn verify(config: &Config, version_source: Option<PathBuf>) {
-    title("Verify Version Source");
+///
+/// With a `format`, only the report in that format is printed. With `engines`, the version
+/// source is verified for each of them instead of its own engine.
+pub fn verify(config: &Config, version_source: Option<PathBuf>, verification_config: Option<PathBuf>, format: Option<ReportFormat>, engines: Vec<String>) {
+    if format.is_none() {
+        title("Verify Version Source");
+    }
 
     let vs_file = match version_source {
         Some(vs) => vs.to_path_buf(),
@@ -35,13 +40,66 @@ pub fn verify(config: &Config, version_source: Option<PathBuf>) {
         },
     };
 
-    let version_source = read_version_source(&vs_file);
+    let (version_source, source_map) = read_version_source_with_source_map(&vs_file);
 
     let mut verification = match AlphaDBVerification::new(version_source) {
-        Ok(v) => v,
-        Err(e) => error!(e.message()),
+        Ok(v) => v.with_source_map(source_map),
+        Err(e) => {
+            if let Some(location) = &e.location {
+                print_source_snippet(location, &vs_file);
+            }
+            error!(e.message())
+        }
     };
 
+    if let Some(verification_config) = verification_config {
+        match VerificationConfig::from_file(verification_config) {
+            Ok(c) => verification = verification.with_config(c),
+            Err(e) => error!(e.message()),
+        }
+    }
+
+    if !engines.is_empty() {
+        let engines: Vec<&str> = engines.iter().map(String::as_str).collect();
+        let engine_issues = match verification.verify_engines(&engines) {
+            Ok(i) => i,
+            Err(e) => error!(e.message()),
+        };
+
+        if let Some(format) = format {
+            // One report for all engines, the messages name the engine they apply to
+            let issues: Vec<VerificationIssue> = engine_issues
+                .into_iter()
+                .flat_map(|e| {
+                    e.issues.into_iter().map(move |mut issue| {
+                        issue.message = format!("[{}] {}", e.engine, issue.message);
+                        issue
+                    })
+                })
+                .collect();
+            println!("{}", verification_report(&issues, format, &verification.custom_rules(), Some(&vs_file)).trim_end());
+            return;
+        }
+
+        for e in engine_issues {
+            match e.issues.is_empty() {
+                true => println!("{} {}\n", e.engine.bold(), "verified, without issues".green()),
+                false => {
+                    println!("{} has {}\n", e.engine.bold(), format!("{} errors", e.issues.len()).red());
+                    print_issues(e.issues, &vs_file);
+                    println!("   ");
+                }
+            }
+        }
+        return;
+    }
+
+    if let Some(format) = format {
+        let issues = verification.verify().err().unwrap_or_default();
+        println!("{}", verification_report(&issues, format, &verification.custom_rules(), Some(&vs_file)).trim_end());
+        return;
+    }
+
     match verification.verify() {
         Ok(_) => {
             println!(
@@ -58,35 +116,71 @@ pub fn verify(config: &Config, version_source: Option<PathBuf>) {
                 format!("{} errors", issues.len()).red()
             );
 
-            for issue in issues {
-                let mut issue_path = get_version_trace_string(&issue.version_trace);
+            print_issues(issues, &vs_file);
+            println!("   ");
+        }
+    }
+}
 
-                if !issue_path.is_empty() {
-                    issue_path = format!("Version {issue_path}: ");
-                }
+/// Print a list of verification issues, with the offending line of the version source
+fn print_issues(issues: Vec<VerificationIssue>, vs_file: &Path) {
+    for issue in issues {
+        let mut issue_path = get_version_trace_string(&issue.version_trace);
 
-                match issue.level {
-                    VerificationIssueLevel::Low => println!(
-                        "{} {}{}",
-                        "LOW VULNERABILITY:".on_white().black(),
-                        issue_path.cyan(),
-                        issue.message
-                    ),
-                    VerificationIssueLevel::High => println!(
-                        "{} {}{}",
-                        "HIGH VULNERABILITY:".on_yellow().black(),
-                        issue_path.cyan(),
-                        issue.message.yellow()
-                    ),
-                    VerificationIssueLevel::Critical => println!(
-                        "{} {}{}",
-                        "CRITICAL:".on_red().black(),
-                        issue_path.cyan(),
-                        issue.message.red()
-                    ),
-                }
-            }
-            println!("   ");
+        if !issue_path.is_empty() {
+            issue_path = format!("Version {issue_path}: ");
+        }
+
+        let rule = match issue.rule {
+            Some(rule) => format!("[{rule}] "),
+            None => String::new(),
+        };
+
+        match issue.level {
+            VerificationIssueLevel::Low => println!(
+                "{} {}{}{}",
+                "LOW VULNERABILITY:".on_white().black(),
+                rule.dimmed(),
+                issue_path.cyan(),
+                issue.message
+            ),
+            VerificationIssueLevel::High => println!(
+                "{} {}{}{}",
+                "HIGH VULNERABILITY:".on_yellow().black(),
+                rule.dimmed(),
+                issue_path.cyan(),
+                issue.message.yellow()
+            ),
+            VerificationIssueLevel::Critical => println!(
+                "{} {}{}{}",
+                "CRITICAL:".on_red().black(),
+                rule.dimmed(),
+                issue_path.cyan(),
+                issue.message.red()
+            ),
+        }
+
+        if let Some(location) = &issue.location {
+            print_source_snippet(location, vs_file);
         }
     }
 }
+
+/// Print the documentation of a verification rule
+pub fn explain_rule(key: &str) {
+    let rule = match find_rule(key) {
+        Some(r) => r,
+        None => error!(format!("Verification rule '{key}' does not exist")),
+    };
+
+    let level = match rule.level {
+        VerificationIssueLevel::Low => "low".normal(),
+        VerificationIssueLevel::High => "high".yellow(),
+        VerificationIssueLevel::Critical => "critical".red(),
+    };
+
+    println!("{} {}", rule.id.bold(), rule.name.cyan());
+    println!("Default level: {level}\n");
+    println!("{}\n", rule.summary);
+    println!("{}\n", rule.explanation);
+}
@@ -13,17 +13,38 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::config::credentials::Credentials;
 use crate::config::setup::{get_config_content, write_config};
 use crate::error;
+use alphadb::prelude::{ClientCertificate, ConnectOptions, TlsMode, TlsOptions};
 use colored::Colorize;
 use serde::Deserialize;
 use serde_derive::Serialize;
 use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum SessionType {
     Postgres(PostgresSession),
     Mysql(MysqlSession),
+    Mariadb(MysqlSession),
+}
+
+impl SessionType {
+    pub fn credentials(&self) -> &Credentials {
+        match self {
+            SessionType::Postgres(s) => &s.credentials,
+            SessionType::Mysql(s) | SessionType::Mariadb(s) => &s.credentials,
+        }
+    }
+
+    pub fn credentials_mut(&mut self) -> &mut Credentials {
+        match self {
+            SessionType::Postgres(s) => &mut s.credentials,
+            SessionType::Mysql(s) | SessionType::Mariadb(s) => &mut s.credentials,
+        }
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -41,18 +62,116 @@ pub struct Setup {
 pub struct MysqlSession {
     pub host: String,
     pub user: String,
-    pub password: String,
+    #[serde(flatten)]
+    pub credentials: Credentials,
     pub database: String,
     pub port: u16,
+    #[serde(flatten)]
+    pub options: SessionOptions,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PostgresSession {
     pub host: String,
     pub user: String,
-    pub password: String,
+    #[serde(flatten)]
+    pub credentials: Credentials,
     pub database: String,
     pub port: u16,
+    #[serde(flatten)]
+    pub options: SessionOptions,
+}
+
+/// TLS mode of a saved session, named like the libpq `sslmode` values
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionTlsMode {
+    #[default]
+    Disable,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl From<SessionTlsMode> for TlsMode {
+    fn from(mode: SessionTlsMode) -> Self {
+        match mode {
+            SessionTlsMode::Disable => TlsMode::Disable,
+            SessionTlsMode::Prefer => TlsMode::Prefer,
+            SessionTlsMode::Require => TlsMode::Require,
+            SessionTlsMode::VerifyCa => TlsMode::VerifyCa,
+            SessionTlsMode::VerifyFull => TlsMode::VerifyFull,
+        }
+    }
+}
+
+/// Optional connection settings of a saved session. Sessions saved
+/// before these existed deserialize with everything unset.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct SessionOptions {
+    /// Unix socket to connect through instead of host and port
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<PathBuf>,
+
+    #[serde(default)]
+    pub tls_mode: SessionTlsMode,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_ca_cert: Option<PathBuf>,
+
+    /// PEM certificate when `tls_client_key` is set, a PKCS#12 archive otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_client_cert: Option<PathBuf>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_client_key: Option<PathBuf>,
+
+    /// Connect timeout in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<u64>,
+
+    /// Statement timeout in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_timeout: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_name: Option<String>,
+}
+
+impl SessionOptions {
+    /// Build the library connect options for a session
+    pub fn connect_options(&self, host: &str, user: &str, password: &str, database: &str, port: u16) -> ConnectOptions {
+        let client_cert = self.tls_client_cert.clone().map(|cert| match &self.tls_client_key {
+            Some(key) => ClientCertificate::Pem { cert, key: key.clone() },
+            None => ClientCertificate::Pkcs12 { path: cert, password: None },
+        });
+
+        ConnectOptions {
+            socket: self.socket.clone(),
+            tls: TlsOptions {
+                mode: Some(self.tls_mode.into()),
+                ca_cert: self.tls_ca_cert.clone(),
+                client_cert,
+            },
+            connect_timeout: self.connect_timeout.map(Duration::from_secs),
+            statement_timeout: self.statement_timeout.map(Duration::from_secs),
+            application_name: self.application_name.clone(),
+            ..ConnectOptions::new(host, user, password, database, port)
+        }
+    }
+}
+
+impl MysqlSession {
+    pub fn connect_options(&self, password: &str) -> ConnectOptions {
+        self.options.connect_options(&self.host, &self.user, password, &self.database, self.port)
+    }
+}
+
+impl PostgresSession {
+    pub fn connect_options(&self, password: &str) -> ConnectOptions {
+        self.options.connect_options(&self.host, &self.user, password, &self.database, self.port)
+    }
 }
 
 /// Get all saved database connections
@@ -82,6 +201,17 @@ pub fn get_connections() -> Option<Vec<String>> {
     return Some(connections);
 }
 
+/// Get the labels of all saved database connections, without formatting
+///
+/// # Returns
+/// * `Vec<String>` - List of connection labels, empty if none exist
+pub fn get_session_labels() -> Vec<String> {
+    match get_config_content::<DbSessions>() {
+        Some(s) => s.sessions.into_keys().collect(),
+        None => Vec::new(),
+    }
+}
+
 #[derive(Debug)]
 pub struct ActiveConnection {
     pub label: String,
@@ -17,7 +17,7 @@ mod connection;
 mod mysql;
 
 pub use connection::{
-    get_active_connection, get_connections, remove_connection, set_active_connection, DbSessions,
+    get_active_connection, get_connections, get_session_labels, remove_connection, set_active_connection, DbSessions,
     SessionType,
 };
 pub use mysql::new_mysql_connection;
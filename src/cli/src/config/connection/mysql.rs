@@ -13,23 +13,24 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::commands::Connection;
-use crate::config::connection::connection::{DbSessions, MysqlSession, SessionType};
+use crate::config::connection::connection::{DbSessions, MysqlSession, SessionOptions, SessionTlsMode, SessionType};
+use crate::config::credentials::prompt_credentials;
 use crate::config::setup::{
     get_config_content, get_home, Config, ALPHADB_DIR, CONFIG_DIR, SESSIONS_FILE,
 };
 use crate::error;
-use crate::utils::encrypt_password;
+use crate::utils::abort;
 use alphadb::AlphaDB;
 use colored::Colorize;
-use inquire::{required, CustomType, Password, Text};
+use inquire::{required, CustomType, Select, Text};
 use std::fs;
+use std::path::PathBuf;
 use toml;
 
-/// Add a new database connection by prompting the user for credentials
+/// Add a new MySQL or MariaDB database connection by prompting the user for credentials
 ///
-/// This function prompts the user for database connection details, tests the connection,
-/// and saves it to the sessions config file.
+/// This function prompts the user for the server type and connection details, tests the
+/// connection, and saves it to the sessions config file.
 ///
 /// # Arguments
 /// * `activate` - Whether to set the connection as active after creating it
@@ -45,6 +46,18 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
     let home = get_home();
 
     print!("\n");
+    let engine = match Select::new("Server", vec!["MySQL", "MariaDB"]).with_vim_mode(config.input.vim_bindings).prompt() {
+        Ok(e) => e,
+        Err(err) => {
+            if let inquire::error::InquireError::OperationInterrupted = err {
+                abort();
+            }
+
+            error!("An unexpected error occured".to_string());
+        }
+    };
+    let is_mariadb = engine == "MariaDB";
+
     let host = Text::new("Host")
         .with_default("localhost")
         .with_help_message("URL/IP")
@@ -57,11 +70,7 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
         .prompt()
         .unwrap();
 
-    let password = Password::new("Password")
-        .without_confirmation()
-        .with_validator(required!("This field is required"))
-        .prompt()
-        .unwrap();
+    let (credentials, password) = prompt_credentials(config);
 
     let database = Text::new("Database")
         .with_validator(required!("This field is required"))
@@ -75,24 +84,24 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
         .prompt()
         .unwrap();
 
-    let connection = Connection {
+    let options = prompt_tls(config);
+
+    let session = MysqlSession {
         host,
         user,
-        password,
+        credentials,
         database,
         port,
+        options,
     };
 
     // Try if the credentials will connect
-    let runtime_config = alphadb::engine::mysql_impl::mysql_runtime_config();
+    let runtime_config = match is_mariadb {
+        true => alphadb::engine::mariadb_impl::mariadb_runtime_config(),
+        false => alphadb::engine::mysql_impl::mysql_runtime_config(),
+    };
     let mut db = AlphaDB::new(runtime_config);
-    let testconn = db.connect(
-        &connection.host,
-        &connection.user,
-        &connection.password,
-        &connection.database,
-        connection.port,
-    );
+    let testconn = db.connect(&session.connect_options(&password));
 
     if let Err(t) = testconn {
         error!(t.to_string());
@@ -105,7 +114,7 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
 
     let label: String = CustomType::new("Label")
         .with_help_message("Optionally add a label to this connection")
-        .with_default(format!("{}@{}", &connection.database, &connection.host))
+        .with_default(format!("{}@{}", &session.database, &session.host))
         .prompt()
         .unwrap();
 
@@ -115,16 +124,11 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
         None => DbSessions::default(),
     };
 
-    sessions_content.sessions.insert(
-        label.to_string(),
-        SessionType::Mysql(MysqlSession {
-            host: connection.host,
-            user: connection.user,
-            password: encrypt_password(&connection.password, config.main.secret.clone().unwrap()),
-            database: connection.database,
-            port: connection.port,
-        }),
-    );
+    let session = match is_mariadb {
+        true => SessionType::Mariadb(session),
+        false => SessionType::Mysql(session),
+    };
+    sessions_content.sessions.insert(label.to_string(), session);
 
     if activate {
         let _ = sessions_content
@@ -155,3 +159,55 @@ pub fn new_mysql_connection(activate: bool, config: &Config) -> String {
 
     return label;
 }
+
+/// Prompt the user for the TLS settings of a new session
+///
+/// # Arguments
+/// * `config` - The full user configuration
+///
+/// # Returns
+/// * `SessionOptions` - Session options with the chosen TLS mode and CA certificate
+fn prompt_tls(config: &Config) -> SessionOptions {
+    let modes = Vec::from(["disable", "prefer", "require", "verify-ca", "verify-full"]);
+
+    let choice = match Select::new("TLS", modes)
+        .with_vim_mode(config.input.vim_bindings)
+        .with_help_message("verify-ca and verify-full check the server certificate")
+        .prompt()
+    {
+        Ok(choice) => choice,
+        Err(err) => {
+            if let inquire::error::InquireError::OperationInterrupted = err {
+                abort();
+            }
+
+            error!("An unexpected error occured".to_string());
+        }
+    };
+
+    let tls_mode = match choice {
+        "prefer" => SessionTlsMode::Prefer,
+        "require" => SessionTlsMode::Require,
+        "verify-ca" => SessionTlsMode::VerifyCa,
+        "verify-full" => SessionTlsMode::VerifyFull,
+        _ => SessionTlsMode::Disable,
+    };
+
+    let mut tls_ca_cert = None;
+    if matches!(tls_mode, SessionTlsMode::VerifyCa | SessionTlsMode::VerifyFull) {
+        let ca_cert = Text::new("CA certificate")
+            .with_help_message("Path to a PEM file, leave empty to use the system certificates")
+            .prompt()
+            .unwrap();
+
+        if !ca_cert.is_empty() {
+            tls_ca_cert = Some(PathBuf::from(ca_cert));
+        }
+    }
+
+    SessionOptions {
+        tls_mode,
+        tls_ca_cert,
+        ..Default::default()
+    }
+}
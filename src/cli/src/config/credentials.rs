@@ -0,0 +1,311 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::setup::Config;
+use crate::error;
+use crate::utils::{abort, decrypt_password, encrypt_password, DecryptionReturnError};
+use inquire::{required, InquireError, Password, Select, Text};
+use serde::Deserialize;
+use serde_derive::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+use thiserror::Error;
+
+/// How the password of a saved session is stored
+///
+/// Only one of the fields is expected to be set. `password` holds a password
+/// encrypted with the secret from `config.toml`; the other fields store the
+/// password by reference, so it never ends up in the AlphaDB config directory.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Credentials {
+    /// Password encrypted with the user secret (ciphertext.nonce)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Name of an environment variable holding the password
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+
+    /// Shell command printing the password, run at connect time (e.g. `pass show db/prod`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_command: Option<String>,
+
+    /// File containing the password. Must not be accessible by group or others.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_file: Option<PathBuf>,
+}
+
+#[derive(Error, Debug)]
+pub enum CredentialError {
+    #[error("No password is configured for this session")]
+    NotConfigured,
+
+    #[error("Unable to decrypt the saved password: {0}")]
+    Decryption(#[from] DecryptionReturnError),
+
+    #[error("Environment variable '{0}' is not set")]
+    MissingEnv(String),
+
+    #[error("Password command '{command}' failed: {message}")]
+    Command { command: String, message: String },
+
+    #[error("Unable to read password file '{path}': {message}")]
+    File { path: PathBuf, message: String },
+
+    #[error("Password file '{path}' is accessible by other users (mode {mode:o}). Run 'chmod 600 {path}' to fix this.")]
+    InsecureFile { path: PathBuf, mode: u32 },
+}
+
+impl Credentials {
+    /// Credentials that reference an environment variable
+    pub fn from_env(name: String) -> Self {
+        Credentials {
+            password_env: Some(name),
+            ..Default::default()
+        }
+    }
+
+    /// Credentials that run a command to get the password
+    pub fn from_command(command: String) -> Self {
+        Credentials {
+            password_command: Some(command),
+            ..Default::default()
+        }
+    }
+
+    /// Credentials that read the password from a file
+    pub fn from_file(path: PathBuf) -> Self {
+        Credentials {
+            password_file: Some(path),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the password is stored encrypted in the sessions file,
+    /// rather than by reference
+    pub fn is_encrypted(&self) -> bool {
+        self.password.is_some() && self.password_env.is_none() && self.password_command.is_none() && self.password_file.is_none()
+    }
+
+    /// Get the plaintext password
+    ///
+    /// References take precedence over an encrypted password, so a session
+    /// that is half-way migrated keeps working.
+    ///
+    /// # Arguments
+    /// * `secret` - The user secret, used to decrypt an encrypted password
+    pub fn resolve(&self, secret: Option<String>) -> Result<String, CredentialError> {
+        if let Some(name) = &self.password_env {
+            return env::var(name).map_err(|_| CredentialError::MissingEnv(name.clone()));
+        }
+
+        if let Some(command) = &self.password_command {
+            return run_password_command(command);
+        }
+
+        if let Some(path) = &self.password_file {
+            return read_password_file(path);
+        }
+
+        match (&self.password, secret) {
+            (Some(password), Some(secret)) => Ok(decrypt_password(password.clone(), secret)?),
+            _ => Err(CredentialError::NotConfigured),
+        }
+    }
+}
+
+/// Only the first line of the output is used, without the trailing newline,
+/// matching how tools like `pass` print secrets.
+fn first_line(content: &str) -> String {
+    content.lines().next().unwrap_or_default().to_string()
+}
+
+/// Run `command` through the platform shell and return the first line it prints
+fn run_password_command(command: &str) -> Result<String, CredentialError> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").args(["/C", command]).output()
+    } else {
+        Command::new("sh").args(["-c", command]).output()
+    };
+
+    let output = output.map_err(|e| CredentialError::Command {
+        command: command.to_string(),
+        message: e.to_string(),
+    })?;
+
+    if !output.status.success() {
+        return Err(CredentialError::Command {
+            command: command.to_string(),
+            message: format!("{} {}", output.status, String::from_utf8_lossy(&output.stderr).trim()),
+        });
+    }
+
+    Ok(first_line(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Check that a password file can only be read by its owner
+#[cfg(unix)]
+pub fn check_file_permissions(path: &Path) -> Result<(), CredentialError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata = fs::metadata(path).map_err(|e| CredentialError::File {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    let mode = metadata.permissions().mode() & 0o777;
+    if mode & 0o077 != 0 {
+        return Err(CredentialError::InsecureFile {
+            path: path.to_path_buf(),
+            mode,
+        });
+    }
+
+    Ok(())
+}
+
+/// File permissions are managed through ACLs on Windows, which are not checked
+#[cfg(not(unix))]
+pub fn check_file_permissions(_path: &Path) -> Result<(), CredentialError> {
+    Ok(())
+}
+
+fn read_password_file(path: &Path) -> Result<String, CredentialError> {
+    check_file_permissions(path)?;
+
+    let content = fs::read_to_string(path).map_err(|e| CredentialError::File {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    Ok(first_line(&content))
+}
+
+/// Write a password to a file that can only be read by the current user
+pub fn write_password_file(path: &Path, password: &str) -> Result<(), CredentialError> {
+    let file_error = |e: std::io::Error| CredentialError::File {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(file_error)?;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+
+        let mut file = fs::OpenOptions::new().write(true).create(true).truncate(true).mode(0o600).open(path).map_err(file_error)?;
+        file.write_all(format!("{password}\n").as_bytes()).map_err(file_error)?;
+
+        // The mode passed to open is only applied to new files
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(file_error)?;
+    }
+
+    #[cfg(not(unix))]
+    fs::write(path, format!("{password}\n")).map_err(file_error)?;
+
+    Ok(())
+}
+
+/// The answer to a prompt. Aborts when the user cancels it, prints an error
+/// and exits the process when the prompt fails otherwise.
+pub fn prompt_answer<T>(answer: Result<T, InquireError>) -> T {
+    match answer {
+        Ok(answer) => answer,
+        Err(err) => {
+            if let InquireError::OperationInterrupted = err {
+                abort();
+            }
+
+            error!("An unexpected error occured".to_string());
+        }
+    }
+}
+
+pub const STORE_ENCRYPTED: &str = "Encrypted in the AlphaDB config";
+pub const STORE_ENV: &str = "Environment variable";
+pub const STORE_COMMAND: &str = "Password command";
+pub const STORE_FILE: &str = "Password file";
+
+/// Prompt the user for how to store the password of a new session
+///
+/// # Arguments
+/// * `config` - The full user configuration
+///
+/// # Returns
+/// * `(Credentials, String)` - The credentials to save and the resolved password,
+///   so the connection can be tested before saving
+///
+/// # Errors
+/// * Aborts when a prompt is cancelled, prints an error and exits the process if a prompt
+///   fails or the referenced password can not be resolved
+pub fn prompt_credentials(config: &Config) -> (Credentials, String) {
+    let options = Vec::from([STORE_ENCRYPTED, STORE_ENV, STORE_COMMAND, STORE_FILE]);
+
+    let choice = prompt_answer(
+        Select::new("How should the password be stored?", options)
+            .with_vim_mode(config.input.vim_bindings)
+            .with_help_message("References are resolved every time AlphaDB connects")
+            .prompt(),
+    );
+
+    let credentials = match choice {
+        STORE_ENV => Credentials::from_env(prompt_answer(
+            Text::new("Environment variable")
+                .with_validator(required!("This field is required"))
+                .with_help_message("Name of the variable holding the password, e.g. DB_PASSWORD")
+                .prompt(),
+        )),
+        STORE_COMMAND => Credentials::from_command(prompt_answer(
+            Text::new("Command")
+                .with_validator(required!("This field is required"))
+                .with_help_message("Command printing the password, e.g. pass show db/prod")
+                .prompt(),
+        )),
+        STORE_FILE => Credentials::from_file(PathBuf::from(prompt_answer(
+            Text::new("File")
+                .with_validator(required!("This field is required"))
+                .with_help_message("Path to a file containing the password, only readable by you")
+                .prompt(),
+        ))),
+        _ => {
+            let password = prompt_answer(
+                Password::new("Password")
+                    .without_confirmation()
+                    .with_validator(required!("This field is required"))
+                    .prompt(),
+            );
+
+            let credentials = Credentials {
+                password: Some(encrypt_password(&password, config.main.secret.clone().unwrap())),
+                ..Default::default()
+            };
+
+            return (credentials, password);
+        }
+    };
+
+    let password = match credentials.resolve(None) {
+        Ok(p) => p,
+        Err(e) => error!(e.to_string()),
+    };
+
+    (credentials, password)
+}
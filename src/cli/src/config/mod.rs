@@ -1,3 +1,4 @@
 pub mod connection;
+pub mod credentials;
 pub mod setup;
 pub mod version_source;
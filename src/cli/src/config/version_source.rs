@@ -138,7 +138,7 @@ pub fn new_version_source() -> String {
 }
 
 /// Get a version source path by label from sources.toml in user config
-fn get_version_source(label: String) -> Option<PathBuf> {
+pub fn get_version_source(label: String) -> Option<PathBuf> {
     let source_content = get_config_content::<VersionSources>();
     if source_content.is_none() {
         return None;
@@ -155,7 +155,7 @@ fn get_version_source(label: String) -> Option<PathBuf> {
 
 /// Get all the saved version source files from
 /// sources.toml in user config
-fn get_version_sources() -> Option<Vec<String>> {
+pub fn get_version_sources() -> Option<Vec<String>> {
     let source_content = get_config_content::<VersionSources>();
     if source_content.is_none() {
         return None;
@@ -16,8 +16,9 @@
 use std::path::PathBuf;
 
 use alphadb::{
-    core::method_types::{Init, Status},
+    core::method_types::{HistoryEntry, Init, Status},
     prelude::{AlphaDB, AlphaDBError, ToleratedVerificationIssueLevel},
+    verification::ReportFormat,
 };
 use clap::ArgMatches;
 use colored::Colorize;
@@ -28,34 +29,43 @@ use crate::{
     commands,
     config::{
         connection::{get_active_connection, remove_connection, SessionType},
+        credentials::CredentialError,
         setup::Config,
+        version_source::get_version_source,
     },
     error,
-    utils::decrypt_password,
 };
 
-/// Enum wrapping both MySQL and PostgreSQL AlphaDB instances
-/// so the CLI can handle both engine types through a single interface.
+/// Enum wrapping the MySQL, MariaDB and PostgreSQL AlphaDB instances
+/// so the CLI can handle every engine type through a single interface.
 pub enum DbInstance {
     Mysql(AlphaDB<PooledConn>),
+    Mariadb(AlphaDB<PooledConn>),
     Postgres(AlphaDB<Client>),
 }
 
 impl DbInstance {
     pub fn init(&mut self) -> Result<Init, AlphaDBError> {
         match self {
-            DbInstance::Mysql(db) => db.init(),
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.init(),
             DbInstance::Postgres(db) => db.init(),
         }
     }
 
     pub fn status(&mut self) -> Result<Status, AlphaDBError> {
         match self {
-            DbInstance::Mysql(db) => db.status(),
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.status(),
             DbInstance::Postgres(db) => db.status(),
         }
     }
 
+    pub fn history(&mut self) -> Result<Vec<HistoryEntry>, AlphaDBError> {
+        match self {
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.history(),
+            DbInstance::Postgres(db) => db.history(),
+        }
+    }
+
     pub fn update(
         &mut self,
         version_source: String,
@@ -65,7 +75,7 @@ impl DbInstance {
         tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
     ) -> Result<(), AlphaDBError> {
         match self {
-            DbInstance::Mysql(db) => db.update(
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.update(
                 version_source,
                 target_version,
                 no_data,
@@ -82,25 +92,49 @@ impl DbInstance {
 
     pub fn vacate(&mut self) -> Result<(), AlphaDBError> {
         match self {
-            DbInstance::Mysql(db) => db.vacate(),
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.vacate(),
             DbInstance::Postgres(db) => db.vacate(),
         }
     }
 
     pub fn is_connected(&self) -> bool {
         match self {
-            DbInstance::Mysql(db) => db.is_connected,
+            DbInstance::Mysql(db) | DbInstance::Mariadb(db) => db.is_connected,
             DbInstance::Postgres(db) => db.is_connected,
         }
     }
 }
 
+/// Get the `--source` argument as a path. A saved version source
+/// label is resolved to its path, anything else is taken as a path.
+fn get_source_arg(matches: &ArgMatches) -> Option<PathBuf> {
+    matches.get_one::<String>("source").map(|vs| resolve_source(vs))
+}
+
+/// Resolve a version source argument to a path
+fn resolve_source(vs: &str) -> PathBuf {
+    if !PathBuf::from(vs).exists() {
+        if let Some(path) = get_version_source(vs.to_string()) {
+            return path;
+        }
+    }
+
+    vs.into()
+}
+
 /// Execute the right commands based on parsed commandline input
 pub fn dispatch(matches: &ArgMatches, config: &Config, mut db: DbInstance) {
     match matches.subcommand() {
-        Some(("connect", _query_matches)) => commands::connect(&config),
+        Some(("connect", query_matches)) => commands::connect(config, query_matches.get_one::<String>("label")),
+        Some(("completions", query_matches)) => commands::completions(query_matches.get_one::<String>("shell").unwrap()),
+        Some(("mangen", query_matches)) => commands::mangen(query_matches.get_one::<String>("out-dir").unwrap().into()),
+        Some(("migrate-credentials", _query_matches)) => commands::migrate_credentials(config),
         Some(("init", _query_matches)) => commands::init(&mut db),
-        Some(("status", _query_matches)) => commands::status(&mut db),
+        Some(("status", query_matches)) => {
+            let version_source = get_source_arg(query_matches);
+            commands::status(&mut db, version_source);
+        }
+        Some(("history", _query_matches)) => commands::history(&mut db),
         Some(("update", query_matches)) => {
             // No data should be false by default
             let mut nodata = false;
@@ -122,10 +156,7 @@ pub fn dispatch(matches: &ArgMatches, config: &Config, mut db: DbInstance) {
                 allowed_error_priority = allowed_error_priority_some.to_string();
             }
 
-            let mut version_source: Option<PathBuf> = None;
-            if let Some(vs) = query_matches.get_one::<String>("source") {
-                version_source = Some(vs.into());
-            }
+            let version_source = get_source_arg(query_matches);
 
             commands::update(
                 &config,
@@ -138,28 +169,60 @@ pub fn dispatch(matches: &ArgMatches, config: &Config, mut db: DbInstance) {
         }
         Some(("vacate", _query_matches)) => commands::vacate(&mut db),
         Some(("verify", query_matches)) => {
-            let mut version_source: Option<PathBuf> = None;
-            if let Some(vs) = query_matches.get_one::<String>("source") {
-                version_source = Some(vs.into());
+            if let Some(rule) = query_matches.get_one::<String>("explain") {
+                commands::explain_rule(rule);
+                return;
             }
-            commands::verify(&config, version_source);
+
+            let version_source = get_source_arg(query_matches);
+            let verification_config = query_matches.get_one::<String>("config").map(PathBuf::from);
+            let format = match query_matches.get_one::<String>("format").map(String::as_str) {
+                Some("text") | None => None,
+                Some(f) => match f.parse::<ReportFormat>() {
+                    Ok(f) => Some(f),
+                    Err(e) => error!(e.message),
+                },
+            };
+            let engines: Vec<String> = match query_matches.get_many::<String>("engines") {
+                Some(e) => e.cloned().collect(),
+                None => Vec::new(),
+            };
+            commands::verify(&config, version_source, verification_config, format, engines);
         }
         Some(("consolidate", query_matches)) => {
-            let mut version_source: Option<PathBuf> = None;
-            if let Some(vs) = query_matches.get_one::<String>("source") {
-                version_source = Some(vs.into());
-            }
+            let version_source = get_source_arg(query_matches);
             commands::consolidate(&config, version_source);
         }
+        Some(("explore", query_matches)) => {
+            let version_source = get_source_arg(query_matches);
+            commands::explore(config, version_source);
+        }
+        Some(("diff", query_matches)) => {
+            let sources: Vec<PathBuf> = match query_matches.get_many::<String>("sources") {
+                Some(s) => s.map(|vs| resolve_source(vs)).collect(),
+                None => Vec::new(),
+            };
+
+            commands::diff(
+                config,
+                sources,
+                query_matches.get_one::<String>("from").cloned(),
+                query_matches.get_one::<String>("to").cloned(),
+                query_matches.get_flag("json"),
+            );
+        }
         _ => unreachable!(), // If all subcommands are defined above, anything else is unreachable
     }
 }
 
+/// Commands that do not need an active database connection
+const NO_CONNECTION_COMMANDS: [&str; 7] = ["connect", "migrate-credentials", "completions", "mangen", "diff", "verify", "explore"];
+
 /// Get the AlphaDB instance
 pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, AlphaDBError> {
     // Check if the current command should have an active database connection
     if let Some(m) = matches.subcommand() {
-        if m.0 != "connect" {
+        if !NO_CONNECTION_COMMANDS.contains(&m.0) {
             let active_connection = match get_active_connection() {
                 Some(c) => c,
                 None => {
@@ -170,14 +233,13 @@ pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, Alpha
                 }
             };
 
+            let is_mariadb = matches!(active_connection.connection, SessionType::Mariadb(_));
+
             match active_connection.connection {
-                SessionType::Mysql(c) => {
-                    let password = match decrypt_password(
-                        c.password,
-                        config.main.secret.clone().unwrap(),
-                    ) {
+                SessionType::Mysql(c) | SessionType::Mariadb(c) => {
+                    let password = match c.credentials.resolve(config.main.secret.clone()) {
                         Ok(p) => p,
-                        Err(_) => {
+                        Err(CredentialError::Decryption(_)) => {
                             remove_connection(active_connection.label);
                             error!(format!(
                                 "Unable to connect to database {}@{}:{} using saved credentials. The connection has been removed.",
@@ -186,11 +248,23 @@ pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, Alpha
                                 c.port.to_string().cyan(),
                             ));
                         }
+                        Err(e) => {
+                            error!(format!(
+                                "Unable to get the password for database {}@{}:{}. {}",
+                                c.database.cyan(),
+                                c.host.cyan(),
+                                c.port.to_string().cyan(),
+                                e
+                            ));
+                        }
                     };
 
-                    let runtime_config = alphadb::engine::mysql_impl::mysql_runtime_config();
+                    let runtime_config = match is_mariadb {
+                        true => alphadb::engine::mariadb_impl::mariadb_runtime_config(),
+                        false => alphadb::engine::mysql_impl::mysql_runtime_config(),
+                    };
                     let mut db = AlphaDB::new(runtime_config);
-                    match db.connect(&c.host, &c.user, &password, &c.database, c.port) {
+                    match db.connect(&c.connect_options(&password)) {
                         Ok(_) => (),
                         Err(e) => {
                             error!(e.to_string());
@@ -204,15 +278,15 @@ pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, Alpha
                         });
                     }
 
-                    return Ok(DbInstance::Mysql(db));
+                    return match is_mariadb {
+                        true => Ok(DbInstance::Mariadb(db)),
+                        false => Ok(DbInstance::Mysql(db)),
+                    };
                 }
                 SessionType::Postgres(c) => {
-                    let password = match decrypt_password(
-                        c.password,
-                        config.main.secret.clone().unwrap(),
-                    ) {
+                    let password = match c.credentials.resolve(config.main.secret.clone()) {
                         Ok(p) => p,
-                        Err(_) => {
+                        Err(CredentialError::Decryption(_)) => {
                             remove_connection(active_connection.label);
                             error!(format!(
                                 "Unable to connect to database {}@{}:{} using saved credentials. The connection has been removed.",
@@ -221,11 +295,20 @@ pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, Alpha
                                 c.port.to_string().cyan(),
                             ));
                         }
+                        Err(e) => {
+                            error!(format!(
+                                "Unable to get the password for database {}@{}:{}. {}",
+                                c.database.cyan(),
+                                c.host.cyan(),
+                                c.port.to_string().cyan(),
+                                e
+                            ));
+                        }
                     };
 
                     let runtime_config = alphadb::engine::postgres_impl::postgres_runtime_config();
                     let mut db = AlphaDB::new(runtime_config);
-                    match db.connect(&c.host, &c.user, &password, &c.database, c.port) {
+                    match db.connect(&c.connect_options(&password)) {
                         Ok(_) => (),
                         Err(e) => {
                             error!(e.to_string());
@@ -245,7 +328,7 @@ pub fn get_db(matches: &ArgMatches, config: &Config) -> Result<DbInstance, Alpha
         }
     }
 
-    // Create a dummy engine for commands that don't require a connection (e.g. "connect")
+    // Create a dummy engine for commands that don't require a connection (see NO_CONNECTION_COMMANDS)
     let runtime_config = alphadb::engine::mysql_impl::mysql_runtime_config();
     let db = AlphaDB::new(runtime_config);
     return Ok(DbInstance::Mysql(db));
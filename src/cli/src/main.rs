@@ -20,9 +20,13 @@ mod parse;
 mod utils;
 
 use config::setup::{config_read, init_config, Config};
+use clap_complete::CompleteEnv;
 use utils::abort;
 
 fn main() {
+    // Answer shell completion requests before anything is printed
+    CompleteEnv::with_factory(parse::build_cli).var(commands::COMPLETE_VAR).complete();
+
     init_config();
     let config = match config_read::<Config>() {
         Some(c) => c,
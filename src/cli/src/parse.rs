@@ -13,20 +13,42 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::commands::{complete_session_label, complete_version_source, SHELLS};
 use clap::{Arg, ArgAction, ArgMatches, Command};
+use clap_complete::engine::{ArgValueCandidates, ArgValueCompleter};
 
-/// Parse command line input using Clap
-pub fn parse_cl_input() -> ArgMatches {
-    let matches = Command::new("alphadb")
+/// Version source argument, shared by every command that reads a version source.
+/// Accepts a path or the label of a saved version source.
+fn source_arg(help: &'static str) -> Arg {
+    Arg::new("source")
+        .short('s')
+        .long("source")
+        .help(help)
+        .action(ArgAction::Set)
+        .add(ArgValueCompleter::new(complete_version_source))
+}
+
+/// Build the full command tree
+///
+/// Shared by the argument parser, shell completions and man page generation.
+pub fn build_cli() -> Command {
+    Command::new("alphadb")
         .about("MySQL database version management")
         .version(env!("CARGO_PKG_VERSION"))
         .name("AlphaDB - Command Line Interface")
         .bin_name("alphadb")
         .subcommand_required(true)
         .arg_required_else_help(true)
-        .subcommand(Command::new("connect").about("Connect to a database"))
+        .subcommand(Command::new("connect").about("Connect to a database").args([Arg::new("label")
+            .help("Label of a saved connection to activate")
+            .action(ArgAction::Set)
+            .add(ArgValueCandidates::new(complete_session_label))]))
+        .subcommand(Command::new("migrate-credentials").about("Move saved encrypted passwords to an environment variable, command or file"))
         .subcommand(Command::new("init").about("Initialize the database"))
-        .subcommand(Command::new("status").about("Get database status"))
+        .subcommand(Command::new("status").about("Get database status").args([
+            source_arg("Version source to list pending versions from")
+        ]))
+        .subcommand(Command::new("history").about("List every version applied to the database"))
         .subcommand(
             Command::new("update").about("Update the database").args([
                 Arg::new("no-data")
@@ -45,29 +67,79 @@ pub fn parse_cl_input() -> ArgMatches {
                     .default_value("low")
                     .help("Specify from which issue level the program will fail (critical, hight, low, all)")
                     .action(ArgAction::Set),
-                Arg::new("source")
-                    .short('s')
-                    .long("source")
-                    .help("Version source to use for the update")
-                    .action(ArgAction::Set)
+                source_arg("Version source to use for the update")
             ]),
         )
         .subcommand(Command::new("vacate").about("Completely empty the database"))
         .subcommand(Command::new("verify").about("Check the version source for errors").args([
-            Arg::new("source")
-                .short('s')
-                .long("source")
-                .help("Version source to verify")
-                .action(ArgAction::Set)
+            source_arg("Version source to verify"),
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .help("Verification config file that disables rules or overrides their levels")
+                .action(ArgAction::Set),
+            Arg::new("explain")
+                .long("explain")
+                .help("Explain a verification rule, by id (e.g. ADB0012) or name")
+                .action(ArgAction::Set),
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .default_value("text")
+                .value_parser(["text", "sarif", "junit"])
+                .help("Output format. sarif and junit print a report for code review and CI tools")
+                .action(ArgAction::Set),
+            Arg::new("engines")
+                .short('e')
+                .long("engines")
+                .value_delimiter(',')
+                .value_parser(["mysql", "mariadb", "postgres", "sqlite"])
+                .help("Verify the version source for each of these engines instead of its own, e.g. mysql,postgres")
+                .action(ArgAction::Append),
         ]))
         .subcommand(Command::new("consolidate").about("Consolidate all versions into a single version").args([
-            Arg::new("source")
-                .short('s')
-                .long("source")
-                .help("Version source to consolidate")
-                .action(ArgAction::Set)
+            source_arg("Version source to consolidate")
+        ]))
+        .subcommand(Command::new("explore").about("Interactively browse a version source and its consolidated schema").args([
+            source_arg("Version source to explore")
+        ]))
+        .subcommand(Command::new("diff").about("Show schema differences between two version sources, or two versions of one").args([
+            Arg::new("sources")
+                .help("One or two version sources to compare (path or saved label)")
+                .num_args(1..=2)
+                .action(ArgAction::Append)
+                .add(ArgValueCompleter::new(complete_version_source)),
+            Arg::new("from")
+                .long("from")
+                .help("Version of the first version source to compare, defaults to the latest version")
+                .action(ArgAction::Set),
+            Arg::new("to")
+                .long("to")
+                .help("Version of the last version source to compare, defaults to the latest version")
+                .action(ArgAction::Set),
+            Arg::new("json")
+                .long("json")
+                .help("Print the diff as JSON")
+                .action(ArgAction::SetTrue),
         ]))
-        .get_matches();
+        .subcommand(
+            Command::new("completions").about("Generate shell completions").args([Arg::new("shell")
+                .help("Shell to generate completions for")
+                .required(true)
+                .value_parser(SHELLS)
+                .action(ArgAction::Set)]),
+        )
+        .subcommand(
+            Command::new("mangen").about("Generate man pages").hide(true).args([Arg::new("out-dir")
+                .short('o')
+                .long("out-dir")
+                .default_value(".")
+                .help("Directory to write the man pages to")
+                .action(ArgAction::Set)]),
+        )
+}
 
-    return matches;
+/// Parse command line input using Clap
+pub fn parse_cl_input() -> ArgMatches {
+    build_cli().get_matches()
 }
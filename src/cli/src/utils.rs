@@ -17,17 +17,18 @@ use crate::config::connection::{get_active_connection, SessionType};
 use crate::error;
 use aes_gcm::aead::{Aead, KeyInit, OsRng};
 use aes_gcm::{Aes256Gcm, Nonce};
-use alphadb::version_source::build_version_source_from_dir;
+use alphadb::verification::{SourceLocation, SourceMap};
+use alphadb::version_source::build_version_source_from_dir_with_source_map;
 use base64::engine::{general_purpose, Engine};
 use colored::Colorize;
 use rand_core::RngCore;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 use std::string::FromUtf8Error;
 use thiserror::Error;
 
-pub const AVAILABLE_ENGINES: [&str; 2] = ["postgres", "mysql"];
+pub const AVAILABLE_ENGINES: [&str; 3] = ["postgres", "mysql", "mariadb"];
 
 /// Print function title and current database connection to the commandline
 ///
@@ -47,6 +48,17 @@ pub fn title(title: &str) {
                     format!("({})", conn.label).green()
                 );
             }
+            SessionType::Mariadb(s) => {
+                println!(
+                    "{} {} {} {}:{} {}",
+                    "Connected to MariaDB database".cyan(),
+                    s.database,
+                    "on".cyan(),
+                    s.host,
+                    s.port,
+                    format!("({})", conn.label).green()
+                );
+            }
             SessionType::Postgres(s) => {
                 println!(
                     "{} {} {} {}:{} {}",
@@ -79,18 +91,32 @@ pub fn abort() {
 /// inside it are combined using `build_version_source_from_dir`. When it
 /// points to a file, the file contents are returned directly.
 pub fn read_version_source(path: &PathBuf) -> String {
+    read_version_source_with_source_map(path).0
+}
+
+/// Read version source content from a file or directory, together with the
+/// locations of its keys in the files it was read from.
+pub fn read_version_source_with_source_map(path: &PathBuf) -> (String, SourceMap) {
     if path.is_dir() {
-        let value = match build_version_source_from_dir(path) {
+        let (value, source_map) = match build_version_source_from_dir_with_source_map(path) {
             Ok(v) => v,
-            Err(e) => error!(e.message),
+            Err(e) => {
+                if let Some(location) = &e.location {
+                    print_source_snippet(location, path);
+                }
+                error!(e.message)
+            }
         };
         match serde_json::to_string(&value) {
-            Ok(s) => s,
+            Ok(s) => (s, source_map),
             Err(_) => error!("Failed to serialize the combined version source.".to_string()),
         }
     } else {
         match fs::read_to_string(path) {
-            Ok(f) => f,
+            Ok(f) => {
+                let source_map = SourceMap::from_json(&f, Some(path));
+                (f, source_map)
+            }
             Err(_) => error!(format!(
                 "An error occured while opening the version source at '{}'",
                 path.to_string_lossy().cyan()
@@ -99,6 +125,57 @@ pub fn read_version_source(path: &PathBuf) -> String {
     }
 }
 
+/// Print the line of the version source a location points at, rustc style
+///
+/// # Arguments
+/// * `location` - Location in the version source
+/// * `fallback` - File to read when the location does not name one
+pub fn print_source_snippet(location: &SourceLocation, fallback: &Path) {
+    let file = location.file.as_deref().unwrap_or(fallback);
+    let Some(line) = fs::read_to_string(file).ok().and_then(|s| {
+        s.lines()
+            .nth(location.line.saturating_sub(1))
+            .map(str::to_string)
+    }) else {
+        return;
+    };
+
+    // Underline a whole key when the location points at one
+    let rest: Vec<char> = line
+        .chars()
+        .skip(location.column.saturating_sub(1))
+        .collect();
+    let width = match rest.first() {
+        Some('"') => rest
+            .iter()
+            .skip(1)
+            .position(|c| *c == '"')
+            .map(|end| end + 2)
+            .unwrap_or(1),
+        _ => 1,
+    };
+
+    let number = location.line.to_string();
+    let gutter = " ".repeat(number.len());
+    println!(
+        "{}{} {}:{}:{}",
+        gutter,
+        "-->".blue().bold(),
+        file.display(),
+        location.line,
+        location.column
+    );
+    println!("{} {}", gutter, "|".blue().bold());
+    println!("{} {} {}", number.blue().bold(), "|".blue().bold(), line);
+    println!(
+        "{} {} {}{}\n",
+        gutter,
+        "|".blue().bold(),
+        " ".repeat(location.column.saturating_sub(1)),
+        "^".repeat(width).red().bold()
+    );
+}
+
 #[cfg(debug_assertions)]
 #[macro_export]
 macro_rules! error {
@@ -1,63 +1,99 @@
-#[cfg(all(feature = "mysql", feature = "postgres"))]
-compile_error!("Enable exactly one AlphaDB engine feature: mysql or postgres.");
-
 #[cfg(not(any(feature = "mysql", feature = "postgres")))]
-compile_error!("Enable one AlphaDB engine feature: mysql or postgres.");
+compile_error!("Enable at least one AlphaDB engine feature: mysql or postgres.");
+
+use alphadb::core::method_types::{Init, Query, Status};
+use alphadb::engine::AlphaDBEngine;
+use alphadb::prelude::{AlphaDBError, ConnectOptions, ToleratedVerificationIssueLevel};
+use alphadb::verification::VersionTrace;
+
+/// AlphaDB instance for the engine selected at runtime,
+/// so both engines can be handled through a single interface.
+#[allow(clippy::large_enum_variant)]
+pub enum DbInstance {
+    #[cfg(feature = "mysql")]
+    Mysql(alphadb::AlphaDB<mysql::PooledConn>),
+    #[cfg(feature = "mysql")]
+    Mariadb(alphadb::AlphaDB<mysql::PooledConn>),
+    #[cfg(feature = "postgres")]
+    Postgres(alphadb::AlphaDB<postgres::Client>),
+}
 
-#[cfg(feature = "mysql")]
-pub use alphadb::engine::mysql_impl::methods::{
-    connect, init, status, update, vacate,
-};
+/// Run the same expression on whichever AlphaDB instance is active
+macro_rules! dispatch {
+    ($self:expr, $db:ident => $body:expr) => {
+        match $self {
+            #[cfg(feature = "mysql")]
+            DbInstance::Mysql($db) => $body,
+            #[cfg(feature = "mysql")]
+            DbInstance::Mariadb($db) => $body,
+            #[cfg(feature = "postgres")]
+            DbInstance::Postgres($db) => $body,
+        }
+    };
+}
 
-#[cfg(feature = "mysql")]
-pub use alphadb::engine::mysql_impl::methods::MYSQL_UPDATE_QUERIES_CONFIG;
+/// Error for an engine that was not compiled into this build
+fn engine_not_included(engine: AlphaDBEngine) -> AlphaDBError {
+    AlphaDBError {
+        message: format!("This build of AlphaDB does not include the {} engine", engine.display_name()),
+        error: "unsupported-engine".to_string(),
+        version_trace: VersionTrace::new(),
+        location: None,
+    }
+}
 
-#[cfg(feature = "mysql")]
-pub type Connection = mysql::PooledConn;
+pub fn default_port(engine: AlphaDBEngine) -> u16 {
+    match engine {
+        AlphaDBEngine::MySQL | AlphaDBEngine::MariaDB => 3306,
+        AlphaDBEngine::PostgreSQL => 5432,
+        // SQLite opens a file, the port is never used
+        AlphaDBEngine::SQLite => 0,
+    }
+}
 
-#[cfg(feature = "mysql")]
-pub const DEFAULT_PORT: u16 = 3306;
+impl DbInstance {
+    pub fn new(engine: AlphaDBEngine) -> Result<Self, AlphaDBError> {
+        match engine {
+            #[cfg(feature = "mysql")]
+            AlphaDBEngine::MySQL => Ok(DbInstance::Mysql(alphadb::AlphaDB::new(alphadb::engine::mysql()))),
+            #[cfg(feature = "mysql")]
+            AlphaDBEngine::MariaDB => Ok(DbInstance::Mariadb(alphadb::AlphaDB::new(alphadb::engine::mariadb()))),
+            #[cfg(feature = "postgres")]
+            AlphaDBEngine::PostgreSQL => Ok(DbInstance::Postgres(alphadb::AlphaDB::new(alphadb::engine::postgres()))),
+            #[allow(unreachable_patterns)]
+            _ => Err(engine_not_included(engine)),
+        }
+    }
 
-#[cfg(feature = "postgres")]
-pub use alphadb::engine::postgres_impl::methods::{
-    connect, init, status, update, vacate,
-};
+    pub fn connect(&mut self, host: &str, user: &str, password: &str, database: &str, port: u16) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.connect(&ConnectOptions::new(host, user, password, database, port)))
+    }
 
-#[cfg(feature = "postgres")]
-pub use alphadb::engine::postgres_impl::methods::POSTGRES_UPDATE_QUERIES_CONFIG;
+    pub fn init(&mut self) -> Result<Init, AlphaDBError> {
+        dispatch!(self, db => db.init())
+    }
 
-#[cfg(feature = "postgres")]
-pub type Connection = postgres::Client;
+    pub fn status(&mut self) -> Result<Status, AlphaDBError> {
+        dispatch!(self, db => db.status())
+    }
 
-#[cfg(feature = "postgres")]
-pub const DEFAULT_PORT: u16 = 5432;
+    /// Generate the queries to update a database to `target_version` (the latest
+    /// version when `None`) without executing them.
+    pub fn update_queries(&mut self, version_source: String, target_version: Option<&str>, no_data: bool) -> Result<Vec<Query>, AlphaDBError> {
+        dispatch!(self, db => db.update_queries(version_source, target_version, no_data))
+    }
 
-/// Generate the queries to update a database to `target_version` (the latest
-/// version when `None`) without executing them.
-pub fn update_queries(
-    db_name: &str,
-    connection: &mut Connection,
-    version_source: String,
-    target_version: Option<&str>,
-    no_data: bool,
-) -> Result<Vec<alphadb::core::method_types::Query>, alphadb::prelude::AlphaDBError> {
-    #[cfg(feature = "mysql")]
-    return alphadb::core::update_queries::update_queries(
-        &MYSQL_UPDATE_QUERIES_CONFIG,
-        db_name,
-        connection,
-        version_source,
-        target_version,
-        no_data,
-    );
+    pub fn update(
+        &mut self,
+        version_source: String,
+        target_version: Option<&str>,
+        no_data: bool,
+        tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+    ) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.update(version_source, target_version, no_data, tolerated_verification_issue_level))
+    }
 
-    #[cfg(feature = "postgres")]
-    return alphadb::core::update_queries::update_queries(
-        &POSTGRES_UPDATE_QUERIES_CONFIG,
-        db_name,
-        connection,
-        version_source,
-        target_version,
-        no_data,
-    );
+    pub fn vacate(&mut self) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.vacate())
+    }
 }
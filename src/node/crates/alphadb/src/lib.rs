@@ -18,44 +18,88 @@ mod methods;
 mod types;
 mod utils;
 
+use crate::engine::DbInstance;
 use crate::methods::connect::connect_wrap;
 use crate::methods::init::init_wrap;
 use crate::methods::status::status_wrap;
 use crate::methods::update::update_wrap;
 use crate::methods::update_queries::update_queries_wrap;
 use crate::methods::vacate::vacate_wrap;
-use crate::types::PooledConnWrap;
+use crate::types::AlphaDBBox;
+use alphadb::engine::AlphaDBEngine;
+use alphadb::prelude::Get;
+use alphadb::verification::{verification_report as adb_verification_report, AlphaDBVerification, ReportFormat};
 use neon::prelude::*;
-use std::cell::RefCell;
-use std::rc::Rc;
-use utils::{get_db_name, get_is_connected};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Create a new AlphaDB instance for the given engine. Every JS `AlphaDB`
+/// object holds its own, so several databases can be handled at once.
+fn create(mut cx: FunctionContext) -> JsResult<JsBox<AlphaDBBox>> {
+    let engine = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    let instance = match engine.parse::<AlphaDBEngine>().and_then(DbInstance::new) {
+        Ok(i) => i,
+        Err(e) => return cx.throw_error(e.message()),
+    };
+
+    Ok(cx.boxed(AlphaDBBox {
+        inner: Arc::new(Mutex::new(instance)),
+    }))
+}
 
 fn default_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
-    Ok(cx.number(engine::DEFAULT_PORT))
+    let engine = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match engine.parse::<AlphaDBEngine>() {
+        Ok(e) => Ok(cx.number(engine::default_port(e))),
+        Err(e) => cx.throw_error(e.message()),
+    }
 }
 
-#[neon::main]
-fn main(mut cx: ModuleContext) -> NeonResult<()> {
-    let conn = Rc::new(RefCell::new(None::<PooledConnWrap>));
-    let conn_rc = cx.boxed(conn);
+fn engine_from_version_source(mut cx: FunctionContext) -> JsResult<JsString> {
+    let version_source = cx.argument::<JsString>(0)?.value(&mut cx);
 
-    let db_name = Rc::new(RefCell::new(None::<String>));
-    let db_name_rc = cx.boxed(db_name);
+    match AlphaDBEngine::from_version_source(&version_source) {
+        Ok(e) => Ok(cx.string(e.to_string())),
+        Err(e) => cx.throw_error(e.message()),
+    }
+}
+
+/// Verify a version source and write the issues as a SARIF log or JUnit XML report
+fn verification_report(mut cx: FunctionContext) -> JsResult<JsString> {
+    let version_source = cx.argument::<JsString>(0)?.value(&mut cx);
+    let format = cx.argument::<JsString>(1)?.value(&mut cx);
+    let source = match cx.argument_opt(2) {
+        Some(source) if !source.is_a::<JsUndefined, _>(&mut cx) => Some(PathBuf::from(source.downcast_or_throw::<JsString, _>(&mut cx)?.value(&mut cx))),
+        _ => None,
+    };
 
-    let is_connected = Rc::new(RefCell::new(false));
-    let is_connected_rc = cx.boxed(is_connected);
+    let format = match format.parse::<ReportFormat>() {
+        Ok(f) => f,
+        Err(e) => return cx.throw_error(e.message()),
+    };
 
-    cx.export_value("internaldbname", db_name_rc)?;
-    cx.export_value("internalisconnected", is_connected_rc)?;
-    cx.export_value("conn", conn_rc)?;
-    cx.export_function("get_db_name", get_db_name)?;
-    cx.export_function("get_is_connected", get_is_connected)?;
+    let mut verification = match AlphaDBVerification::new(version_source) {
+        Ok(v) => v,
+        Err(e) => return cx.throw_error(e.message()),
+    };
+
+    let issues = verification.verify().err().unwrap_or_default();
+    Ok(cx.string(adb_verification_report(&issues, format, &verification.custom_rules(), source.as_deref())))
+}
+
+#[neon::main]
+fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    cx.export_function("create", create)?;
     cx.export_function("default_port", default_port)?;
+    cx.export_function("engine_from_version_source", engine_from_version_source)?;
     cx.export_function("connect", connect_wrap)?;
     cx.export_function("init", init_wrap)?;
     cx.export_function("status", status_wrap)?;
     cx.export_function("update_queries", update_queries_wrap)?;
     cx.export_function("update", update_wrap)?;
     cx.export_function("vacate", vacate_wrap)?;
+    cx.export_function("verification_report", verification_report)?;
     Ok(())
 }
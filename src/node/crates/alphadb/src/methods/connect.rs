@@ -13,37 +13,25 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::engine::connect;
-use crate::types::PooledConnWrap;
+use crate::utils::{get_instance, with_instance};
 use alphadb::prelude::*;
 use neon::prelude::*;
-use std::cell::RefCell;
-use std::rc::Rc;
 
-pub fn connect_wrap(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let conn_rc = cx.argument::<JsBox<Rc<RefCell<Option<PooledConnWrap>>>>>(0)?;
-    let mut conn = conn_rc.borrow_mut();
+pub fn connect_wrap(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let instance = get_instance(&mut cx)?;
 
-    let db_name_rc = cx.argument::<JsBox<Rc<RefCell<Option<String>>>>>(1)?;
-    let mut db_name = db_name_rc.borrow_mut();
+    let host = cx.argument::<JsString>(1)?.value(&mut cx);
+    let user = cx.argument::<JsString>(2)?.value(&mut cx);
+    let password = cx.argument::<JsString>(3)?.value(&mut cx);
+    let database = cx.argument::<JsString>(4)?.value(&mut cx);
+    let port = cx.argument::<JsNumber>(5)?.value(&mut cx) as u16;
 
-    let is_connected_rc = cx.argument::<JsBox<Rc<RefCell<bool>>>>(2)?;
-    let mut is_connected = is_connected_rc.borrow_mut();
+    let promise = cx
+        .task(move || with_instance(&instance, |db| db.connect(&host, &user, &password, &database, port)))
+        .promise(|mut cx, result| match result {
+            Ok(()) => Ok(cx.undefined()),
+            Err(e) => cx.throw_error(e.message()),
+        });
 
-    let host = cx.argument::<JsString>(3)?.value(&mut cx);
-    let user = cx.argument::<JsString>(4)?.value(&mut cx);
-    let password = cx.argument::<JsString>(5)?.value(&mut cx);
-    let database = cx.argument::<JsString>(6)?.value(&mut cx);
-    let port = cx.argument::<JsNumber>(7)?.value(&mut cx) as u16;
-
-    let c = match connect(&host, &user, &password, &database, port) {
-        Ok(c) => c,
-        Err(e) => cx.throw_error(e.message())?,
-    };
-
-    *conn = Some(PooledConnWrap { inner: Some(c) });
-    *db_name = Some(database);
-    *is_connected = true;
-
-    Ok(cx.undefined())
+    Ok(promise)
 }
@@ -13,77 +13,69 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::engine::update_queries;
-use crate::types::PooledConnWrap;
-use crate::utils::get_connection;
+use crate::utils::{get_instance, with_instance};
+use alphadb::core::method_types::QueryValue;
 use alphadb::prelude::*;
 use neon::prelude::*;
-use std::cell::RefCell;
-use std::rc::Rc;
 
-pub fn update_queries_wrap(mut cx: FunctionContext) -> JsResult<JsArray> {
-    let conn_rc = cx.argument::<JsBox<Rc<RefCell<Option<PooledConnWrap>>>>>(0)?;
-    let mut conn_ref = conn_rc.borrow_mut();
-
-    let db_name_rc = cx.argument::<JsBox<Rc<RefCell<Option<String>>>>>(1)?;
-    let db_name_ref = db_name_rc.borrow();
+/// Convert a query parameter to the matching JS type. Integers
+/// become numbers, so values beyond 2^53 lose precision.
+fn query_value_to_js<'a, C: Context<'a>>(cx: &mut C, value: &QueryValue) -> Handle<'a, JsValue> {
+    match value {
+        QueryValue::String(v) => cx.string(v).upcast(),
+        QueryValue::Integer(v) => cx.number(*v as f64).upcast(),
+        QueryValue::Unsigned(v) => cx.number(*v as f64).upcast(),
+        QueryValue::Float(v) => cx.number(*v).upcast(),
+        QueryValue::Bool(v) => cx.boolean(*v).upcast(),
+        QueryValue::Null => cx.null().upcast(),
+    }
+}
 
-    let (db_name, connection) = match get_connection(db_name_ref, &mut conn_ref) {
-        Ok(v) => v,
-        Err(e) => return cx.throw_error(e.message()),
-    };
+pub fn update_queries_wrap(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let instance = get_instance(&mut cx)?;
 
-    let version_source = cx.argument::<JsString>(2)?.value(&mut cx);
-    let target_version = cx.argument::<JsString>(3)?.value(&mut cx);
-    let no_data = cx.argument::<JsBoolean>(4)?.value(&mut cx);
+    let version_source = cx.argument::<JsString>(1)?.value(&mut cx);
+    let target_version = cx.argument::<JsString>(2)?.value(&mut cx);
+    let no_data = cx.argument::<JsBoolean>(3)?.value(&mut cx);
 
-    // The TypeScript wrapper allows for target_version to be undefined
-    // so it's set to NOVERSION if that is the case
-    let mut target_version_processed: Option<&str> = None;
-    if target_version != "NOVERSION".to_string() {
-        target_version_processed = Some(target_version.as_str());
-    }
+    let promise = cx
+        .task(move || {
+            // The TypeScript wrapper allows for target_version to be undefined
+            // so it's set to NOVERSION if that is the case
+            let target_version = if target_version != "NOVERSION" { Some(target_version.as_str()) } else { None };
 
-    let query_array = cx.empty_array();
+            with_instance(&instance, |db| db.update_queries(version_source, target_version, no_data))
+        })
+        .promise(|mut cx, result| {
+            let queries = match result {
+                Ok(q) => q,
+                Err(e) => return cx.throw_error(e.message()),
+            };
 
-    if let Some(connection) = connection.inner.as_mut() {
-        match update_queries(
-            &db_name,
-            connection,
-            version_source,
-            target_version_processed,
-            no_data,
-        ) {
-            Ok(c) => {
-                // Convert to JS array
-                for (i, q) in c.iter().enumerate() {
-                    let tup = cx.empty_array();
+            // Convert to JS array
+            let query_array = cx.empty_array();
+            for (i, q) in queries.iter().enumerate() {
+                let tup = cx.empty_array();
 
-                    let query = cx.string(q.query.clone());
-                    tup.set(&mut cx, 0, query)?;
+                let query = cx.string(q.query.clone());
+                tup.set(&mut cx, 0, query)?;
 
-                    // Convert the data
-                    let data = cx.empty_array();
-                    match &q.data {
-                        Some(d) => {
-                            for (di, v) in d.iter().enumerate() {
-                                let v = cx.string(v.to_string_lossy());
-                                data.set(&mut cx, di as u32, v)?;
-                            }
-                        }
-                        None => (),
+                // Convert the data to native JS values
+                let data = cx.empty_array();
+                if let Some(d) = &q.data {
+                    for (di, v) in d.iter().enumerate() {
+                        let v = query_value_to_js(&mut cx, v);
+                        data.set(&mut cx, di as u32, v)?;
                     }
+                }
 
-                    tup.set(&mut cx, 1, data)?;
+                tup.set(&mut cx, 1, data)?;
 
-                    query_array.set(&mut cx, i as u32, tup)?;
-                }
+                query_array.set(&mut cx, i as u32, tup)?;
             }
-            Err(e) => cx.throw_error(e.message())?,
-        };
-    } else {
-        return cx.throw_error("Connection is missing.");
-    }
 
-    return Ok(query_array);
+            Ok(query_array)
+        });
+
+    Ok(promise)
 }
@@ -13,32 +13,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::engine::vacate;
-use crate::types::PooledConnWrap;
-use crate::utils::get_connection;
+use crate::utils::{get_instance, with_instance};
 use alphadb::prelude::*;
 use neon::prelude::*;
-use std::cell::RefCell;
-use std::rc::Rc;
 
-pub fn vacate_wrap(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let conn_rc = cx.argument::<JsBox<Rc<RefCell<Option<PooledConnWrap>>>>>(0)?;
-    let mut conn_ref = conn_rc.borrow_mut();
+pub fn vacate_wrap(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let instance = get_instance(&mut cx)?;
 
-    let db_name_rc = cx.argument::<JsBox<Rc<RefCell<Option<String>>>>>(1)?;
-    let db_name_ref = db_name_rc.borrow();
+    let promise = cx
+        .task(move || with_instance(&instance, |db| db.vacate()))
+        .promise(|mut cx, result| match result {
+            Ok(()) => Ok(cx.undefined()),
+            Err(e) => cx.throw_error(e.message()),
+        });
 
-    let (_, connection) = match get_connection(db_name_ref, &mut conn_ref) {
-        Ok(v) => v,
-        Err(e) => return cx.throw_error(e.message()),
-    };
-
-    if let Some(connection) = connection.inner.as_mut() {
-        match vacate(connection) {
-            Ok(_) => Ok(cx.undefined()),
-            Err(e) => return cx.throw_error(e.message()),
-        }
-    } else {
-        return cx.throw_error("Connection is missing.");
-    }
+    Ok(promise)
 }
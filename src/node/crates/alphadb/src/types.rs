@@ -1,8 +1,11 @@
-use crate::engine::Connection;
+use crate::engine::DbInstance;
 use neon::prelude::*;
+use std::sync::{Arc, Mutex};
 
-pub struct PooledConnWrap {
-    pub inner: Option<Connection>,
+/// AlphaDB instance owned by a single JS `AlphaDB` object. Every method
+/// locks it on neon's task pool, so calls on one instance run in order.
+pub struct AlphaDBBox {
+    pub inner: Arc<Mutex<DbInstance>>,
 }
 
-impl Finalize for PooledConnWrap {}
+impl Finalize for AlphaDBBox {}
@@ -13,60 +13,32 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::PooledConnWrap;
+use crate::engine::DbInstance;
+use crate::types::AlphaDBBox;
 use alphadb::prelude::AlphaDBError;
-use neon::{
-    prelude::{Context, FunctionContext},
-    result::JsResult,
-    types::{JsBoolean, JsBox, JsValue},
-};
-use std::{
-    cell::{Ref, RefCell},
-    rc::Rc,
-};
+use neon::prelude::*;
+use std::sync::{Arc, Mutex};
 
-pub fn get_connection<'a>(
-    db_name: Ref<Option<String>>,
-    connection: &'a mut Option<PooledConnWrap>,
-) -> Result<(String, &'a mut PooledConnWrap), AlphaDBError> {
-    let connection = match connection {
-        Some(c) => c,
-        None => {
-            return Err(AlphaDBError {
-                message: "No active database connection".to_string(),
-                ..Default::default()
-            })
-        }
-    };
+/// Get the AlphaDB instance passed as the first argument
+pub fn get_instance(cx: &mut FunctionContext) -> NeonResult<Arc<Mutex<DbInstance>>> {
+    let instance = cx.argument::<JsBox<AlphaDBBox>>(0)?;
+    Ok(Arc::clone(&instance.inner))
+}
 
-    let db_name = match &*db_name {
-        Some(db) => db.clone(),
-        None => {
+/// Lock the instance and run `f` on it. Meant to be called from neon's task pool.
+pub fn with_instance<T>(
+    instance: &Mutex<DbInstance>,
+    f: impl FnOnce(&mut DbInstance) -> Result<T, AlphaDBError>,
+) -> Result<T, AlphaDBError> {
+    let mut db = match instance.lock() {
+        Ok(db) => db,
+        Err(_) => {
             return Err(AlphaDBError {
-                message: "No connection seems to be active. db_name does not have a value"
-                    .to_string(),
+                message: "The AlphaDB instance can not be used after a previous call panicked".to_string(),
                 ..Default::default()
             })
         }
     };
 
-    return Ok((db_name, connection));
-}
-
-pub fn get_db_name(mut cx: FunctionContext) -> JsResult<JsValue> {
-    let db_name_rc = cx.argument::<JsBox<Rc<RefCell<Option<String>>>>>(0)?;
-    let db_name = db_name_rc.borrow();
-
-    if let Some(ref name) = *db_name {
-        Ok(cx.string(name.clone()).upcast())
-    } else {
-        Ok(cx.undefined().upcast())
-    }
-}
-
-pub fn get_is_connected(mut cx: FunctionContext) -> JsResult<JsBoolean> {
-    let is_connected_rc = cx.argument::<JsBox<Rc<RefCell<bool>>>>(0)?;
-    let is_connected = is_connected_rc.borrow();
-
-    Ok(cx.boolean(*is_connected))
+    f(&mut db)
 }
@@ -18,13 +18,6 @@ const __dirname = path.dirname(__filename);
 const platform = os.platform();
 const arch = os.arch();
 const platformArch = `${platform}-${arch}`;
-const packageJson = JSON.parse(fs.readFileSync(path.resolve(__dirname, "./package.json"), "utf8"));
-const engine = packageJson.alphadb?.engine;
-
-if (!["mysql", "postgres"].includes(engine)) {
-	console.error("Missing or invalid package.json alphadb.engine. Expected \"mysql\" or \"postgres\".");
-	process.exit(1);
-}
 
 function getBinaryURL() {
 	const fileName = SUPPORTED_BINARIES[platformArch];
@@ -33,7 +26,7 @@ function getBinaryURL() {
 		return null;
 	}
 
-	return `${BASE_URL}/${fileName}-${engine}.node`;
+	return `${BASE_URL}/${fileName}.node`;
 }
 
 async function downloadBinary(url) {
@@ -81,7 +74,7 @@ function buildFromSource() {
 	}
 
 	try {
-		execSync(engine === "postgres" ? "npm run build:postgres:notsc" : "npm run build:mysql:notsc", {
+		execSync("npm run build:notsc", {
 			cwd: __dirname,
 			stdio: "inherit",
 		});
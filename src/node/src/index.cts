@@ -7,7 +7,9 @@ interface Status {
 	template: string | null;
 }
 
-type Query = [string, Array<string>];
+type QueryParam = string | number | boolean | null;
+
+type Query = [string, Array<QueryParam>];
 
 interface Version {
 	_id: string;
@@ -15,11 +17,21 @@ interface Version {
 	altertable?: string;
 }
 
+type Engine = "mysql" | "mariadb" | "postgres";
+
 interface VersionSource {
 	name: string;
+	engine?: Engine;
 	version: Array<Version>;
 }
 
+interface AlphaDBOptions {
+	/** Database engine. Takes precedence over the engine of `versionSource`. */
+	engine?: Engine;
+	/** Version source to read the engine from. */
+	versionSource?: VersionSource;
+}
+
 interface ConnectProps {
 	host: string;
 	user: string;
@@ -30,67 +42,75 @@ interface ConnectProps {
 
 type ToleratedVerificationIssueLevel = "LOW" | "HIGH" | "CRITICAL" | "ALL";
 
+type ReportFormat = "sarif" | "junit";
+
 interface AlphaDB {
-	conn: any;
-	internaldbname: any;
-	internalisconnected: any;
+	instance: any;
+	engine: Engine;
 	db_name: string | undefined;
 	is_connected: boolean;
-	connect(props: ConnectProps): void;
-	init(): void;
-	status(): Status;
-	updateQueries(version_source: VersionSource, target_version?: string, no_data?: boolean): Array<Query>;
-	update(version_source: VersionSource, target_version?: string, no_data?: boolean, toleratedVerificationIssueLevel?: ToleratedVerificationIssueLevel): void;
-	vacate(): void;
+	connect(props: ConnectProps): Promise<void>;
+	init(): Promise<void>;
+	status(): Promise<Status>;
+	updateQueries(version_source: VersionSource, target_version?: string, no_data?: boolean): Promise<Array<Query>>;
+	update(version_source: VersionSource, target_version?: string, no_data?: boolean, toleratedVerificationIssueLevel?: ToleratedVerificationIssueLevel): Promise<void>;
+	vacate(): Promise<void>;
 }
 
 
 // Use this declaration to assign types to the addon's exports,
 // which otherwise by default are `any`.
 declare module "./load.cjs" {
-	const conn: any;
-	const internaldbname: any;
-	const internalisconnected: any;
-	function get_is_connected(internalisconnected: any): boolean;
-	function get_db_name(internaldbname: any): string | undefined;
-	function default_port(): number;
-	function connect(conn: any, internaldbname: any, internalisconnected: any, host: string, user: string, password: string, database: string, port: number): void;
-	function init(conn: any, internaldbname: any): void;
-	function status(conn: any, internaldbname: any): Status;
-	function update_queries(conn: any, internaldbname: any, version_source: string, target_version: string, no_data: boolean): Array<Query>;
-	function update(conn: any, internaldbname: any, version_source: string, target_version: string, no_data: boolean, tolerated_verification_issue_level: string): Array<Query>;
-	function vacate(conn: any, internaldbname: any): void;
+	function create(engine: Engine): any;
+	function default_port(engine: Engine): number;
+	function engine_from_version_source(version_source: string): Engine;
+	function connect(instance: any, host: string, user: string, password: string, database: string, port: number): Promise<void>;
+	function init(instance: any): Promise<void>;
+	function status(instance: any): Promise<Status>;
+	function update_queries(instance: any, version_source: string, target_version: string, no_data: boolean): Promise<Array<Query>>;
+	function update(instance: any, version_source: string, target_version: string, no_data: boolean, tolerated_verification_issue_level: string): Promise<void>;
+	function vacate(instance: any): Promise<void>;
+	function verification_report(version_source: string, format: ReportFormat, source?: string): string;
 }
 
 class AlphaDB {
-	private constructor() {
-		this.conn = addon.conn;
-		this.internaldbname = addon.internaldbname;
-		this.internalisconnected = addon.internalisconnected;
+	/**
+	 * The engine is taken from `options.engine`, or else from the engine of
+	 * `options.versionSource`. Defaults to MySQL when neither is provided.
+	 *
+	 * Every instance holds its own connection. The methods run on a background
+	 * thread, so several instances can be used concurrently.
+	 */
+	public constructor(options?: AlphaDBOptions) {
+		if (typeof options?.engine !== "undefined") this.engine = options.engine;
+		else if (typeof options?.versionSource !== "undefined") this.engine = addon.engine_from_version_source(JSON.stringify(options.versionSource));
+		else this.engine = "mysql";
+
+		this.instance = addon.create(this.engine);
 		this.db_name = undefined;
 		this.is_connected = false;
 	}
 
-	public connect(props: ConnectProps) {
-		if (typeof props.port === "undefined") props.port = addon.default_port();
-		addon.connect(this.conn, this.internaldbname, this.internalisconnected, props.host, props.user, props.password, props.database, props.port);
+	public async connect(props: ConnectProps) {
+		if (typeof props.port === "undefined") props.port = addon.default_port(this.engine);
+		await addon.connect(this.instance, props.host, props.user, props.password, props.database, props.port);
 
-		this.db_name = addon.get_db_name(this.internaldbname);
-		this.is_connected = addon.get_is_connected(this.internalisconnected);
+		this.db_name = props.database;
+		this.is_connected = true;
 	}
 
-	public init() {
-		addon.init(this.conn, this.internaldbname);
+	public async init() {
+		return addon.init(this.instance);
 	}
 
-	public status() {
-		return addon.status(this.conn, this.internaldbname);
+	public async status() {
+		return addon.status(this.instance);
 	}
 
-	public updateQueries(version_source: VersionSource, target_version?: string, no_data?: boolean) {
+	public async updateQueries(version_source: VersionSource, target_version?: string, no_data?: boolean) {
 		if (typeof target_version === "undefined") target_version = "NOVERSION";
 		if (typeof no_data === "undefined") no_data = false;
-		return addon.update_queries(this.conn, this.internaldbname, JSON.stringify(version_source), target_version, no_data);
+		return addon.update_queries(this.instance, JSON.stringify(version_source), target_version, no_data);
 	}
 
 	public async update(version_source: VersionSource, target_version?: string, no_data?: boolean, toleratedVerificationIssueLevel?: ToleratedVerificationIssueLevel) {
@@ -98,14 +118,31 @@ class AlphaDB {
 		if (typeof no_data === "undefined") no_data = false;
 		if (typeof toleratedVerificationIssueLevel === "undefined") toleratedVerificationIssueLevel = "LOW";
 
-		return addon.update(this.conn, this.internaldbname, JSON.stringify(version_source), target_version, no_data, toleratedVerificationIssueLevel);
+		return addon.update(this.instance, JSON.stringify(version_source), target_version, no_data, toleratedVerificationIssueLevel);
 	}
 
-	public vacate() {
-		addon.vacate(this.conn, this.internaldbname);
+	public async vacate() {
+		return addon.vacate(this.instance);
 	}
 }
 
+/**
+ * Verify a version source and write the issues as a SARIF log or JUnit XML
+ * report. `source` is the file the issues are reported against.
+ */
+function verificationReport(versionSource: VersionSource, format: ReportFormat, source?: string): string {
+	return addon.verification_report(JSON.stringify(versionSource), format, source);
+}
+
 export {
-	AlphaDB
+	AlphaDB,
+	verificationReport,
+}
+
+export type {
+	AlphaDBOptions,
+	Engine,
+	Query,
+	QueryParam,
+	ReportFormat,
 }
@@ -1,5 +1,5 @@
 import { describe, it, expect } from "vitest";
-import AlphaDB from ".";
+import AlphaDB, { verificationReport } from ".";
 import fs from "fs";
 import path from "path";
 
@@ -33,7 +33,7 @@ if (!engines[engine]) {
 }
 
 const config = engines[engine];
-const db = new AlphaDB();
+const db = new AlphaDB({ engine });
 
 function loadStructure() {
 	const structurePath = path.resolve("../../assets", config.structure);
@@ -48,24 +48,54 @@ function loadStructure() {
 
 describe(`AlphaDB ${engine} Tests`, () => {
 
-	it("should connect to the database", () => {
+	it("should select the engine", () => {
+		expect(db.engine).toEqual(engine);
+		expect(new AlphaDB().engine).toEqual("mysql");
+		expect(new AlphaDB({ versionSource: { name: "test", engine: "postgres", version: [] } }).engine).toEqual("postgres");
+		expect(() => new AlphaDB({ versionSource: { name: "test", version: [] } })).toThrowError("No engine specified");
+	});
+
+	it("should write verification reports", () => {
+		const versionSource = { name: "test", engine, version: [{ _id: "0.0.1", createtable: { table1: { col1: { length: 200 } } } }] };
+
+		const sarif = JSON.parse(verificationReport(versionSource, "sarif", "structure.json"));
+		const result = sarif.runs[0].results[0];
+		expect(result.ruleId).toEqual("ADB0010");
+		expect(result.level).toEqual("error");
+		expect(result.locations[0].physicalLocation.artifactLocation.uri).toEqual("structure.json");
+
+		expect(verificationReport(versionSource, "junit")).toContain('<failure type="critical"');
+		expect(() => verificationReport(versionSource, "xml")).toThrowError("not a supported report format");
+	});
+
+	it("should connect to the database", async () => {
 		expect(db.is_connected).toEqual(false);
 		expect(db.db_name).toBeUndefined();
-		db.connect(config.connect);
+		await db.connect(config.connect);
 		expect(db.db_name).toEqual(config.connect.database);
 		expect(db.is_connected).toEqual(true);
-		db.vacate();
+		await db.vacate();
 	});
 
-	it("should initialize the database and throw if already initialized", () => {
-		db.init();
-		expect(() => db.init()).toThrowError(
+	it("should keep instances independent", async () => {
+		const other = new AlphaDB({ engine });
+		expect(other.is_connected).toEqual(false);
+		await expect(other.status()).rejects.toThrowError("No connection");
+
+		await other.connect(config.connect);
+		const [status, otherStatus] = await Promise.all([db.status(), other.status()]);
+		expect(otherStatus).toEqual(status);
+	});
+
+	it("should initialize the database and throw if already initialized", async () => {
+		await db.init();
+		await expect(db.init()).rejects.toThrowError(
 			"The database is already initialized"
 		);
 	});
 
-	it("should be initialized", () => {
-		const status = db.status();
+	it("should be initialized", async () => {
+		const status = await db.status();
 		expect(status).toEqual({
 			init: true,
 			version: "0.0.0",
@@ -75,9 +105,13 @@ describe(`AlphaDB ${engine} Tests`, () => {
 	});
 
 	it("should update the database structure to version 0.2.6", async () => {
+		const queries = await db.updateQueries(loadStructure());
+		const defaultData = queries.find(([query]) => query.startsWith("INSERT INTO") && query.includes("table1"));
+		expect(defaultData[1]).toEqual([4, "test", "test", 12]);
+
 		await db.update(loadStructure());
 
-		const status = db.status();
+		const status = await db.status();
 		expect(status).toEqual({
 			init: true,
 			version: "0.2.6",
@@ -86,10 +120,10 @@ describe(`AlphaDB ${engine} Tests`, () => {
 		});
 	});
 
-	it("should vacate the database", () => {
-		db.vacate();
+	it("should vacate the database", async () => {
+		await db.vacate();
 
-		const status = db.status();
+		const status = await db.status();
 		expect(status).toEqual({
 			init: false,
 			version: null,
@@ -1,9 +1,17 @@
+import os
 from typing import List, Literal, Optional, Tuple, TypedDict, Union
 
 class Check(TypedDict):
     check: bool
     version: str
 
+QueryParam = Union[str, int, float, bool, None]
+
+class Query(TypedDict):
+    query: str
+    data: Optional[List[QueryParam]]
+    """Query parameters as native values. `None` when the query has no parameters"""
+
 class Status(TypedDict):
     init: bool
     version: Union[str, None]
@@ -17,28 +25,92 @@ HIGH: Will still work, but might produce a different result than desired.
 CRITICAL: Will not execute.
 """
 
+class AlphaDBError(RuntimeError):
+    """Base exception for all AlphaDB errors"""
+
+    error: str
+    """Stable error code, e.g. `up-to-date`. Empty when the error has no code"""
+    message: str
+    version_trace: List[str]
+
+class UpToDateError(AlphaDBError): ...
+class NotInitializedError(AlphaDBError): ...
+class IncompatibleVersionSourceError(AlphaDBError): ...
+class InvalidVersionNumberError(AlphaDBError): ...
+class NoVersionNumberError(AlphaDBError): ...
+class NoEngineError(AlphaDBError): ...
+class UnsupportedEngineError(AlphaDBError): ...
+class InvalidVersionSourceError(AlphaDBError): ...
+class VersionSourceDirectoryError(AlphaDBError): ...
+
+class Init:
+    Success: "Init"
+    AlreadyInitialized: "Init"
+
+class VerificationIssueLevel:
+    Low: "VerificationIssueLevel"
+    High: "VerificationIssueLevel"
+    Critical: "VerificationIssueLevel"
+
+class VerificationIssue:
+    level: VerificationIssueLevel
+    message: str
+    version_trace: List[str]
+    rule: Optional[str]
+    """Stable id of the rule that produced the issue, e.g. `ADB0012`"""
+    location: Optional[Tuple[int, int]]
+    """Line and column in the version source the issue points at"""
+
+class AlphaDBVerification:
+    def __init__(self, version_source: str): ...
+    """Prepare a version source for verification. The version source must specify an `engine`"""
+
+    def verify(self) -> List[VerificationIssue]: ...
+    """Verify the version source. Returns an empty list when no issues are found"""
+
+    def verify_engines(self, engines: List[str]) -> List[Tuple[str, List[VerificationIssue]]]: ...
+    """Verify the version source for each of `engines` instead of its own engine. Returns the issues per engine, in the order of `engines`"""
+
+    def report(self, format: Literal["sarif", "junit"], source: Optional[Union[str, os.PathLike]] = None) -> str: ...
+    """Verify the version source and write the issues as a SARIF log or JUnit XML report. `source` is the file the issues are reported against"""
+
+def consolidate_version_source(version_source: str) -> str: ...
+"""Consolidate all versions of a version source into a single version"""
+
+def build_version_source_from_dir(path: Union[str, os.PathLike]) -> str: ...
+"""Combine a directory of version source files (containing an `adb-config.json`) into a single version source"""
+
+Engine = Literal["mysql"] | Literal["mariadb"] | Literal["postgres"]
+
 class AlphaDB:
+    engine: Engine
     is_connected: bool
 
+    def __init__(self, engine: Optional[Engine] = None, version_source: Optional[str] = None): ...
+    """
+    Create an AlphaDB instance. The engine is taken from `engine`, or else from the
+    `engine` field of `version_source`. Defaults to MySQL when neither is provided.
+    """
+
     def connect(
         self,
         host: str,
         user: str,
         password: str,
         database: str,
-        port: Optional[int] = 3306,
+        port: Optional[int] = None,
     ): ...
-    """Connect to a database"""
+    """Connect to a database. Port defaults to 3306 for MySQL and 5432 for PostgreSQL"""
 
-    def init(self): ...
-    """Initialize the database"""
+    def init(self) -> Init: ...
+    """Initialize the database. Returns `Init.AlreadyInitialized` if it was initialized before"""
 
     def status(self) -> Status: ...
     """Get the databases status"""
 
     def update_queries(
         self, version_source: str, target_version: Optional[str] = None, no_data=False
-    ) -> List[Tuple[str, List[str]]]: ...
+    ) -> List[Query]: ...
     """Generate queries to update the database"""
 
     def update(
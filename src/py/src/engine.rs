@@ -0,0 +1,163 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use alphadb::core::method_types::{Init, Query, Status};
+use alphadb::engine::AlphaDBEngine;
+use alphadb::prelude::*;
+use alphadb::verification::VersionTrace;
+#[cfg(feature = "mysql")]
+use mysql::PooledConn;
+#[cfg(feature = "postgres")]
+use postgres::Client;
+
+/// AlphaDB instance for the engine selected at runtime,
+/// so both engines can be handled through a single interface.
+#[allow(clippy::large_enum_variant)]
+pub enum DbInstance {
+    #[cfg(feature = "mysql")]
+    Mysql(alphadb::AlphaDB<PooledConn>),
+    #[cfg(feature = "mysql")]
+    Mariadb(alphadb::AlphaDB<PooledConn>),
+    #[cfg(feature = "postgres")]
+    Postgres(alphadb::AlphaDB<Client>),
+}
+
+/// Run the same expression on whichever AlphaDB instance is active
+macro_rules! dispatch {
+    ($self:expr, $db:ident => $body:expr) => {
+        match $self {
+            #[cfg(feature = "mysql")]
+            DbInstance::Mysql($db) => $body,
+            #[cfg(feature = "mysql")]
+            DbInstance::Mariadb($db) => $body,
+            #[cfg(feature = "postgres")]
+            DbInstance::Postgres($db) => $body,
+        }
+    };
+}
+
+/// Engine used when neither an engine nor a version source is provided
+#[cfg(feature = "mysql")]
+pub const DEFAULT_ENGINE: AlphaDBEngine = AlphaDBEngine::MySQL;
+#[cfg(not(feature = "mysql"))]
+pub const DEFAULT_ENGINE: AlphaDBEngine = AlphaDBEngine::PostgreSQL;
+
+/// Select the engine from an explicit engine name, or else from
+/// the `engine` field of a version source
+pub fn resolve_engine(
+    engine: Option<&str>,
+    version_source: Option<&str>,
+) -> Result<AlphaDBEngine, AlphaDBError> {
+    match (engine, version_source) {
+        (Some(engine), _) => engine.parse(),
+        (None, Some(version_source)) => AlphaDBEngine::from_version_source(version_source),
+        (None, None) => Ok(DEFAULT_ENGINE),
+    }
+}
+
+impl DbInstance {
+    pub fn new(engine: AlphaDBEngine) -> Result<Self, AlphaDBError> {
+        match engine {
+            #[cfg(feature = "mysql")]
+            AlphaDBEngine::MySQL => Ok(DbInstance::Mysql(alphadb::AlphaDB::new(
+                alphadb::engine::mysql(),
+            ))),
+            #[cfg(feature = "mysql")]
+            AlphaDBEngine::MariaDB => Ok(DbInstance::Mariadb(alphadb::AlphaDB::new(
+                alphadb::engine::mariadb(),
+            ))),
+            #[cfg(feature = "postgres")]
+            AlphaDBEngine::PostgreSQL => Ok(DbInstance::Postgres(alphadb::AlphaDB::new(
+                alphadb::engine::postgres(),
+            ))),
+            #[allow(unreachable_patterns)]
+            _ => Err(AlphaDBError {
+                message: format!(
+                    "This build of AlphaDB does not include the {} engine",
+                    engine.display_name()
+                ),
+                error: "unsupported-engine".to_string(),
+                version_trace: VersionTrace::new(),
+                location: None,
+            }),
+        }
+    }
+
+    pub fn engine(&self) -> AlphaDBEngine {
+        match self {
+            #[cfg(feature = "mysql")]
+            DbInstance::Mysql(_) => AlphaDBEngine::MySQL,
+            #[cfg(feature = "mysql")]
+            DbInstance::Mariadb(_) => AlphaDBEngine::MariaDB,
+            #[cfg(feature = "postgres")]
+            DbInstance::Postgres(_) => AlphaDBEngine::PostgreSQL,
+        }
+    }
+
+    pub fn default_port(&self) -> u16 {
+        match self.engine() {
+            AlphaDBEngine::MySQL | AlphaDBEngine::MariaDB => 3306,
+            AlphaDBEngine::PostgreSQL => 5432,
+            // SQLite opens a file, the port is never used
+            AlphaDBEngine::SQLite => 0,
+        }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        dispatch!(self, db => db.is_connected)
+    }
+
+    pub fn connect(
+        &mut self,
+        host: &str,
+        user: &str,
+        password: &str,
+        database: &str,
+        port: u16,
+    ) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.connect(&ConnectOptions::new(host, user, password, database, port)))
+    }
+
+    pub fn init(&mut self) -> Result<Init, AlphaDBError> {
+        dispatch!(self, db => db.init())
+    }
+
+    pub fn status(&mut self) -> Result<Status, AlphaDBError> {
+        dispatch!(self, db => db.status())
+    }
+
+    pub fn update_queries(
+        &mut self,
+        version_source: String,
+        target_version: Option<&str>,
+        no_data: bool,
+    ) -> Result<Vec<Query>, AlphaDBError> {
+        dispatch!(self, db => db.update_queries(version_source, target_version, no_data))
+    }
+
+    pub fn update(
+        &mut self,
+        version_source: String,
+        target_version: Option<&str>,
+        no_data: bool,
+        tolerated_verification_issue_level: ToleratedVerificationIssueLevel,
+    ) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.update(version_source, target_version, no_data, tolerated_verification_issue_level))
+    }
+
+    pub fn vacate(&mut self) -> Result<(), AlphaDBError> {
+        dispatch!(self, db => db.vacate())
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2024 Wibo Kuipers
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use alphadb::prelude::{AlphaDBError as AdbError, Get};
+use pyo3::create_exception;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+create_exception!(
+    alphadb,
+    AlphaDBError,
+    PyRuntimeError,
+    "Base exception for all AlphaDB errors. Carries the `error` code, `message` and `version_trace`."
+);
+create_exception!(alphadb, UpToDateError, AlphaDBError, "The database is already up-to-date.");
+create_exception!(alphadb, NotInitializedError, AlphaDBError, "The database is not initialized.");
+create_exception!(
+    alphadb,
+    IncompatibleVersionSourceError,
+    AlphaDBError,
+    "The version source does not belong to this database."
+);
+create_exception!(alphadb, InvalidVersionNumberError, AlphaDBError, "A version number could not be parsed.");
+create_exception!(alphadb, NoVersionNumberError, AlphaDBError, "A version is missing its version number.");
+create_exception!(alphadb, NoEngineError, AlphaDBError, "The version source does not specify an engine.");
+create_exception!(
+    alphadb,
+    UnsupportedEngineError,
+    AlphaDBError,
+    "The engine or one of its features is not supported."
+);
+create_exception!(
+    alphadb,
+    InvalidVersionSourceError,
+    AlphaDBError,
+    "The version source is not structured correctly."
+);
+create_exception!(
+    alphadb,
+    VersionSourceDirectoryError,
+    AlphaDBError,
+    "A version source directory could not be read or combined."
+);
+
+/// Map an AlphaDB error code to its Python exception
+fn new_err(error: &str, message: String) -> PyErr {
+    match error {
+        "up-to-date" => UpToDateError::new_err(message),
+        "not-initialized" => NotInitializedError::new_err(message),
+        "incompatible-version-source" | "incompatible-version-attributes" => {
+            IncompatibleVersionSourceError::new_err(message)
+        }
+        "invalid-version-number" | "version-number-parse-failed" => {
+            InvalidVersionNumberError::new_err(message)
+        }
+        "no-version-number" | "missing-version-number" => NoVersionNumberError::new_err(message),
+        "no-engine" => NoEngineError::new_err(message),
+        "unsupported-engine" | "unsupported-feature" => UnsupportedEngineError::new_err(message),
+        "invalid-structure"
+        | "incomplete-version-object"
+        | "column-has-no-type"
+        | "name-not-defined"
+        | "version-source-not-an-object"
+        | "invalid-default-data-index"
+        | "invalid-json-boolean"
+        | "invalid-json-number"
+        | "invalid-json-object"
+        | "invalid-json-string"
+        | "serde-json-error" => InvalidVersionSourceError::new_err(message),
+        "no-config-file-found"
+        | "multiple-config-files-found"
+        | "directory-read-failed"
+        | "directory-entry-read-failed"
+        | "no-version-source-files-provided" => VersionSourceDirectoryError::new_err(message),
+        _ => AlphaDBError::new_err(message),
+    }
+}
+
+/// Convert an AlphaDB error to a typed Python exception
+pub fn to_py_err(e: AdbError) -> PyErr {
+    Python::with_gil(|py| {
+        let err = new_err(&e.error(), e.message());
+
+        let value = err.value(py);
+        for (attr, result) in [
+            ("error", value.setattr("error", e.error())),
+            ("message", value.setattr("message", e.message.clone())),
+            ("version_trace", value.setattr("version_trace", e.version_trace().to_vec())),
+        ] {
+            if let Err(set_err) = result {
+                return PyRuntimeError::new_err(format!("Unable to set exception attribute '{attr}': {set_err}"));
+            }
+        }
+
+        err
+    })
+}
+
+/// Create an AlphaDB error without an error code
+pub fn alphadb_err(message: &str) -> PyErr {
+    to_py_err(AdbError {
+        message: message.to_string(),
+        ..Default::default()
+    })
+}
+
+/// Register all exceptions on the module
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    m.add("AlphaDBError", py.get_type::<AlphaDBError>())?;
+    m.add("UpToDateError", py.get_type::<UpToDateError>())?;
+    m.add("NotInitializedError", py.get_type::<NotInitializedError>())?;
+    m.add("IncompatibleVersionSourceError", py.get_type::<IncompatibleVersionSourceError>())?;
+    m.add("InvalidVersionNumberError", py.get_type::<InvalidVersionNumberError>())?;
+    m.add("NoVersionNumberError", py.get_type::<NoVersionNumberError>())?;
+    m.add("NoEngineError", py.get_type::<NoEngineError>())?;
+    m.add("UnsupportedEngineError", py.get_type::<UnsupportedEngineError>())?;
+    m.add("InvalidVersionSourceError", py.get_type::<InvalidVersionSourceError>())?;
+    m.add("VersionSourceDirectoryError", py.get_type::<VersionSourceDirectoryError>())?;
+
+    Ok(())
+}
@@ -13,50 +13,28 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-#[cfg(all(feature = "mysql", feature = "postgres"))]
-compile_error!("Enable only one database engine feature: mysql or postgres");
-
 #[cfg(not(any(feature = "mysql", feature = "postgres")))]
-compile_error!("Enable one database engine feature: mysql or postgres");
+compile_error!("Enable at least one database engine feature: mysql or postgres");
+
+mod engine;
+mod errors;
 
-use alphadb::core::method_types::{Init, Query as AdbQuery};
+use engine::{resolve_engine, DbInstance};
+use errors::{alphadb_err, to_py_err};
+use alphadb::core::method_types::{Init, Query as AdbQuery, QueryValue as AdbQueryValue};
+use alphadb::core::utils::consolidate::consolidate_version_source as adb_consolidate_version_source;
 use alphadb::prelude::*;
-#[cfg(all(feature = "mysql", not(feature = "postgres")))]
-use mysql::PooledConn;
-#[cfg(all(feature = "postgres", not(feature = "mysql")))]
-use postgres::Client;
-use pyo3::exceptions::PyRuntimeError;
+use alphadb::verification::{
+    verification_report, AlphaDBVerification as AdbVerification, ReportFormat,
+    VerificationIssue as AdbVerificationIssue, VerificationIssueLevel,
+};
+use alphadb::version_source::build_version_source_from_dir as adb_build_version_source_from_dir;
 use pyo3::prelude::*;
-
-#[cfg(any(
-    all(feature = "mysql", feature = "postgres"),
-    not(any(feature = "mysql", feature = "postgres"))
-))]
-struct DisabledConnection;
-
-#[cfg(all(feature = "mysql", not(feature = "postgres")))]
-type DbConnection = PooledConn;
-#[cfg(all(feature = "postgres", not(feature = "mysql")))]
-type DbConnection = Client;
-#[cfg(any(
-    all(feature = "mysql", feature = "postgres"),
-    not(any(feature = "mysql", feature = "postgres"))
-))]
-type DbConnection = DisabledConnection;
-
-#[cfg(all(feature = "mysql", not(feature = "postgres")))]
-const DEFAULT_PORT: u16 = 3306;
-#[cfg(all(feature = "postgres", not(feature = "mysql")))]
-const DEFAULT_PORT: u16 = 5432;
-#[cfg(any(
-    all(feature = "mysql", feature = "postgres"),
-    not(any(feature = "mysql", feature = "postgres"))
-))]
-const DEFAULT_PORT: u16 = 0;
+use std::path::PathBuf;
 
 #[pyclass(unsendable)]
 struct AlphaDB {
-    inner: alphadb::AlphaDB<DbConnection>,
+    inner: DbInstance,
 }
 
 #[derive(Debug, IntoPyObject, IntoPyObjectRef)]
@@ -67,25 +45,54 @@ pub struct Status {
     pub template: Option<String>,
 }
 
+/// Query parameter, converted to the matching native Python type
+#[derive(Debug, IntoPyObject, IntoPyObjectRef)]
+pub enum QueryValue {
+    String(String),
+    Integer(i64),
+    Unsigned(u64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Convert a query parameter, `NULL` becomes `None`
+fn query_value(value: AdbQueryValue) -> Option<QueryValue> {
+    match value {
+        AdbQueryValue::String(v) => Some(QueryValue::String(v)),
+        AdbQueryValue::Integer(v) => Some(QueryValue::Integer(v)),
+        AdbQueryValue::Unsigned(v) => Some(QueryValue::Unsigned(v)),
+        AdbQueryValue::Float(v) => Some(QueryValue::Float(v)),
+        AdbQueryValue::Bool(v) => Some(QueryValue::Bool(v)),
+        AdbQueryValue::Null => None,
+    }
+}
+
 #[derive(Debug, IntoPyObject, IntoPyObjectRef)]
 pub struct Query {
     pub query: String,
-    pub data: Option<Vec<String>>,
+    pub data: Option<Vec<Option<QueryValue>>>,
 }
 
 impl From<AdbQuery> for Query {
     fn from(q: AdbQuery) -> Self {
         Query {
-            data: q.data.map(|data| {
-                data.into_iter()
-                    .map(|value| value.to_string_lossy())
-                    .collect()
-            }),
+            data: q
+                .data
+                .map(|data| data.into_iter().map(query_value).collect()),
             query: q.query,
         }
     }
 }
 
+#[pyclass(name = "Init", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+enum PyInit {
+    /// Success: The database was initialized.
+    Success,
+    /// AlreadyInitialized: The database was already initialized, nothing was changed.
+    AlreadyInitialized,
+}
+
 #[pyclass(eq, eq_int)]
 #[derive(Clone, PartialEq)]
 enum PyToleratedVerificationIssueLevel {
@@ -99,56 +106,170 @@ enum PyToleratedVerificationIssueLevel {
     All,
 }
 
+#[pyclass(name = "VerificationIssueLevel", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+enum PyVerificationIssueLevel {
+    /// Low: Will work, but will not have any effect on the database.
+    Low,
+    /// High: Will still work, but might produce a different result than desired.
+    High,
+    /// Critical: Will not execute.
+    Critical,
+}
+
+#[pyclass(name = "VerificationIssue", get_all)]
+#[derive(Clone)]
+struct PyVerificationIssue {
+    level: PyVerificationIssueLevel,
+    message: String,
+    version_trace: Vec<String>,
+    /// Stable id of the rule that produced the issue, e.g. `ADB0012`
+    rule: Option<String>,
+    /// Line and column in the version source the issue points at
+    location: Option<(usize, usize)>,
+}
+
 #[pymethods]
-impl AlphaDB {
-    #[new]
-    fn __new__() -> Self {
-        #[cfg(all(feature = "mysql", not(feature = "postgres")))]
-        let config = alphadb::engine::mysql();
+impl PyVerificationIssue {
+    fn __repr__(&self) -> String {
+        let level = match self.level {
+            PyVerificationIssueLevel::Low => "LOW",
+            PyVerificationIssueLevel::High => "HIGH",
+            PyVerificationIssueLevel::Critical => "CRITICAL",
+        };
 
-        #[cfg(all(feature = "postgres", not(feature = "mysql")))]
-        let config = alphadb::engine::postgres();
+        format!(
+            "VerificationIssue(level={}, message={:?}, version_trace={:?}, rule={:?})",
+            level, self.message, self.version_trace, self.rule
+        )
+    }
+}
 
-        #[cfg(any(
-            all(feature = "mysql", feature = "postgres"),
-            not(any(feature = "mysql", feature = "postgres"))
-        ))]
-        let config = unreachable!();
+impl From<AdbVerificationIssue> for PyVerificationIssue {
+    fn from(issue: AdbVerificationIssue) -> Self {
+        PyVerificationIssue {
+            level: match issue.level {
+                VerificationIssueLevel::Low => PyVerificationIssueLevel::Low,
+                VerificationIssueLevel::High => PyVerificationIssueLevel::High,
+                VerificationIssueLevel::Critical => PyVerificationIssueLevel::Critical,
+            },
+            message: issue.message,
+            version_trace: issue.version_trace.to_vec(),
+            rule: issue.rule.map(String::from),
+            location: issue.location.map(|l| (l.line, l.column)),
+        }
+    }
+}
 
-        Self {
-            inner: alphadb::AlphaDB::new(config),
+#[pyclass(name = "AlphaDBVerification")]
+struct PyAlphaDBVerification {
+    inner: AdbVerification,
+}
+
+#[pymethods]
+impl PyAlphaDBVerification {
+    #[new]
+    fn __new__(version_source: String) -> PyResult<Self> {
+        match AdbVerification::new(version_source) {
+            Ok(inner) => Ok(Self { inner }),
+            Err(e) => Err(to_py_err(e)),
+        }
+    }
+
+    /// Verify the version source. Returns an empty list when no issues are found.
+    fn verify(&mut self) -> Vec<PyVerificationIssue> {
+        match self.inner.verify() {
+            Ok(()) => Vec::new(),
+            Err(issues) => issues.into_iter().map(|issue| issue.into()).collect(),
         }
     }
 
+    /// Verify the version source for each of `engines` instead of its own engine.
+    /// Returns the issues per engine, in the order of `engines`.
+    fn verify_engines(&mut self, engines: Vec<String>) -> PyResult<Vec<(String, Vec<PyVerificationIssue>)>> {
+        let engines: Vec<&str> = engines.iter().map(String::as_str).collect();
+        match self.inner.verify_engines(&engines) {
+            Ok(engine_issues) => Ok(engine_issues
+                .into_iter()
+                .map(|e| (e.engine.to_string(), e.issues.into_iter().map(|issue| issue.into()).collect()))
+                .collect()),
+            Err(e) => Err(to_py_err(e)),
+        }
+    }
+
+    /// Verify the version source and write the issues as a SARIF log or JUnit XML report.
+    #[pyo3(signature = (format, source=None))]
+    fn report(&mut self, format: String, source: Option<PathBuf>) -> PyResult<String> {
+        let format = format.parse::<ReportFormat>().map_err(to_py_err)?;
+        let issues = self.inner.verify().err().unwrap_or_default();
+        Ok(verification_report(&issues, format, &self.inner.custom_rules(), source.as_deref()))
+    }
+}
+
+/// Consolidate all versions of a version source into a single version
+#[pyfunction]
+fn consolidate_version_source(version_source: String) -> PyResult<String> {
+    match adb_consolidate_version_source(version_source) {
+        Ok(consolidated) => Ok(consolidated.to_string()),
+        Err(e) => Err(to_py_err(e)),
+    }
+}
+
+/// Combine a directory of version source files into a single version source
+#[pyfunction]
+fn build_version_source_from_dir(path: PathBuf) -> PyResult<String> {
+    match adb_build_version_source_from_dir(&path) {
+        Ok(version_source) => Ok(version_source.to_string()),
+        Err(e) => Err(to_py_err(e)),
+    }
+}
+
+#[pymethods]
+impl AlphaDB {
+    #[new]
+    #[pyo3(signature = (engine=None, version_source=None))]
+    fn __new__(engine: Option<&str>, version_source: Option<&str>) -> PyResult<Self> {
+        let engine = resolve_engine(engine, version_source).map_err(to_py_err)?;
+
+        Ok(Self {
+            inner: DbInstance::new(engine).map_err(to_py_err)?,
+        })
+    }
+
+    #[getter]
+    fn engine(&self) -> String {
+        self.inner.engine().to_string()
+    }
+
     #[getter]
     fn is_connected(&self) -> bool {
-        self.inner.is_connected
+        self.inner.is_connected()
     }
 
-    #[pyo3(signature = (host, user, password, database, port=DEFAULT_PORT))]
+    #[pyo3(signature = (host, user, password, database, port=None))]
     fn connect(
         &mut self,
         host: &str,
         user: &str,
         password: &str,
         database: &str,
-        port: u16,
+        port: Option<u16>,
     ) -> PyResult<()> {
+        let port = port.unwrap_or_else(|| self.inner.default_port());
+
         match self.inner.connect(host, user, password, database, port) {
             Ok(()) => Ok(()),
-            Err(e) => Err(PyRuntimeError::new_err(e.message())),
+            Err(e) => Err(to_py_err(e)),
         }
     }
 
-    fn init(&mut self) -> PyResult<()> {
+    fn init(&mut self) -> PyResult<PyInit> {
         match self.inner.init() {
             Ok(i) => match i {
-                Init::AlreadyInitialized => Err(PyRuntimeError::new_err(
-                    "The database is already initialized",
-                )),
-                Init::Success => Ok(()),
+                Init::AlreadyInitialized => Ok(PyInit::AlreadyInitialized),
+                Init::Success => Ok(PyInit::Success),
             },
-            Err(e) => Err(PyRuntimeError::new_err(e.message())),
+            Err(e) => Err(to_py_err(e)),
         }
     }
 
@@ -165,10 +286,10 @@ impl AlphaDB {
 
                 match status {
                     Ok(status) => Ok(status.into()),
-                    Err(_) => Err(PyRuntimeError::new_err("Unable to parse return value")),
+                    Err(_) => Err(alphadb_err("Unable to parse return value")),
                 }
             }
-            Err(e) => Err(PyRuntimeError::new_err(e.message())),
+            Err(e) => Err(to_py_err(e)),
         })
     }
 
@@ -193,7 +314,7 @@ impl AlphaDB {
 
                     Ok(queries_converted)
                 }
-                Err(e) => Err(PyRuntimeError::new_err(e.message())),
+                Err(e) => Err(to_py_err(e)),
             }
         })
     }
@@ -227,14 +348,14 @@ impl AlphaDB {
             allowed_error_priority,
         ) {
             Ok(_) => Ok(()),
-            Err(e) => Err(PyRuntimeError::new_err(e.message())),
+            Err(e) => Err(to_py_err(e)),
         }
     }
 
     fn vacate(&mut self) -> PyResult<()> {
         match self.inner.vacate() {
             Ok(_) => Ok(()),
-            Err(e) => Err(PyRuntimeError::new_err(e.message())),
+            Err(e) => Err(to_py_err(e)),
         }
     }
 }
@@ -242,5 +363,12 @@ impl AlphaDB {
 #[pymodule(name = "alphadb")]
 fn alphadb_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<AlphaDB>()?;
+    m.add_class::<PyInit>()?;
+    m.add_class::<PyAlphaDBVerification>()?;
+    m.add_class::<PyVerificationIssue>()?;
+    m.add_class::<PyVerificationIssueLevel>()?;
+    m.add_function(wrap_pyfunction!(consolidate_version_source, m)?)?;
+    m.add_function(wrap_pyfunction!(build_version_source_from_dir, m)?)?;
+    errors::register(m)?;
     Ok(())
 }
@@ -1,7 +1,17 @@
+import json
 import os
 
 import pytest
-from alphadb import AlphaDB
+from alphadb import (
+    AlphaDB,
+    AlphaDBError,
+    AlphaDBVerification,
+    Init,
+    NoEngineError,
+    UpToDateError,
+    VerificationIssueLevel,
+    consolidate_version_source,
+)
 
 engine = os.environ.get("ALPHADB_ENGINE", "mysql")
 
@@ -32,7 +42,7 @@ if engine not in engines:
     raise ValueError(f"Unsupported ALPHADB_ENGINE '{engine}'")
 
 config = engines[engine]
-db = AlphaDB()
+db = AlphaDB(engine=engine)
 
 
 def test_connect():
@@ -42,10 +52,8 @@ def test_connect():
 
 
 def test_init():
-    db.init()
-
-    with pytest.raises(RuntimeError, match="The database is already initialized"):
-        db.init()
+    assert db.init() == Init.Success
+    assert db.init() == Init.AlreadyInitialized
 
 
 def test_status():
@@ -62,6 +70,10 @@ def test_update():
     with open(f"../../assets/{config['structure']}") as f:
         structure = f.read()
 
+    queries = db.update_queries(version_source=structure)
+    default_data = next(q for q in queries if q["query"].startswith("INSERT INTO") and "table1" in q["query"])
+    assert default_data["data"] == [4, "test", "test", 12]
+
     db.update(version_source=structure)
 
     status = db.status()
@@ -72,6 +84,11 @@ def test_update():
         "template": "test",
     }
 
+    with pytest.raises(UpToDateError) as e:
+        db.update_queries(version_source=structure)
+
+    assert e.value.error == "up-to-date"
+
 
 def test_vacate():
     db.vacate()
@@ -83,3 +100,92 @@ def test_vacate():
         "name": config["connect"]["database"],
         "template": None,
     }
+
+
+def test_verify():
+    structure = {
+        "name": "test",
+        "engine": engine,
+        "version": [
+            {"_id": "0.0.1", "createtable": {"table1": {"col1": {"type": "VARCHAR", "length": 200}}}},
+        ],
+    }
+    assert AlphaDBVerification(json.dumps(structure)).verify() == []
+
+    del structure["name"]
+    issues = AlphaDBVerification(json.dumps(structure)).verify()
+    assert len(issues) == 1
+    assert issues[0].level == VerificationIssueLevel.Critical
+    assert issues[0].message == "No rootlevel name specified."
+    assert issues[0].version_trace == []
+    assert issues[0].rule == "ADB0002"
+    assert issues[0].location is None
+
+    structure["name"] = "test"
+    del structure["version"][0]["createtable"]["table1"]["col1"]["type"]
+    issues = AlphaDBVerification(json.dumps(structure, indent=4)).verify()
+    assert issues[0].rule == "ADB0010"
+    assert issues[0].location == (9, 21)
+
+    verification = AlphaDBVerification(json.dumps(structure, indent=4))
+    sarif = json.loads(verification.report("sarif", "structure.json"))
+    result = sarif["runs"][0]["results"][0]
+    assert result["ruleId"] == "ADB0010"
+    assert result["level"] == "error"
+    assert result["locations"][0]["physicalLocation"]["region"] == {"startLine": 9, "startColumn": 21}
+    assert '<failure type="critical"' in verification.report("junit")
+
+
+def test_verify_engines():
+    structure = {
+        "name": "test",
+        "engine": engine,
+        "version": [
+            {"_id": "0.0.1", "createtable": {"table1": {"col1": {"type": "JSONB"}}}},
+        ],
+    }
+    engine_issues = AlphaDBVerification(json.dumps(structure)).verify_engines(["postgres", "mysql"])
+    assert [e for e, _ in engine_issues] == ["postgres", "mysql"]
+    assert engine_issues[0][1] == []
+    assert engine_issues[1][1][0].rule == "ADB0016"
+    assert engine_issues[1][1][0].message == "Column type JSONB is not supported on mysql, but is on postgres"
+
+
+def test_consolidate_version_source():
+    version_source = json.dumps(
+        {
+            "name": "test",
+            "version": [
+                {"_id": "0.0.1", "createtable": {"table1": {"col1": {"type": "VARCHAR", "length": 200}}}},
+                {"_id": "0.0.2", "altertable": {"table1": {"addcolumn": {"col2": {"type": "TEXT"}}}}},
+            ],
+        }
+    )
+
+    assert json.loads(consolidate_version_source(version_source)) == {
+        "name": "test",
+        "version": [
+            {
+                "_id": "0.0.2",
+                "createtable": {"table1": {"col1": {"type": "VARCHAR", "length": 200}, "col2": {"type": "TEXT"}}},
+            }
+        ],
+    }
+
+
+def test_error_attributes():
+    with pytest.raises(NoEngineError) as e:
+        AlphaDBVerification(json.dumps({"name": "test", "version": []}))
+
+    assert isinstance(e.value, AlphaDBError)
+    assert isinstance(e.value, RuntimeError)
+    assert e.value.error == "no-engine"
+    assert e.value.version_trace == []
+
+
+def test_engine_from_version_source():
+    assert AlphaDB(version_source=json.dumps({"name": "test", "engine": "postgres", "version": []})).engine == "postgres"
+    assert AlphaDB(engine="mysql").engine == "mysql"
+
+    with pytest.raises(NoEngineError):
+        AlphaDB(version_source=json.dumps({"name": "test", "version": []}))